@@ -0,0 +1,139 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::path::PathBuf;
+
+use crate::fs::PersistentData;
+
+/// Slatepack contacts, saved next to the config file.
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Default, Clone)]
+pub struct AddressBook {
+    #[serde(default)]
+    pub contacts: Vec<Contact>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Contact {
+    pub name: String,
+
+    /// Slatepack address of the contact
+    pub address: String,
+
+    #[serde(default)]
+    pub notes: String,
+
+    /// Last time a transaction was sent to the contact
+    #[serde(default)]
+    pub last_used: Option<DateTime<Utc>>,
+}
+
+impl Contact {
+    pub fn new(name: String, address: String, notes: String) -> Self {
+        Self {
+            name,
+            address,
+            notes,
+            last_used: None,
+        }
+    }
+}
+
+impl Display for Contact {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl AddressBook {
+    pub fn find_by_address(&self, address: &str) -> Option<&Contact> {
+        let address = address.trim();
+        self.contacts.iter().find(|c| c.address == address)
+    }
+
+    /// Adds a contact, replacing the name and notes of any contact with the same address
+    pub fn upsert(&mut self, contact: Contact) {
+        match self
+            .contacts
+            .iter_mut()
+            .find(|c| c.address == contact.address)
+        {
+            Some(existing) => {
+                existing.name = contact.name;
+                existing.notes = contact.notes;
+            }
+            None => self.contacts.push(contact),
+        }
+    }
+
+    pub fn remove(&mut self, address: &str) {
+        self.contacts.retain(|c| c.address != address);
+    }
+
+    /// Records a send to `address`, returning whether it belongs to a contact
+    pub fn touch(&mut self, address: &str, time: DateTime<Utc>) -> bool {
+        let address = address.trim();
+        match self.contacts.iter_mut().find(|c| c.address == address) {
+            Some(contact) => {
+                contact.last_used = Some(time);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Contacts most recently sent to first, then by name
+    pub fn by_last_used(&self) -> Vec<Contact> {
+        let mut contacts = self.contacts.clone();
+        contacts.sort_by(|a, b| {
+            b.last_used
+                .cmp(&a.last_used)
+                .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+        });
+        contacts
+    }
+}
+
+impl PersistentData for AddressBook {
+    fn relative_path() -> PathBuf {
+        PathBuf::from("address-book.yml")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AddressBook, Contact};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_address_book_upsert_and_order() {
+        let mut book = AddressBook::default();
+        book.upsert(Contact::new("bob".into(), "grin1bob".into(), "".into()));
+        book.upsert(Contact::new("Alice".into(), "grin1alice".into(), "".into()));
+        book.upsert(Contact::new("Carol".into(), "grin1carol".into(), "".into()));
+
+        // Same address updates the existing contact
+        book.upsert(Contact::new("Bob".into(), "grin1bob".into(), "work".into()));
+        assert_eq!(book.contacts.len(), 3);
+        let bob = book.find_by_address(" grin1bob ").unwrap();
+        assert_eq!(bob.name, "Bob");
+        assert_eq!(bob.notes, "work");
+
+        let names = |book: &AddressBook| -> Vec<String> {
+            book.by_last_used().into_iter().map(|c| c.name).collect()
+        };
+        assert_eq!(names(&book), vec!["Alice", "Bob", "Carol"]);
+
+        assert!(book.touch("grin1carol", Utc.timestamp_opt(1_600_000_000, 0).unwrap()));
+        assert!(book.touch("grin1bob", Utc.timestamp_opt(1_700_000_000, 0).unwrap()));
+        assert!(!book.touch("grin1dave", Utc.timestamp_opt(1_700_000_000, 0).unwrap()));
+        assert_eq!(names(&book), vec!["Bob", "Carol", "Alice"]);
+
+        book.remove("grin1bob");
+        assert!(book.find_by_address("grin1bob").is_none());
+
+        // Contacts saved before notes or sends were recorded still load
+        let book: AddressBook =
+            serde_yaml::from_str("contacts:\n  - name: Old\n    address: grin1old\n").unwrap();
+        assert_eq!(book.contacts[0].last_used, None);
+    }
+}
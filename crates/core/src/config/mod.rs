@@ -4,11 +4,19 @@ use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
 use std::path::PathBuf;
 
+mod address_book;
+mod sent_slatepacks;
+mod tx_notes;
 mod wallet;
 
 use crate::fs::PersistentData;
 
-pub use crate::config::wallet::Wallet;
+pub use crate::config::address_book::{AddressBook, Contact};
+pub use crate::config::sent_slatepacks::{SentSlatepack, SentSlatepacks};
+pub use crate::config::tx_notes::{parse_tags, TxNote, TxNotes};
+pub use crate::config::wallet::{
+    SendDefaults, Wallet, WalletBackendType, DEFAULT_CONFIRMATIONS_THRESHOLD,
+};
 
 /// Config struct.
 #[derive(Deserialize, Serialize, Debug, PartialEq, Default, Clone)]
@@ -257,6 +265,48 @@ const fn default_true() -> bool {
 
 #[cfg(test)]
 mod test {
+    use super::{SendDefaults, Wallet, WalletBackendType, DEFAULT_CONFIRMATIONS_THRESHOLD};
+    use grin_core::global::ChainTypes;
+
+    #[test]
+    fn test_wallet_backend_defaults_to_local() {
+        let wallet: Wallet = serde_yaml::from_str("display_name: Old").unwrap();
+        assert_eq!(wallet.backend, WalletBackendType::Local);
+        assert_eq!(wallet.send_defaults, SendDefaults::default());
+        assert_eq!(
+            wallet.confirmations_threshold,
+            DEFAULT_CONFIRMATIONS_THRESHOLD
+        );
+    }
+
+    #[test]
+    fn test_send_defaults_partial() {
+        let wallet: Wallet = serde_yaml::from_str(
+            "display_name: Old\nsend_defaults:\n  num_change_outputs: 3\n  ttl_blocks: 60\n",
+        )
+        .unwrap();
+        assert_eq!(wallet.send_defaults.num_change_outputs, 3);
+        assert_eq!(wallet.send_defaults.ttl_blocks, Some(60));
+        assert_eq!(wallet.send_defaults.minimum_confirmations, 2);
+    }
+
+    #[test]
+    fn test_wallet_backend_round_trip() {
+        let wallet = Wallet::new_remote(
+            "Remote".to_owned(),
+            ChainTypes::Testnet,
+            WalletBackendType::RemoteOwnerApi {
+                owner_api_url: "http://10.0.0.2:13420".to_owned(),
+                owner_api_secret_path: None,
+                foreign_api_url: Some("http://10.0.0.2:13415".to_owned()),
+                foreign_api_secret_path: None,
+            },
+        );
+        let yaml = serde_yaml::to_string(&wallet).unwrap();
+        let parsed: Wallet = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed, wallet);
+        assert!(parsed.backend.is_remote());
+    }
 
     /// This method will take a relative path and make a case insentitive pattern
     // For some reason the case insensitive pattern doesn't work
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::fs::PersistentData;
+
+/// Slatepacks as they were handed to the other party, so an outstanding transaction's can
+/// be shown again. The wallet only stores the transaction, not the participant data the
+/// other party needs, so the armored slatepack itself is kept in the config directory.
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Default, Clone)]
+pub struct SentSlatepacks {
+    /// Keyed by slate id, then by the stage the slate was at (`S1`, `I2`, ...). Both
+    /// sides of a transaction may be handled on this machine.
+    #[serde(default)]
+    pub slatepacks: BTreeMap<String, BTreeMap<String, SentSlatepack>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Default, Clone)]
+pub struct SentSlatepack {
+    /// Name the slatepack was offered for saving under
+    #[serde(default)]
+    pub file_name: String,
+
+    #[serde(default)]
+    pub slatepack: String,
+}
+
+impl SentSlatepacks {
+    pub fn get(&self, slate_id: &str, stage: &str) -> Option<&SentSlatepack> {
+        self.slatepacks.get(slate_id).and_then(|s| s.get(stage))
+    }
+
+    pub fn insert(&mut self, slate_id: String, stage: String, slatepack: SentSlatepack) {
+        self.slatepacks
+            .entry(slate_id)
+            .or_default()
+            .insert(stage, slatepack);
+    }
+
+    /// Drops every stage kept for `slate_id`, returning whether there were any
+    pub fn remove(&mut self, slate_id: &str) -> bool {
+        self.slatepacks.remove(slate_id).is_some()
+    }
+}
+
+impl PersistentData for SentSlatepacks {
+    fn relative_path() -> PathBuf {
+        PathBuf::from("sent-slatepacks.yml")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{SentSlatepack, SentSlatepacks};
+
+    #[test]
+    fn test_sent_slatepacks() {
+        let mut slatepacks = SentSlatepacks::default();
+        let id = "0436430c-2b02-624c-2032-570501212b00".to_owned();
+        let sent = SentSlatepack {
+            file_name: format!("{}.S1.slatepack", id),
+            slatepack: "BEGINSLATEPACK. S1. ENDSLATEPACK.".to_owned(),
+        };
+        slatepacks.insert(id.clone(), "S1".to_owned(), sent.clone());
+        assert_eq!(slatepacks.get(&id, "S1"), Some(&sent));
+        assert_eq!(slatepacks.get(&id, "S2"), None);
+
+        // The response of the other side doesn't replace it
+        let response = SentSlatepack {
+            file_name: format!("{}.S2.slatepack", id),
+            slatepack: "BEGINSLATEPACK. S2. ENDSLATEPACK.".to_owned(),
+        };
+        slatepacks.insert(id.clone(), "S2".to_owned(), response.clone());
+        assert_eq!(slatepacks.get(&id, "S1"), Some(&sent));
+        assert_eq!(slatepacks.get(&id, "S2"), Some(&response));
+
+        assert!(slatepacks.remove(&id));
+        assert_eq!(slatepacks.get(&id, "S1"), None);
+        assert!(!slatepacks.remove(&id));
+        assert!(slatepacks.slatepacks.is_empty());
+    }
+}
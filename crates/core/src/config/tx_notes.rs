@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::fs::PersistentData;
+
+/// Local notes and tags on transactions. Slates carry no memo, so these are kept
+/// in the config directory, keyed by the shared slate id, and never leave this machine.
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Default, Clone)]
+pub struct TxNotes {
+    #[serde(default)]
+    pub notes: BTreeMap<String, TxNote>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Default, Clone)]
+pub struct TxNote {
+    #[serde(default)]
+    pub text: String,
+
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl TxNote {
+    pub fn new(text: &str, tags: &str) -> Self {
+        Self {
+            text: text.trim().to_owned(),
+            tags: parse_tags(tags),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty() && self.tags.is_empty()
+    }
+
+    /// Tags as typed into the editor, comma separated
+    pub fn tags_string(&self) -> String {
+        self.tags.join(", ")
+    }
+
+    /// Whether the text or any tag contains `query`, ignoring case
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        self.text.to_lowercase().contains(&query)
+            || self.tags.iter().any(|t| t.to_lowercase().contains(&query))
+    }
+}
+
+/// Splits comma separated tags, dropping blanks and repeats
+pub fn parse_tags(tags: &str) -> Vec<String> {
+    let mut parsed: Vec<String> = vec![];
+    for tag in tags.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        if !parsed.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            parsed.push(tag.to_owned());
+        }
+    }
+    parsed
+}
+
+impl TxNotes {
+    pub fn get(&self, slate_id: &str) -> Option<&TxNote> {
+        self.notes.get(slate_id)
+    }
+
+    /// Stores the note for a slate, dropping it entirely once emptied
+    pub fn set(&mut self, slate_id: String, note: TxNote) {
+        if note.is_empty() {
+            self.notes.remove(&slate_id);
+        } else {
+            self.notes.insert(slate_id, note);
+        }
+    }
+}
+
+impl PersistentData for TxNotes {
+    fn relative_path() -> PathBuf {
+        PathBuf::from("tx-notes.yml")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_tags, TxNote, TxNotes};
+
+    #[test]
+    fn test_tx_notes() {
+        assert_eq!(
+            parse_tags(" rent, , Office,office ,q3"),
+            vec!["rent", "Office", "q3"]
+        );
+
+        let mut notes = TxNotes::default();
+        let id = "0436430c-2b02-624c-2032-570501212b00".to_owned();
+        notes.set(id.clone(), TxNote::new(" March invoice ", "rent, office"));
+
+        let note = notes.get(&id).unwrap();
+        assert_eq!(note.text, "March invoice");
+        assert_eq!(note.tags_string(), "rent, office");
+        assert!(note.matches("MARCH"));
+        assert!(note.matches("offi"));
+        assert!(!note.matches("payroll"));
+
+        notes.set(id.clone(), TxNote::new("", " , "));
+        assert!(notes.get(&id).is_none());
+    }
+}
@@ -16,6 +16,79 @@ pub struct Wallet {
     pub use_embedded_node: bool,
     /// Chain type of wallet
     pub chain_type: ChainTypes,
+    /// Which backend drives this wallet
+    #[serde(default)]
+    pub backend: WalletBackendType,
+    /// Account last selected in this wallet, `None` means the `default` account
+    #[serde(default)]
+    pub active_account: Option<String>,
+    /// Parameters new sends from this wallet start with
+    #[serde(default)]
+    pub send_defaults: SendDefaults,
+    /// Confirmations a transaction needs before the tx list shows it as confirmed
+    pub confirmations_threshold: u64,
+}
+
+/// Confirmations wallets ask for unless told otherwise, as grin-wallet does
+pub const DEFAULT_CONFIRMATIONS_THRESHOLD: u64 = 10;
+
+/// Transaction building parameters, editable in the advanced panel of the send screen
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(default)]
+pub struct SendDefaults {
+    /// Confirmations an output needs before it can fund a send
+    pub minimum_confirmations: u64,
+    /// Number of outputs the change is split into
+    pub num_change_outputs: u32,
+    /// Select inputs and lock them only when finalizing
+    pub late_lock: bool,
+    /// Blocks after which an unconfirmed send expires, `None` for never
+    pub ttl_blocks: Option<u64>,
+    /// Spend every eligible output rather than the fewest that cover the amount
+    pub use_all: bool,
+}
+
+impl Default for SendDefaults {
+    fn default() -> Self {
+        SendDefaults {
+            minimum_confirmations: 2,
+            num_change_outputs: 1,
+            late_lock: false,
+            ttl_blocks: None,
+            use_all: false,
+        }
+    }
+}
+
+/// How the GUI talks to a wallet
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type")]
+pub enum WalletBackendType {
+    /// Wallet directory on the local disk, driven by an in-process Owner API
+    Local,
+    /// grin-wallet instance running elsewhere, driven over its Owner API v3
+    RemoteOwnerApi {
+        /// Base url of the owner listener, e.g. http://127.0.0.1:3420
+        owner_api_url: String,
+        /// File holding the owner API secret, if the listener requires one
+        owner_api_secret_path: Option<PathBuf>,
+        /// Base url of the foreign listener, needed to receive transactions
+        foreign_api_url: Option<String>,
+        /// File holding the foreign API secret, if the listener requires one
+        foreign_api_secret_path: Option<PathBuf>,
+    },
+}
+
+impl WalletBackendType {
+    pub fn is_remote(&self) -> bool {
+        matches!(self, WalletBackendType::RemoteOwnerApi { .. })
+    }
+}
+
+impl Default for WalletBackendType {
+    fn default() -> Self {
+        WalletBackendType::Local
+    }
 }
 
 impl Wallet {
@@ -25,6 +98,27 @@ impl Wallet {
             display_name,
             use_embedded_node: true,
             chain_type,
+            backend: WalletBackendType::Local,
+            active_account: None,
+            send_defaults: SendDefaults::default(),
+            confirmations_threshold: DEFAULT_CONFIRMATIONS_THRESHOLD,
+        }
+    }
+
+    pub fn new_remote(
+        display_name: String,
+        chain_type: ChainTypes,
+        backend: WalletBackendType,
+    ) -> Self {
+        Self {
+            tld: None,
+            display_name,
+            use_embedded_node: false,
+            chain_type,
+            backend,
+            active_account: None,
+            send_defaults: SendDefaults::default(),
+            confirmations_threshold: DEFAULT_CONFIRMATIONS_THRESHOLD,
         }
     }
 }
@@ -36,6 +130,10 @@ impl Default for Wallet {
             display_name: "Default".to_owned(),
             use_embedded_node: true,
             chain_type: ChainTypes::Mainnet,
+            backend: WalletBackendType::Local,
+            active_account: None,
+            send_defaults: SendDefaults::default(),
+            confirmations_threshold: DEFAULT_CONFIRMATIONS_THRESHOLD,
         }
     }
 }
\ No newline at end of file
@@ -14,6 +14,53 @@ pub enum GrinWalletInterfaceError {
     ForeignAPINotInstantiated,
     #[error("Invalid Slatepack Address")]
     InvalidSlatepackAddress,
+    #[error("Remote Owner API error: {0}")]
+    RemoteOwnerApi(String),
+    #[error("Wallet did not respond in time")]
+    WalletWorkerTimeout,
+    #[error("Wallet operation cancelled")]
+    WalletWorkerCancelled,
+    #[error("Wallet worker has stopped")]
+    WalletWorkerStopped,
+    #[error("Remote Foreign API url not configured")]
+    RemoteForeignApiNotConfigured,
+    #[error("Output {0} is not spendable")]
+    OutputNotSpendable(String),
+    #[error("The chosen outputs don't cover the amount and fee")]
+    SelectedOutputsInsufficient,
+    #[error("Inputs can't be locked late when choosing outputs")]
+    LateLockWithCoinControl,
+    #[error("Choosing outputs is not supported by remote wallets")]
+    CoinControlUnsupported,
+    #[error("Changing the password is not supported by remote wallets")]
+    ChangePasswordUnsupported,
+    #[error("Showing the recovery phrase is not supported by remote wallets")]
+    RecoveryPhraseUnsupported,
+    #[error("No stored transaction for transaction {0}")]
+    StoredTxNotFound(u32),
+    #[error("No slatepack was kept for transaction {0}")]
+    SentSlatepackNotFound(u32),
+    #[error(transparent)]
+    Isahc(#[from] isahc::Error),
+    #[error(transparent)]
+    Http(#[from] isahc::http::Error),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    SerdeJson(#[from] serde_json::Error),
+}
+
+impl GrinWalletInterfaceError {
+    /// Whether a slatepack couldn't be read because it's encrypted for another address
+    pub fn is_slatepack_decryption(&self) -> bool {
+        match self {
+            GrinWalletInterfaceError::WalletLibWallet(
+                grin_wallet_libwallet::Error::SlatepackDecryption(_),
+            ) => true,
+            GrinWalletInterfaceError::RemoteOwnerApi(msg) => msg.contains("decrypt"),
+            _ => false,
+        }
+    }
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -18,6 +18,7 @@ pub mod container;
 pub mod modal;
 pub mod header;
 pub mod picklist;
+pub mod progress_bar;
 pub mod scrollable;
 pub mod text;
 pub mod text_input;
@@ -29,6 +30,7 @@ pub use checkbox::CheckboxStyle;
 pub use container::ContainerStyle;
 pub use modal::ModalStyle;
 pub use picklist::PickListStyle;
+pub use progress_bar::ProgressBarStyle;
 pub use scrollable::ScrollableStyle;
 pub use text_input::TextInputStyle;
 pub use table_row::TableRowStyle;
@@ -56,10 +58,12 @@ pub type TextInput<'a, Message> = iced::widget::TextInput<'a, Message, Renderer>
 pub type Button<'a, Message> = iced::widget::Button<'a, Message, Renderer>;
 pub type Scrollable<'a, Message> = iced::widget::Scrollable<'a, Message, Renderer>;
 pub type PickList<'a, T, Message> = iced::widget::PickList<'a, T, Message, Renderer>;
+pub type ProgressBar = iced::widget::ProgressBar<Renderer>;
 pub type Card<'a, Message> = iced_aw::native::Card<'a, Message, Renderer>;
 pub type Modal<'a, Content, Message> = iced_aw::modal::Modal<'a, Content, Message, Renderer, Theme>;
 pub type Header<'a, Message> = grin_gui_widgets::widget::header::Header<'a, Message, Renderer>;
 pub type TableRow<'a, Message> = grin_gui_widgets::widget::table_row::TableRow<'a, Message, Renderer>;
+pub type Reveal<'a, Message> = grin_gui_widgets::widget::reveal::Reveal<'a, Message, Renderer>;
 
 
 #[derive(Debug, Clone, Copy, Default, Deserialize, Serialize)]
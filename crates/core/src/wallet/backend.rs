@@ -0,0 +1,198 @@
+/// Common interface over everything the GUI can drive as a wallet
+use std::path::PathBuf;
+
+use grin_util::secp::pedersen::Commitment;
+use iced_futures::futures::channel::mpsc;
+
+use crate::error::GrinWalletInterfaceError;
+
+use super::subscriber::UIMessage;
+use super::{
+    global, AcctPathMapping, InitTxArgs, IssueInvoiceTxArgs, OutputCommitMapping, PaymentProof,
+    RemoteOwnerApi, RetrieveTxQueryArgs, Slate, Slatepack, TxEstimate, TxLogEntry, WalletInfo,
+    ZeroingString,
+};
+
+/// Operations the GUI performs against a wallet. Calls are blocking, so the GUI only
+/// ever reaches a backend through the `WalletWorker` thread.
+pub trait WalletBackend: Send {
+    fn wallet_is_open(&self) -> bool;
+
+    /// Whether to point the wallet at the embedded node rather than its configured one
+    fn set_use_embedded_node(&mut self, value: bool);
+
+    /// Foreign API secret file of the node used for checking outputs
+    fn set_check_node_foreign_api_secret_path(&mut self, path: Option<String>);
+
+    /// Creates a new wallet, returning (top level directory, recovery phrase, display name,
+    /// chain type)
+    fn init(
+        &mut self,
+        password: String,
+        top_level_directory: PathBuf,
+        display_name: String,
+        chain_type: global::ChainTypes,
+        recovery_phrase: Option<String>,
+    ) -> Result<(String, String, String, global::ChainTypes), GrinWalletInterfaceError>;
+
+    fn open_wallet(
+        &mut self,
+        password: String,
+        top_level_directory: PathBuf,
+        chain_type: global::ChainTypes,
+    ) -> Result<(), GrinWalletInterfaceError>;
+
+    fn open_remote_wallet(
+        &mut self,
+        password: String,
+        remote_api: RemoteOwnerApi,
+        chain_type: global::ChainTypes,
+    ) -> Result<(), GrinWalletInterfaceError>;
+
+    fn close_wallet(&mut self) -> Result<(), GrinWalletInterfaceError>;
+
+    /// Re-encrypts the open wallet's seed under `new_password` and reopens the wallet
+    /// with it. Nothing changes if `old_password` is wrong.
+    fn change_password(
+        &mut self,
+        old_password: ZeroingString,
+        new_password: ZeroingString,
+    ) -> Result<(), GrinWalletInterfaceError>;
+
+    /// Recovery phrase of the open wallet, which takes the password again
+    fn get_recovery_phrase(
+        &self,
+        password: ZeroingString,
+    ) -> Result<ZeroingString, GrinWalletInterfaceError>;
+
+    /// Where to push updater status messages, see `wallet::subscriber`
+    fn set_status_sender(&mut self, sender: mpsc::Sender<UIMessage>);
+
+    /// Fetches updater messages for backends that can't push them (remote wallets)
+    /// and forwards them to the status sender. No-op for backends that push.
+    fn poll_updater_status(&mut self) -> Result<(), GrinWalletInterfaceError>;
+
+    fn get_wallet_info(&self) -> Result<(bool, WalletInfo), GrinWalletInterfaceError>;
+
+    fn get_txs(
+        &self,
+        query_args: Option<RetrieveTxQueryArgs>,
+    ) -> Result<(bool, Vec<TxLogEntry>), GrinWalletInterfaceError>;
+
+    /// Outputs of the active account, refreshed from the node first
+    fn get_outputs(
+        &self,
+        include_spent: bool,
+    ) -> Result<(bool, Vec<OutputCommitMapping>), GrinWalletInterfaceError>;
+
+    fn get_slatepack_address(&self) -> Result<String, GrinWalletInterfaceError>;
+
+    fn accounts(&self) -> Result<Vec<AcctPathMapping>, GrinWalletInterfaceError>;
+
+    fn create_account(&mut self, label: String) -> Result<(), GrinWalletInterfaceError>;
+
+    /// Account that summary, transaction and send calls operate on from now on
+    fn set_active_account(&mut self, label: String) -> Result<(), GrinWalletInterfaceError>;
+
+    /// Creates and locks a send transaction, returning the S1 slate and its slatepack for
+    /// the recipient.
+    /// `selected_outputs` are commitments (hex) of the exact outputs to spend, `None`
+    /// leaves input selection to the wallet.
+    fn create_tx(
+        &mut self,
+        init_args: InitTxArgs,
+        dest_slatepack_address: String,
+        selected_outputs: Option<Vec<String>>,
+    ) -> Result<(Slate, String), GrinWalletInterfaceError>;
+
+    /// Estimates a send without creating or locking anything. `selected_outputs` as for
+    /// `create_tx`.
+    fn estimate_tx(
+        &self,
+        init_args: InitTxArgs,
+        selected_outputs: Option<Vec<String>>,
+    ) -> Result<TxEstimate, GrinWalletInterfaceError>;
+
+    /// Issues an invoice, returning the I1 slate and its slatepack for the payer
+    fn issue_invoice(
+        &mut self,
+        args: IssueInvoiceTxArgs,
+        dest_slatepack_address: String,
+    ) -> Result<(Slate, String), GrinWalletInterfaceError>;
+
+    /// Pays an invoice (I1) and locks the outputs used, returning the I2 slatepack for
+    /// the issuer
+    fn process_invoice(
+        &mut self,
+        slate: Slate,
+        init_args: InitTxArgs,
+        dest_slatepack_address: String,
+    ) -> Result<String, GrinWalletInterfaceError>;
+
+    /// Finalizes a paid invoice (I2) and posts it to the chain
+    fn finalize_invoice(&mut self, slate: Slate) -> Result<(), GrinWalletInterfaceError>;
+
+    /// Receives a standard S1 slate, returning the S2 slatepack for the sender
+    fn receive_tx_from_s1(
+        &mut self,
+        slate: Slate,
+        dest_slatepack_address: String,
+    ) -> Result<Option<String>, GrinWalletInterfaceError>;
+
+    /// Finalizes a standard S2 slate and posts it to the chain
+    fn finalize_from_s2(
+        &mut self,
+        slate: Slate,
+        send_to_chain: bool,
+    ) -> Result<Option<String>, GrinWalletInterfaceError>;
+
+    /// Checks the wallet's outputs against the chain from `start_height` (genesis if
+    /// `None`), restoring missing outputs and fixing up ones in the wrong state.
+    /// Progress goes to the status sender as `StatusMessage::Scanning`.
+    fn scan(
+        &mut self,
+        start_height: Option<u64>,
+        delete_unconfirmed: bool,
+    ) -> Result<(), GrinWalletInterfaceError>;
+
+    /// Payment proof of a completed send, refreshed from the node first
+    fn retrieve_payment_proof(&self, tx_id: u32) -> Result<PaymentProof, GrinWalletInterfaceError>;
+
+    /// Checks a payment proof against the chain, returning whether the sender and the
+    /// recipient addresses are ours
+    fn verify_payment_proof(
+        &self,
+        proof: PaymentProof,
+    ) -> Result<(bool, bool), GrinWalletInterfaceError>;
+
+    fn cancel_tx(&mut self, id: u32) -> Result<u32, GrinWalletInterfaceError>;
+
+    /// Slate holding the transaction stored when transaction `tx_id` locked its outputs,
+    /// `None` if the wallet never stored one
+    fn get_stored_tx(&self, tx_id: u32) -> Result<Option<Slate>, GrinWalletInterfaceError>;
+
+    /// Height of the block holding the kernel with excess `excess` (hex), looked up on the
+    /// node from `min_height`. `None` if the node doesn't have the kernel.
+    fn kernel_height(
+        &self,
+        excess: Commitment,
+        min_height: Option<u64>,
+    ) -> Result<Option<u64>, GrinWalletInterfaceError>;
+
+    /// Posts the stored transaction of `tx_id` to the node again, stemming it through
+    /// dandelion unless `fluff`
+    fn repost_tx(&mut self, tx_id: u32, fluff: bool) -> Result<(), GrinWalletInterfaceError>;
+
+    /// Attempt to decode and decrypt a given slatepack
+    fn decrypt_slatepack(
+        &self,
+        slatepack: String,
+    ) -> Result<(Slatepack, Slate), GrinWalletInterfaceError>;
+
+    /// Armors `slate` as a slatepack, encrypted to `dest_slatepack_address` if given
+    fn encrypt_slatepack(
+        &self,
+        slate: Slate,
+        dest_slatepack_address: Option<String>,
+    ) -> Result<String, GrinWalletInterfaceError>;
+}
@@ -0,0 +1,299 @@
+use grin_core::consensus::COINBASE_MATURITY;
+use grin_core::core::{Committed, KernelFeatures, Transaction};
+use grin_util::ToHex;
+
+use super::{
+    OutputCommitMapping, Slate, SlatepackAddress, TxLogEntry, TxLogEntryType, WalletBackend,
+};
+
+/// Inputs, outputs and kernels of a stored transaction, for display
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StoredTxSummary {
+    /// Input commitments (hex)
+    pub inputs: Vec<String>,
+    /// Output commitments (hex) with their features
+    pub outputs: Vec<(String, String)>,
+    /// Kernel excesses (hex) with their features
+    pub kernels: Vec<(String, String)>,
+}
+
+impl StoredTxSummary {
+    pub fn new(tx: &Transaction) -> Self {
+        StoredTxSummary {
+            inputs: tx
+                .inputs_committed()
+                .iter()
+                .map(|c| c.0.to_vec().to_hex())
+                .collect(),
+            outputs: tx
+                .outputs()
+                .iter()
+                .map(|o| {
+                    (
+                        o.commitment().0.to_vec().to_hex(),
+                        format!("{:?}", o.features()),
+                    )
+                })
+                .collect(),
+            kernels: tx
+                .kernels()
+                .iter()
+                .map(|k| (k.excess.0.to_vec().to_hex(), kernel_features(&k.features)))
+                .collect(),
+        }
+    }
+
+    /// Summary of the transaction held by a stored slate, if it carries one
+    pub fn from_slate(slate: &Slate) -> Option<Self> {
+        slate.tx.as_ref().map(StoredTxSummary::new)
+    }
+}
+
+fn kernel_features(features: &KernelFeatures) -> String {
+    match features {
+        KernelFeatures::Plain { .. } => "Plain".to_owned(),
+        KernelFeatures::Coinbase => "Coinbase".to_owned(),
+        KernelFeatures::HeightLocked { lock_height, .. } => {
+            format!("HeightLocked ({})", lock_height)
+        }
+        KernelFeatures::NoRecentDuplicate {
+            relative_height, ..
+        } => format!("NoRecentDuplicate ({})", relative_height),
+    }
+}
+
+/// Slatepack address of the other party, as recorded in the transaction's payment proof.
+/// Transactions without a proof don't record one.
+pub fn tx_counterparty_address(tx: &TxLogEntry) -> Option<String> {
+    let proof = tx.payment_proof.as_ref()?;
+    let address = match tx.tx_type {
+        TxLogEntryType::TxSent | TxLogEntryType::TxSentCancelled => &proof.receiver_address,
+        TxLogEntryType::TxReceived | TxLogEntryType::TxReceivedCancelled => &proof.sender_address,
+        _ => return None,
+    };
+    Some(SlatepackAddress::new(address).to_string())
+}
+
+/// Hex form of the transaction's kernel excess, once known
+pub fn tx_kernel_excess_hex(tx: &TxLogEntry) -> Option<String> {
+    tx.kernel_excess.map(|k| k.0.to_vec().to_hex())
+}
+
+/// Whether the transaction was finalized but hasn't confirmed, so its stored transaction
+/// can be posted again
+pub fn tx_can_repost(tx: &TxLogEntry) -> bool {
+    !tx.confirmed
+        && tx.kernel_excess.is_some()
+        && tx.stored_tx.is_some()
+        && matches!(
+            tx.tx_type,
+            TxLogEntryType::TxSent | TxLogEntryType::TxReceived
+        )
+}
+
+/// Whether the transaction is still waiting on the other party, so its slatepack can be
+/// shown again: a send awaiting the S2 response, or a receive awaiting finalization
+pub fn tx_can_resend_slatepack(tx: &TxLogEntry) -> bool {
+    !tx.confirmed
+        && tx.kernel_excess.is_none()
+        && tx.stored_tx.is_some()
+        && matches!(
+            tx.tx_type,
+            TxLogEntryType::TxSent | TxLogEntryType::TxReceived
+        )
+}
+
+/// Height the transaction was confirmed at, looked up on the node by its kernel. Coinbase
+/// rewards log no kernel, theirs is the height of the output they created. `None` while
+/// unconfirmed, or when the node can't tell.
+pub fn tx_confirmed_height(
+    w: &dyn WalletBackend,
+    tx: &TxLogEntry,
+    outputs: &[OutputCommitMapping],
+) -> Option<u64> {
+    if !tx.confirmed {
+        return None;
+    }
+    if tx.tx_type == TxLogEntryType::ConfirmedCoinbase {
+        return coinbase_height(tx, outputs);
+    }
+    let excess = tx.kernel_excess?;
+    match w.kernel_height(excess, tx.kernel_lookup_min_height) {
+        Ok(height) => height,
+        Err(e) => {
+            warn!(
+                "Unable to look up the kernel of transaction {}: {}",
+                tx.id, e
+            );
+            None
+        }
+    }
+}
+
+/// Height a coinbase reward was mined at, going by the output it created
+pub fn coinbase_height(tx: &TxLogEntry, outputs: &[OutputCommitMapping]) -> Option<u64> {
+    outputs
+        .iter()
+        .find(|o| o.output.tx_log_entry == Some(tx.id))
+        .map(|o| o.output.height)
+}
+
+/// Number of confirmations of something confirmed at `height`, with the chain at `tip`
+pub fn confirmations(height: u64, tip: u64) -> u64 {
+    if height > tip {
+        0
+    } else {
+        tip - height + 1
+    }
+}
+
+/// How far a transaction has come towards counting as confirmed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxMaturity {
+    Unconfirmed,
+    /// In a block, with fewer confirmations than the wallet asks for
+    Confirming(u64),
+    /// Coinbase reward that can't be spent for this many more blocks
+    Immature(u64),
+    Confirmed,
+    /// Confirmed according to the wallet, at a height the node can't tell
+    Unknown,
+}
+
+/// Confirmations of the transaction with the chain at `tip`, given the height it was
+/// confirmed at. `None` when it's confirmed but that height isn't known.
+pub fn tx_confirmations(tx: &TxLogEntry, confirmed_height: Option<u64>, tip: u64) -> Option<u64> {
+    if !tx.confirmed {
+        return Some(0);
+    }
+    confirmed_height.map(|height| confirmations(height, tip))
+}
+
+/// Where the transaction stands with the chain at `tip`, when `threshold` confirmations
+/// count as confirmed
+pub fn tx_maturity(
+    tx: &TxLogEntry,
+    confirmed_height: Option<u64>,
+    tip: u64,
+    threshold: u64,
+) -> TxMaturity {
+    if !tx.confirmed {
+        return TxMaturity::Unconfirmed;
+    }
+    let height = match confirmed_height {
+        Some(height) => height,
+        None => return TxMaturity::Unknown,
+    };
+    if tx.tx_type == TxLogEntryType::ConfirmedCoinbase && tip < height + COINBASE_MATURITY {
+        return TxMaturity::Immature(height + COINBASE_MATURITY - tip);
+    }
+    match confirmations(height, tip) {
+        count if count < threshold => TxMaturity::Confirming(count),
+        _ => TxMaturity::Confirmed,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        confirmations, tx_can_repost, tx_can_resend_slatepack, tx_confirmations,
+        tx_confirmed_height, tx_maturity, TxMaturity, COINBASE_MATURITY,
+    };
+    use crate::wallet::{
+        global, MockWallet, OutputStatus, TxLogEntry, TxLogEntryType, WalletBackend,
+    };
+    use grin_keychain::Identifier;
+    use grin_util::secp::pedersen::Commitment;
+    use grin_util::ToHex;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_tx_confirmations() {
+        let mut w = MockWallet::new("password");
+        w.open_wallet(
+            "password".to_owned(),
+            PathBuf::new(),
+            global::ChainTypes::Testnet,
+        )
+        .unwrap();
+        let excess = Commitment::from_vec(vec![7; 33]);
+        w.kernel_heights.insert(excess.0.to_vec().to_hex(), 98);
+
+        let mut tx = TxLogEntry::new(Identifier::zero(), TxLogEntryType::TxReceived, 3);
+        let mut change = MockWallet::output(0, 10, OutputStatus::Unspent, Some(3));
+        change.output.height = 100;
+        let other = MockWallet::output(1, 10, OutputStatus::Unspent, Some(4));
+        let outputs = vec![other, change];
+
+        assert_eq!(tx_confirmed_height(&w, &tx, &outputs), None);
+        // The kernel's height counts, not that of the outputs, and none without a kernel
+        tx.confirmed = true;
+        assert_eq!(tx_confirmed_height(&w, &tx, &outputs), None);
+        tx.kernel_excess = Some(excess);
+        assert_eq!(tx_confirmed_height(&w, &tx, &outputs), Some(98));
+        tx.kernel_lookup_min_height = Some(99);
+        assert_eq!(tx_confirmed_height(&w, &tx, &outputs), None);
+
+        // Rewards have no kernel logged, they confirmed with their output
+        let mut reward = TxLogEntry::new(Identifier::zero(), TxLogEntryType::ConfirmedCoinbase, 3);
+        reward.confirmed = true;
+        assert_eq!(tx_confirmed_height(&w, &reward, &outputs), Some(100));
+        reward.confirmed = false;
+        assert_eq!(tx_confirmed_height(&w, &reward, &outputs), None);
+
+        assert_eq!(confirmations(100, 100), 1);
+        assert_eq!(confirmations(100, 109), 10);
+        assert_eq!(confirmations(101, 100), 0);
+    }
+
+    #[test]
+    fn test_tx_maturity() {
+        let mut tx = TxLogEntry::new(Identifier::zero(), TxLogEntryType::TxReceived, 3);
+        assert_eq!(tx_confirmations(&tx, None, 100), Some(0));
+        assert_eq!(tx_maturity(&tx, None, 100, 10), TxMaturity::Unconfirmed);
+
+        tx.confirmed = true;
+        assert_eq!(tx_confirmations(&tx, None, 100), None);
+        assert_eq!(tx_maturity(&tx, None, 100, 10), TxMaturity::Unknown);
+        assert_eq!(tx_confirmations(&tx, Some(95), 100), Some(6));
+        assert_eq!(
+            tx_maturity(&tx, Some(95), 100, 10),
+            TxMaturity::Confirming(6)
+        );
+        assert_eq!(tx_maturity(&tx, Some(95), 100, 6), TxMaturity::Confirmed);
+
+        // Rewards count down to being spendable, whatever the threshold
+        tx.tx_type = TxLogEntryType::ConfirmedCoinbase;
+        assert_eq!(
+            tx_maturity(&tx, Some(95), 100, 1),
+            TxMaturity::Immature(1435)
+        );
+        assert_eq!(
+            tx_maturity(&tx, Some(95), 95 + COINBASE_MATURITY, 10),
+            TxMaturity::Confirmed
+        );
+    }
+
+    #[test]
+    fn test_tx_can_repost_or_resend() {
+        // A send waiting on the response, then finalized, then confirmed
+        let mut tx = TxLogEntry::new(Identifier::zero(), TxLogEntryType::TxSent, 3);
+        assert!(!tx_can_resend_slatepack(&tx));
+        tx.stored_tx = Some("tx.grintx".to_owned());
+        assert!(tx_can_resend_slatepack(&tx));
+        assert!(!tx_can_repost(&tx));
+        tx.kernel_excess = Some(Commitment::from_vec(vec![7; 33]));
+        assert!(!tx_can_resend_slatepack(&tx));
+        assert!(tx_can_repost(&tx));
+        tx.confirmed = true;
+        assert!(!tx_can_repost(&tx));
+
+        // Nothing goes back to the other party once cancelled
+        tx.tx_type = TxLogEntryType::TxSentCancelled;
+        tx.confirmed = false;
+        tx.kernel_excess = None;
+        assert!(!tx_can_resend_slatepack(&tx));
+        tx.tx_type = TxLogEntryType::TxReceived;
+        assert!(tx_can_resend_slatepack(&tx));
+    }
+}
@@ -0,0 +1,206 @@
+use chrono::{DateTime, Utc};
+use grin_core::core::amount_to_hr_string;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::{tx_kernel_excess_hex, TxLogEntry, TxLogEntryType};
+use crate::config::TxNote;
+use crate::error::GrinWalletInterfaceError;
+
+/// File formats transaction history can be exported to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxHistoryFormat {
+    Csv,
+    Json,
+}
+
+impl TxHistoryFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            TxHistoryFormat::Csv => "csv",
+            TxHistoryFormat::Json => "json",
+        }
+    }
+}
+
+/// A transaction as written to history exports, with every column the tx list can show.
+/// Amounts are in GRIN, as strings so no precision is lost on the way to a spreadsheet.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TxHistoryRecord {
+    pub id: u32,
+    pub tx_type: String,
+    pub shared_id: Option<String>,
+    pub creation_time: DateTime<Utc>,
+    pub status: String,
+    pub ttl_cutoff_height: Option<u64>,
+    pub height: Option<u64>,
+    pub is_confirmed: bool,
+    pub confirmation_time: Option<DateTime<Utc>>,
+    pub num_inputs: usize,
+    pub num_outputs: usize,
+    pub amount_credited: String,
+    pub amount_debited: String,
+    pub fee: Option<String>,
+    pub net_difference: String,
+    pub payment_proof: bool,
+    pub kernel_excess: Option<String>,
+    pub stored_tx: Option<String>,
+    pub notes: String,
+    pub tags: Vec<String>,
+}
+
+/// CSV header, in the same order as the fields of [`TxHistoryRecord`]
+const CSV_HEADER: [&str; 20] = [
+    "id",
+    "tx_type",
+    "shared_id",
+    "creation_time",
+    "status",
+    "ttl_cutoff_height",
+    "height",
+    "is_confirmed",
+    "confirmation_time",
+    "num_inputs",
+    "num_outputs",
+    "amount_credited",
+    "amount_debited",
+    "fee",
+    "net_difference",
+    "payment_proof",
+    "kernel_excess",
+    "stored_tx",
+    "notes",
+    "tags",
+];
+
+impl TxHistoryRecord {
+    pub fn new(tx: &TxLogEntry, note: Option<&TxNote>) -> Self {
+        let status = match tx.tx_type {
+            TxLogEntryType::TxSentCancelled | TxLogEntryType::TxReceivedCancelled => "cancelled",
+            _ if tx.confirmed => "confirmed",
+            _ => "unconfirmed",
+        };
+        let net_difference = if tx.amount_credited >= tx.amount_debited {
+            amount_to_hr_string(tx.amount_credited - tx.amount_debited, false)
+        } else {
+            format!(
+                "-{}",
+                amount_to_hr_string(tx.amount_debited - tx.amount_credited, false)
+            )
+        };
+
+        TxHistoryRecord {
+            id: tx.id,
+            tx_type: tx.tx_type.to_string().replace('\n', ""),
+            shared_id: tx.tx_slate_id.map(|id| id.to_string()),
+            creation_time: tx.creation_ts,
+            status: status.to_owned(),
+            ttl_cutoff_height: tx.ttl_cutoff_height,
+            height: tx.kernel_lookup_min_height,
+            is_confirmed: tx.confirmed,
+            confirmation_time: tx.confirmation_ts,
+            num_inputs: tx.num_inputs,
+            num_outputs: tx.num_outputs,
+            amount_credited: amount_to_hr_string(tx.amount_credited, false),
+            amount_debited: amount_to_hr_string(tx.amount_debited, false),
+            fee: tx.fee.map(|f| amount_to_hr_string(f.fee(), false)),
+            net_difference,
+            payment_proof: tx.payment_proof.is_some(),
+            kernel_excess: tx_kernel_excess_hex(tx),
+            stored_tx: tx.stored_tx.clone(),
+            notes: note.map(|n| n.text.clone()).unwrap_or_default(),
+            tags: note.map(|n| n.tags.clone()).unwrap_or_default(),
+        }
+    }
+
+    fn csv_fields(&self) -> [String; 20] {
+        let opt = |o: &Option<String>| o.clone().unwrap_or_default();
+        let opt_num = |o: Option<u64>| o.map(|n| n.to_string()).unwrap_or_default();
+        let opt_time = |o: Option<DateTime<Utc>>| o.map(|t| t.to_rfc3339()).unwrap_or_default();
+
+        [
+            self.id.to_string(),
+            self.tx_type.clone(),
+            opt(&self.shared_id),
+            self.creation_time.to_rfc3339(),
+            self.status.clone(),
+            opt_num(self.ttl_cutoff_height),
+            opt_num(self.height),
+            self.is_confirmed.to_string(),
+            opt_time(self.confirmation_time),
+            self.num_inputs.to_string(),
+            self.num_outputs.to_string(),
+            self.amount_credited.clone(),
+            self.amount_debited.clone(),
+            opt(&self.fee),
+            self.net_difference.clone(),
+            self.payment_proof.to_string(),
+            opt(&self.kernel_excess),
+            opt(&self.stored_tx),
+            self.notes.clone(),
+            self.tags.join(", "),
+        ]
+    }
+}
+
+/// Quotes a CSV field if it holds anything that would break the row apart
+fn csv_escape(field: &str) -> String {
+    if field.contains(|c| matches!(c, ',' | '"' | '\n' | '\r')) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+pub fn tx_history_csv(records: &[TxHistoryRecord]) -> String {
+    let mut csv = CSV_HEADER.join(",");
+    csv.push_str("\r\n");
+    for record in records {
+        let fields: Vec<String> = record.csv_fields().iter().map(|f| csv_escape(f)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+pub fn write_tx_history(
+    path: &Path,
+    records: &[TxHistoryRecord],
+    format: TxHistoryFormat,
+) -> Result<(), GrinWalletInterfaceError> {
+    let contents = match format {
+        TxHistoryFormat::Csv => tx_history_csv(records),
+        TxHistoryFormat::Json => serde_json::to_string_pretty(records)?,
+    };
+    std::fs::write(path, contents)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{csv_escape, tx_history_csv, TxHistoryRecord};
+    use crate::config::TxNote;
+    use crate::wallet::{TxLogEntry, TxLogEntryType};
+    use grin_keychain::Identifier;
+
+    #[test]
+    fn test_tx_history_csv() {
+        assert_eq!(csv_escape("plain"), "plain");
+        assert_eq!(csv_escape("a, \"b\""), "\"a, \"\"b\"\"\"");
+
+        let mut tx = TxLogEntry::new(Identifier::zero(), TxLogEntryType::TxSent, 7);
+        tx.amount_debited = 1_500_000_000;
+        tx.amount_credited = 500_000_000;
+        let note = TxNote::new("Rent, March", "rent, office");
+        let record = TxHistoryRecord::new(&tx, Some(&note));
+        assert_eq!(record.net_difference, "-1.000000000");
+        assert_eq!(record.status, "unconfirmed");
+
+        let csv = tx_history_csv(&[record]);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("id,tx_type,shared_id,"));
+        assert!(lines[1].starts_with("7,"));
+        assert!(lines[1].ends_with(",\"Rent, March\",\"rent, office\""));
+    }
+}
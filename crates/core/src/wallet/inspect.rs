@@ -0,0 +1,185 @@
+use super::{Slate, SlateState, Slatepack, SlatepackAddress, TxLogEntry};
+
+/// Reasons a decoded slatepack can't be applied by this wallet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SlatepackProblem {
+    /// Sent from a wallet on another chain
+    WrongChain,
+    /// Nothing is left to do with the transaction
+    AlreadyFinalized,
+    /// This wallet already took this step of the transaction
+    AlreadyApplied,
+}
+
+/// What a slatepack holds, decoded and checked against this wallet before it's applied
+#[derive(Debug, Clone)]
+pub struct SlatepackInspection {
+    pub slatepack: Slatepack,
+    pub slate: Slate,
+    /// Transaction this wallet already holds for the slate
+    pub wallet_tx: Option<TxLogEntry>,
+    pub problems: Vec<SlatepackProblem>,
+}
+
+/// Human readable part of a slatepack address, which tells the chain apart
+fn address_hrp(address: &str) -> &str {
+    address.split('1').next().unwrap_or_default()
+}
+
+impl SlatepackInspection {
+    /// Checks a decoded slatepack against the wallet's own slatepack address and
+    /// transactions
+    pub fn new(
+        slatepack: Slatepack,
+        slate: Slate,
+        wallet_address: &str,
+        txs: &[TxLogEntry],
+    ) -> Self {
+        let wallet_tx = txs
+            .iter()
+            .find(|t| t.tx_slate_id == Some(slate.id))
+            .cloned();
+
+        let mut problems = vec![];
+        if let Some(sender) = slatepack.sender.as_ref() {
+            if address_hrp(&sender.to_string()) != address_hrp(wallet_address) {
+                problems.push(SlatepackProblem::WrongChain);
+            }
+        }
+        match slate.state {
+            SlateState::Standard3 | SlateState::Invoice3 => {
+                problems.push(SlatepackProblem::AlreadyFinalized)
+            }
+            SlateState::Standard2 | SlateState::Invoice2 => {
+                if wallet_tx
+                    .as_ref()
+                    .map_or(false, |t| t.confirmed || t.kernel_excess.is_some())
+                {
+                    problems.push(SlatepackProblem::AlreadyFinalized);
+                }
+            }
+            _ => {
+                if wallet_tx.is_some() {
+                    problems.push(SlatepackProblem::AlreadyApplied);
+                }
+            }
+        }
+
+        SlatepackInspection {
+            slatepack,
+            slate,
+            wallet_tx,
+            problems,
+        }
+    }
+
+    /// Whether only this wallet could have read the slatepack
+    pub fn is_encrypted(&self) -> bool {
+        self.slatepack.mode == 1
+    }
+
+    /// Whether the slate belongs with this wallet: a response to a transaction it
+    /// started, or a new one encrypted to its address. `None` for a new transaction sent
+    /// in the clear, which any wallet can take.
+    pub fn is_for_this_wallet(&self) -> Option<bool> {
+        match self.slate.state {
+            SlateState::Standard2
+            | SlateState::Invoice2
+            | SlateState::Standard3
+            | SlateState::Invoice3 => Some(self.wallet_tx.is_some()),
+            _ if self.is_encrypted() => Some(true),
+            _ => None,
+        }
+    }
+
+    pub fn fee(&self) -> u64 {
+        self.slate.fee_fields.fee()
+    }
+
+    /// Height past which the transaction won't be accepted, if it expires
+    pub fn ttl_cutoff_height(&self) -> Option<u64> {
+        Some(self.slate.ttl_cutoff_height).filter(|h| *h > 0)
+    }
+
+    /// Slatepack address a payment proof is requested for, if the sender asked for one
+    pub fn payment_proof_recipient(&self) -> Option<String> {
+        self.slate
+            .payment_proof
+            .as_ref()
+            .map(|p| SlatepackAddress::new(&p.receiver_address).to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{SlatepackInspection, SlatepackProblem};
+    use crate::wallet::mock::MOCK_SLATEPACK_ADDRESS;
+    use crate::wallet::{
+        Slate, SlateState, Slatepack, SlatepackAddress, TxLogEntry, TxLogEntryType,
+    };
+    use grin_keychain::Identifier;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_slatepack_inspection() {
+        let slatepack = Slatepack {
+            sender: Some(SlatepackAddress::try_from(MOCK_SLATEPACK_ADDRESS).unwrap()),
+            ..Default::default()
+        };
+        let mut slate = Slate::blank(2, false);
+        slate.state = SlateState::Standard1;
+
+        let inspection = SlatepackInspection::new(
+            slatepack.clone(),
+            slate.clone(),
+            MOCK_SLATEPACK_ADDRESS,
+            &[],
+        );
+        assert!(inspection.problems.is_empty());
+        assert_eq!(inspection.is_for_this_wallet(), None);
+        assert_eq!(inspection.ttl_cutoff_height(), None);
+
+        // A testnet wallet can't take a mainnet slatepack, nor one it already received
+        let mut tx = TxLogEntry::new(Identifier::zero(), TxLogEntryType::TxReceived, 0);
+        tx.tx_slate_id = Some(slate.id);
+        let inspection = SlatepackInspection::new(
+            slatepack.clone(),
+            slate.clone(),
+            "tgrin1p4fuklglxqsgg602hu4c4jl4aunu5tynyf4lkg96ezh3jefzpy6sxxxxxx",
+            &[tx.clone()],
+        );
+        assert_eq!(
+            inspection.problems,
+            vec![
+                SlatepackProblem::WrongChain,
+                SlatepackProblem::AlreadyApplied
+            ]
+        );
+
+        // A response is for the wallet that started the transaction, until finalized
+        slate.state = SlateState::Standard2;
+        tx.tx_type = TxLogEntryType::TxSent;
+        let inspection = SlatepackInspection::new(
+            slatepack.clone(),
+            slate.clone(),
+            MOCK_SLATEPACK_ADDRESS,
+            &[tx.clone()],
+        );
+        assert!(inspection.problems.is_empty());
+        assert_eq!(inspection.is_for_this_wallet(), Some(true));
+        let inspection = SlatepackInspection::new(
+            slatepack.clone(),
+            slate.clone(),
+            MOCK_SLATEPACK_ADDRESS,
+            &[],
+        );
+        assert_eq!(inspection.is_for_this_wallet(), Some(false));
+
+        tx.confirmed = true;
+        let inspection = SlatepackInspection::new(slatepack, slate, MOCK_SLATEPACK_ADDRESS, &[tx]);
+        assert_eq!(
+            inspection.problems,
+            vec![SlatepackProblem::AlreadyFinalized]
+        );
+    }
+}
@@ -0,0 +1,780 @@
+/// In-memory wallet backend, used to exercise the GUI without a wallet directory or node
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use futures::executor::block_on;
+use futures::SinkExt;
+use grin_core::libtx::tx_fee;
+use grin_keychain::{ExtKeychainPath, Identifier};
+use grin_util::secp::pedersen::Commitment;
+use grin_util::ToHex;
+use grin_wallet_libwallet::Error as LibWalletError;
+use iced_futures::futures::channel::mpsc;
+use serde_json::json;
+
+use crate::error::GrinWalletInterfaceError;
+
+use super::subscriber::UIMessage;
+use super::{
+    check_late_lock, global, output_commit_hex, AcctPathMapping, InitTxArgs, IssueInvoiceTxArgs,
+    OutputCommitMapping, OutputData, OutputStatus, PaymentProof, RemoteOwnerApi,
+    RetrieveTxQueryArgs, RetrieveTxQuerySortOrder, Slate, SlateState, Slatepack, StatusMessage,
+    TxEstimate, TxLogEntry, TxLogEntryType, WalletBackend, WalletInfo, ZeroingString,
+};
+
+pub const MOCK_SLATEPACK_ADDRESS: &str =
+    "grin1p4fuklglxqsgg602hu4c4jl4aunu5tynyf4lkg96ezh3jefzpy6swshp5x";
+
+pub struct MockWallet {
+    pub password: String,
+    pub recovery_phrase: String,
+    pub chain_type: global::ChainTypes,
+    pub address: String,
+    pub info: WalletInfo,
+    pub txs: Vec<TxLogEntry>,
+    pub outputs: Vec<OutputCommitMapping>,
+    /// Slatepacks `decrypt_slatepack` knows how to decode, keyed by armored message
+    pub slatepacks: HashMap<String, (Slatepack, Slate)>,
+    /// Armored slatepacks encrypted for some other wallet, which fail to decrypt
+    pub foreign_slatepacks: Vec<String>,
+    /// Messages pushed to the status sender on the next `poll_updater_status`, oldest first
+    pub updater_messages: Vec<StatusMessage>,
+    /// Outputs `scan` finds on chain and adds to the wallet
+    pub scan_restores: Vec<OutputCommitMapping>,
+    /// Payment proofs by transaction id. `verify_payment_proof` accepts any proof whose
+    /// excess is among them.
+    pub payment_proofs: HashMap<u32, PaymentProof>,
+    /// Slates stored when transactions locked their outputs, by transaction id
+    pub stored_txs: HashMap<u32, Slate>,
+    /// Transactions reposted so far, with whether they were fluffed
+    pub reposted_txs: Vec<(u32, bool)>,
+    /// Heights of the kernels on chain, by excess (hex)
+    pub kernel_heights: HashMap<String, u64>,
+    /// Accounts in creation order, starting with `default`
+    pub accounts: Vec<AcctPathMapping>,
+    pub active_account: String,
+    /// Names of the backend calls made so far, in order
+    pub calls: Vec<String>,
+    wallet_is_open: bool,
+    use_embedded_node: bool,
+    status_sender: Option<mpsc::Sender<UIMessage>>,
+}
+
+impl MockWallet {
+    pub fn new(password: &str) -> Self {
+        MockWallet {
+            password: password.to_owned(),
+            recovery_phrase: vec!["abandon"; 24].join(" "),
+            chain_type: global::ChainTypes::Testnet,
+            address: MOCK_SLATEPACK_ADDRESS.to_owned(),
+            info: WalletInfo {
+                last_confirmed_height: 1,
+                minimum_confirmations: 10,
+                total: 0,
+                amount_awaiting_finalization: 0,
+                amount_awaiting_confirmation: 0,
+                amount_immature: 0,
+                amount_currently_spendable: 0,
+                amount_locked: 0,
+                amount_reverted: 0,
+            },
+            txs: vec![],
+            outputs: vec![],
+            slatepacks: HashMap::new(),
+            foreign_slatepacks: vec![],
+            updater_messages: vec![],
+            scan_restores: vec![],
+            payment_proofs: HashMap::new(),
+            stored_txs: HashMap::new(),
+            reposted_txs: vec![],
+            kernel_heights: HashMap::new(),
+            accounts: vec![MockWallet::account_path("default", 0)],
+            active_account: "default".to_owned(),
+            calls: vec![],
+            wallet_is_open: false,
+            use_embedded_node: true,
+            status_sender: None,
+        }
+    }
+
+    /// Credits the wallet with a confirmed received transaction
+    pub fn with_received(mut self, amount: u64) -> Self {
+        let mut tx = MockWallet::tx(self.txs.len() as u32, TxLogEntryType::TxReceived);
+        tx.amount_credited = amount;
+        tx.confirmed = true;
+        // Kernel in the block of the output it created
+        let excess = Commitment::from_vec(vec![0x80 | tx.id as u8; 33]);
+        self.kernel_heights
+            .insert(excess.0.to_vec().to_hex(), self.info.last_confirmed_height);
+        tx.kernel_excess = Some(excess);
+        let output = MockWallet::output(
+            self.outputs.len() as u8,
+            amount,
+            OutputStatus::Unspent,
+            Some(tx.id),
+        );
+        self.outputs.push(output);
+        self.txs.push(tx);
+        self.info.total += amount;
+        self.info.amount_currently_spendable += amount;
+        self
+    }
+
+    /// Builds an unconfirmed transaction with nothing credited or debited yet
+    pub fn tx(id: u32, tx_type: TxLogEntryType) -> TxLogEntry {
+        TxLogEntry::new(Identifier::zero(), tx_type, id)
+    }
+
+    /// Builds an output with a commitment derived from `n`, so each one is distinct
+    pub fn output(
+        n: u8,
+        value: u64,
+        status: OutputStatus,
+        tx_log_entry: Option<u32>,
+    ) -> OutputCommitMapping {
+        let key_id = ExtKeychainPath::new(3, 0, n as u32, 0, 0).to_identifier();
+        let commit = Commitment::from_vec(vec![n; 33]);
+        OutputCommitMapping {
+            output: OutputData {
+                root_key_id: ExtKeychainPath::new(2, 0, 0, 0, 0).to_identifier(),
+                key_id,
+                n_child: n as u32,
+                commit: None,
+                mmr_index: None,
+                value,
+                status,
+                height: 1,
+                lock_height: 0,
+                is_coinbase: false,
+                tx_log_entry,
+            },
+            commit,
+        }
+    }
+
+    /// Builds a payment proof between two addresses, with placeholder excess and
+    /// signatures
+    pub fn payment_proof(amount: u64, sender: &str, recipient: &str) -> PaymentProof {
+        serde_json::from_value(json!({
+            "amount": amount.to_string(),
+            "excess": format!("08{}", "00".repeat(32)),
+            "recipient_address": recipient,
+            "recipient_sig": "00".repeat(64),
+            "sender_address": sender,
+            "sender_sig": "00".repeat(64),
+        }))
+        .unwrap()
+    }
+
+    fn account_path(label: &str, index: u32) -> AcctPathMapping {
+        AcctPathMapping {
+            label: label.to_owned(),
+            path: ExtKeychainPath::new(2, index, 0, 0, 0).to_identifier(),
+        }
+    }
+
+    /// What the wallet keeps of a slate, as `get_stored_tx` gives it back: the id and
+    /// transaction, none of the participant data or the stage it was at
+    fn stored_copy(slate: &Slate) -> Slate {
+        let mut stored = Slate::blank(2, false);
+        stored.id = slate.id;
+        stored.state = SlateState::Unknown;
+        stored.tx = slate.tx.clone();
+        stored
+    }
+
+    fn record(&mut self, call: &str) {
+        self.calls.push(call.to_owned());
+    }
+
+    fn check_open(&self) -> Result<(), GrinWalletInterfaceError> {
+        if self.wallet_is_open {
+            Ok(())
+        } else {
+            Err(GrinWalletInterfaceError::OwnerAPINotInstantiated)
+        }
+    }
+
+    fn generic_error(msg: &str) -> GrinWalletInterfaceError {
+        GrinWalletInterfaceError::WalletLibWallet(LibWalletError::GenericError(msg.to_owned()))
+    }
+
+    fn armor(slate: &Slate, dest: &str) -> String {
+        format!("BEGINSLATEPACK. mock {} {} ENDSLATEPACK.", slate.id, dest)
+    }
+}
+
+impl WalletBackend for MockWallet {
+    fn wallet_is_open(&self) -> bool {
+        self.wallet_is_open
+    }
+
+    fn set_use_embedded_node(&mut self, value: bool) {
+        self.use_embedded_node = value;
+    }
+
+    fn set_check_node_foreign_api_secret_path(&mut self, _path: Option<String>) {}
+
+    fn init(
+        &mut self,
+        password: String,
+        top_level_directory: PathBuf,
+        display_name: String,
+        chain_type: global::ChainTypes,
+        recovery_phrase: Option<String>,
+    ) -> Result<(String, String, String, global::ChainTypes), GrinWalletInterfaceError> {
+        self.record("init");
+        self.password = password;
+        self.chain_type = chain_type;
+        if let Some(phrase) = recovery_phrase {
+            self.recovery_phrase = phrase;
+        }
+        Ok((
+            top_level_directory.display().to_string(),
+            self.recovery_phrase.clone(),
+            display_name,
+            chain_type,
+        ))
+    }
+
+    fn open_wallet(
+        &mut self,
+        password: String,
+        _top_level_directory: PathBuf,
+        chain_type: global::ChainTypes,
+    ) -> Result<(), GrinWalletInterfaceError> {
+        self.record("open_wallet");
+        if password != self.password {
+            return Err(MockWallet::generic_error("Invalid password"));
+        }
+        self.chain_type = chain_type;
+        self.wallet_is_open = true;
+        Ok(())
+    }
+
+    fn open_remote_wallet(
+        &mut self,
+        password: String,
+        _remote_api: RemoteOwnerApi,
+        chain_type: global::ChainTypes,
+    ) -> Result<(), GrinWalletInterfaceError> {
+        self.open_wallet(password, PathBuf::new(), chain_type)
+    }
+
+    fn close_wallet(&mut self) -> Result<(), GrinWalletInterfaceError> {
+        self.record("close_wallet");
+        self.wallet_is_open = false;
+        Ok(())
+    }
+
+    fn change_password(
+        &mut self,
+        old_password: ZeroingString,
+        new_password: ZeroingString,
+    ) -> Result<(), GrinWalletInterfaceError> {
+        self.check_open()?;
+        self.record("change_password");
+        if &*old_password != self.password {
+            return Err(MockWallet::generic_error("Invalid password"));
+        }
+        self.password = new_password.to_string();
+        Ok(())
+    }
+
+    fn get_recovery_phrase(
+        &self,
+        password: ZeroingString,
+    ) -> Result<ZeroingString, GrinWalletInterfaceError> {
+        self.check_open()?;
+        if &*password != self.password {
+            return Err(MockWallet::generic_error("Invalid password"));
+        }
+        Ok(self.recovery_phrase.clone().into())
+    }
+
+    fn set_status_sender(&mut self, sender: mpsc::Sender<UIMessage>) {
+        self.status_sender = Some(sender);
+    }
+
+    fn poll_updater_status(&mut self) -> Result<(), GrinWalletInterfaceError> {
+        self.check_open()?;
+        if let Some(s) = self.status_sender.as_mut() {
+            for m in self.updater_messages.drain(..) {
+                let _ = block_on(s.send(UIMessage::UpdaterStatus(m)));
+            }
+        }
+        Ok(())
+    }
+
+    fn get_wallet_info(&self) -> Result<(bool, WalletInfo), GrinWalletInterfaceError> {
+        self.check_open()?;
+        Ok((true, self.info.clone()))
+    }
+
+    fn get_txs(
+        &self,
+        query_args: Option<RetrieveTxQueryArgs>,
+    ) -> Result<(bool, Vec<TxLogEntry>), GrinWalletInterfaceError> {
+        self.check_open()?;
+        let mut txs = self.txs.clone();
+        if let Some(q) = query_args {
+            if q.exclude_cancelled == Some(true) {
+                txs.retain(|t| {
+                    t.tx_type != TxLogEntryType::TxReceivedCancelled
+                        && t.tx_type != TxLogEntryType::TxSentCancelled
+                });
+            }
+            if q.include_outstanding_only == Some(true) {
+                txs.retain(|t| !t.confirmed);
+            }
+            if let Some(RetrieveTxQuerySortOrder::Desc) = q.sort_order {
+                txs.reverse();
+            }
+        }
+        Ok((true, txs))
+    }
+
+    fn get_slatepack_address(&self) -> Result<String, GrinWalletInterfaceError> {
+        self.check_open()?;
+        Ok(self.address.clone())
+    }
+
+    fn get_outputs(
+        &self,
+        include_spent: bool,
+    ) -> Result<(bool, Vec<OutputCommitMapping>), GrinWalletInterfaceError> {
+        self.check_open()?;
+        let mut outputs = self.outputs.clone();
+        if !include_spent {
+            outputs.retain(|o| o.output.status != OutputStatus::Spent);
+        }
+        Ok((true, outputs))
+    }
+
+    fn accounts(&self) -> Result<Vec<AcctPathMapping>, GrinWalletInterfaceError> {
+        self.check_open()?;
+        Ok(self.accounts.clone())
+    }
+
+    fn create_account(&mut self, label: String) -> Result<(), GrinWalletInterfaceError> {
+        self.check_open()?;
+        self.record("create_account");
+        if self.accounts.iter().any(|a| a.label == label) {
+            return Err(MockWallet::generic_error("Account label already exists"));
+        }
+        let index = self.accounts.len() as u32;
+        self.accounts.push(MockWallet::account_path(&label, index));
+        Ok(())
+    }
+
+    fn set_active_account(&mut self, label: String) -> Result<(), GrinWalletInterfaceError> {
+        self.check_open()?;
+        self.record("set_active_account");
+        if !self.accounts.iter().any(|a| a.label == label) {
+            return Err(MockWallet::generic_error("Unknown account"));
+        }
+        self.active_account = label;
+        Ok(())
+    }
+
+    fn create_tx(
+        &mut self,
+        init_args: InitTxArgs,
+        dest_slatepack_address: String,
+        selected_outputs: Option<Vec<String>>,
+    ) -> Result<(Slate, String), GrinWalletInterfaceError> {
+        self.check_open()?;
+        self.record("create_tx");
+        if !dest_slatepack_address.starts_with("grin") && !dest_slatepack_address.starts_with("tgrin")
+        {
+            return Err(GrinWalletInterfaceError::InvalidSlatepackAddress);
+        }
+        check_late_lock(&init_args, &selected_outputs)?;
+        if init_args.amount > self.info.amount_currently_spendable {
+            return Err(MockWallet::generic_error("Not enough funds"));
+        }
+        if let Some(commits) = selected_outputs {
+            let height = self.info.last_confirmed_height;
+            let mut selected = vec![];
+            for commit in commits.iter() {
+                match self.outputs.iter().position(|o| {
+                    o.output
+                        .eligible_to_spend(height, init_args.minimum_confirmations)
+                        && output_commit_hex(o) == *commit
+                }) {
+                    Some(i) => selected.push(i),
+                    None => {
+                        return Err(GrinWalletInterfaceError::OutputNotSpendable(commit.clone()))
+                    }
+                }
+            }
+            let values: Vec<u64> = selected
+                .iter()
+                .map(|i| self.outputs[*i].output.value)
+                .collect();
+            if TxEstimate::from_outputs(
+                init_args.amount,
+                init_args.num_change_outputs as usize,
+                &values,
+            )
+            .is_none()
+            {
+                return Err(GrinWalletInterfaceError::SelectedOutputsInsufficient);
+            }
+            for i in selected {
+                self.outputs[i].output.status = OutputStatus::Locked;
+            }
+        }
+
+        let mut slate = Slate::blank(2, false);
+        slate.amount = init_args.amount;
+
+        let mut tx = TxLogEntry::new(
+            Identifier::zero(),
+            TxLogEntryType::TxSent,
+            self.txs.len() as u32,
+        );
+        tx.tx_slate_id = Some(slate.id);
+        tx.amount_debited = init_args.amount;
+        tx.stored_tx = Some(format!("{}.grintx", slate.id));
+        self.stored_txs
+            .insert(tx.id, MockWallet::stored_copy(&slate));
+        self.txs.push(tx);
+
+        self.info.amount_currently_spendable -= init_args.amount;
+        self.info.amount_locked += init_args.amount;
+
+        let slatepack = MockWallet::armor(&slate, &dest_slatepack_address);
+        Ok((slate, slatepack))
+    }
+
+    fn estimate_tx(
+        &self,
+        init_args: InitTxArgs,
+        selected_outputs: Option<Vec<String>>,
+    ) -> Result<TxEstimate, GrinWalletInterfaceError> {
+        self.check_open()?;
+        check_late_lock(&init_args, &selected_outputs)?;
+        let unspent = self
+            .outputs
+            .iter()
+            .filter(|o| o.output.status == OutputStatus::Unspent);
+        let candidates: Vec<&OutputCommitMapping> = match selected_outputs {
+            Some(commits) => {
+                // Only outputs with the confirmations asked for, as the real wallet
+                let height = self.info.last_confirmed_height;
+                let selected: Vec<&OutputCommitMapping> = unspent
+                    .filter(|o| {
+                        o.output
+                            .eligible_to_spend(height, init_args.minimum_confirmations)
+                            && commits.contains(&output_commit_hex(o))
+                    })
+                    .collect();
+                if let Some(c) = commits
+                    .iter()
+                    .find(|c| !selected.iter().any(|o| output_commit_hex(o) == **c))
+                {
+                    return Err(GrinWalletInterfaceError::OutputNotSpendable(c.clone()));
+                }
+                // Chosen outputs are all spent, as the real wallet does
+                let values: Vec<u64> = selected.iter().map(|o| o.output.value).collect();
+                return TxEstimate::from_outputs(
+                    init_args.amount,
+                    init_args.num_change_outputs as usize,
+                    &values,
+                )
+                .ok_or(GrinWalletInterfaceError::SelectedOutputsInsufficient);
+            }
+            None => unspent.collect(),
+        };
+
+        // Take outputs in order until they cover amount and fee, all of them when asked to.
+        // An exact match needs no change output, so pays the smaller fee.
+        let num_outputs = init_args.num_change_outputs as usize + 1;
+        let num_candidates = candidates.len();
+        let mut total_inputs = 0;
+        let mut num_inputs = 0;
+        for o in candidates {
+            total_inputs += o.output.value;
+            num_inputs += 1;
+            if init_args.selection_strategy_is_use_all && num_inputs < num_candidates {
+                continue;
+            }
+            let fee = if total_inputs == init_args.amount + tx_fee(num_inputs, 1, 1) {
+                tx_fee(num_inputs, 1, 1)
+            } else {
+                tx_fee(num_inputs, num_outputs, 1)
+            };
+            if total_inputs >= init_args.amount + fee {
+                let num_change_outputs = if total_inputs > init_args.amount + fee {
+                    init_args.num_change_outputs as usize
+                } else {
+                    0
+                };
+                return Ok(TxEstimate {
+                    amount: init_args.amount,
+                    fee,
+                    total_inputs,
+                    num_inputs,
+                    num_change_outputs,
+                });
+            }
+        }
+        Err(MockWallet::generic_error("Not enough funds"))
+    }
+
+    fn issue_invoice(
+        &mut self,
+        args: IssueInvoiceTxArgs,
+        dest_slatepack_address: String,
+    ) -> Result<(Slate, String), GrinWalletInterfaceError> {
+        self.check_open()?;
+        self.record("issue_invoice");
+        if !dest_slatepack_address.starts_with("grin") && !dest_slatepack_address.starts_with("tgrin")
+        {
+            return Err(GrinWalletInterfaceError::InvalidSlatepackAddress);
+        }
+
+        let mut slate = Slate::blank(2, true);
+        slate.amount = args.amount;
+
+        let mut tx = TxLogEntry::new(
+            Identifier::zero(),
+            TxLogEntryType::TxReceived,
+            self.txs.len() as u32,
+        );
+        tx.tx_slate_id = Some(slate.id);
+        tx.amount_credited = args.amount;
+        self.txs.push(tx);
+
+        let slatepack = MockWallet::armor(&slate, &dest_slatepack_address);
+        Ok((slate, slatepack))
+    }
+
+    fn process_invoice(
+        &mut self,
+        mut slate: Slate,
+        _init_args: InitTxArgs,
+        dest_slatepack_address: String,
+    ) -> Result<String, GrinWalletInterfaceError> {
+        self.check_open()?;
+        self.record("process_invoice");
+        if slate.state != SlateState::Invoice1 {
+            return Err(MockWallet::generic_error("Not an invoice"));
+        }
+        if slate.amount > self.info.amount_currently_spendable {
+            return Err(MockWallet::generic_error("Not enough funds"));
+        }
+
+        let mut tx = TxLogEntry::new(
+            Identifier::zero(),
+            TxLogEntryType::TxSent,
+            self.txs.len() as u32,
+        );
+        tx.tx_slate_id = Some(slate.id);
+        tx.amount_debited = slate.amount;
+        self.txs.push(tx);
+
+        self.info.amount_currently_spendable -= slate.amount;
+        self.info.amount_locked += slate.amount;
+
+        slate.state = SlateState::Invoice2;
+        Ok(MockWallet::armor(&slate, &dest_slatepack_address))
+    }
+
+    fn finalize_invoice(&mut self, slate: Slate) -> Result<(), GrinWalletInterfaceError> {
+        self.check_open()?;
+        self.record("finalize_invoice");
+        if slate.state != SlateState::Invoice2 {
+            return Err(MockWallet::generic_error("Not a paid invoice"));
+        }
+        match self.txs.iter().find(|t| t.tx_slate_id == Some(slate.id)) {
+            Some(_) => Ok(()),
+            None => Err(MockWallet::generic_error("Unknown slate")),
+        }
+    }
+
+    fn receive_tx_from_s1(
+        &mut self,
+        slate: Slate,
+        dest_slatepack_address: String,
+    ) -> Result<Option<String>, GrinWalletInterfaceError> {
+        self.check_open()?;
+        self.record("receive_tx_from_s1");
+
+        let mut tx = TxLogEntry::new(
+            Identifier::zero(),
+            TxLogEntryType::TxReceived,
+            self.txs.len() as u32,
+        );
+        tx.tx_slate_id = Some(slate.id);
+        tx.amount_credited = slate.amount;
+        tx.stored_tx = Some(format!("{}.grintx", slate.id));
+        self.stored_txs
+            .insert(tx.id, MockWallet::stored_copy(&slate));
+        self.txs.push(tx);
+        self.info.amount_awaiting_confirmation += slate.amount;
+
+        Ok(Some(MockWallet::armor(&slate, &dest_slatepack_address)))
+    }
+
+    fn finalize_from_s2(
+        &mut self,
+        slate: Slate,
+        _send_to_chain: bool,
+    ) -> Result<Option<String>, GrinWalletInterfaceError> {
+        self.check_open()?;
+        self.record("finalize_from_s2");
+        // Confirmation is up to the chain, so the entry stays outstanding
+        match self.txs.iter_mut().find(|t| t.tx_slate_id == Some(slate.id)) {
+            Some(tx) => {
+                // Placeholder excess, it's only ever compared
+                tx.kernel_excess = Some(Commitment::from_vec(vec![9; 33]));
+                self.stored_txs
+                    .insert(tx.id, MockWallet::stored_copy(&slate));
+                Ok(None)
+            }
+            None => Err(MockWallet::generic_error("Unknown slate")),
+        }
+    }
+
+    fn scan(
+        &mut self,
+        _start_height: Option<u64>,
+        _delete_unconfirmed: bool,
+    ) -> Result<(), GrinWalletInterfaceError> {
+        self.check_open()?;
+        self.record("scan");
+        if let Some(s) = self.status_sender.as_mut() {
+            for m in [
+                StatusMessage::Scanning("Scanning".to_owned(), 50),
+                StatusMessage::ScanningComplete("Scanning complete".to_owned()),
+            ] {
+                let _ = block_on(s.send(UIMessage::UpdaterStatus(m)));
+            }
+        }
+        // Locked outputs without a pending transaction are released
+        for o in self.outputs.iter_mut() {
+            if o.output.status == OutputStatus::Locked {
+                o.output.status = OutputStatus::Unspent;
+                self.info.amount_locked -= o.output.value;
+                self.info.amount_currently_spendable += o.output.value;
+            }
+        }
+        for o in self.scan_restores.iter() {
+            self.info.total += o.output.value;
+            self.info.amount_currently_spendable += o.output.value;
+        }
+        self.outputs.append(&mut self.scan_restores);
+        Ok(())
+    }
+
+    fn retrieve_payment_proof(&self, tx_id: u32) -> Result<PaymentProof, GrinWalletInterfaceError> {
+        self.check_open()?;
+        match self.payment_proofs.get(&tx_id) {
+            Some(p) => Ok(p.clone()),
+            None => Err(MockWallet::generic_error(
+                "No payment proof for transaction",
+            )),
+        }
+    }
+
+    fn verify_payment_proof(
+        &self,
+        proof: PaymentProof,
+    ) -> Result<(bool, bool), GrinWalletInterfaceError> {
+        self.check_open()?;
+        if !self
+            .payment_proofs
+            .values()
+            .any(|p| p.excess == proof.excess)
+        {
+            return Err(MockWallet::generic_error("Kernel not found on chain"));
+        }
+        Ok((
+            proof.sender_address.to_string() == self.address,
+            proof.recipient_address.to_string() == self.address,
+        ))
+    }
+
+    fn cancel_tx(&mut self, id: u32) -> Result<u32, GrinWalletInterfaceError> {
+        self.check_open()?;
+        self.record("cancel_tx");
+        let tx = match self.txs.iter_mut().find(|t| t.id == id) {
+            Some(tx) => tx,
+            None => return Err(MockWallet::generic_error("Unknown transaction")),
+        };
+        if tx.confirmed {
+            return Err(MockWallet::generic_error("Transaction already confirmed"));
+        }
+        match tx.tx_type {
+            TxLogEntryType::TxSent => {
+                tx.tx_type = TxLogEntryType::TxSentCancelled;
+                self.info.amount_locked -= tx.amount_debited;
+                self.info.amount_currently_spendable += tx.amount_debited;
+            }
+            TxLogEntryType::TxReceived => {
+                tx.tx_type = TxLogEntryType::TxReceivedCancelled;
+                self.info.amount_awaiting_confirmation -= tx.amount_credited;
+            }
+            _ => return Err(MockWallet::generic_error("Transaction can't be cancelled")),
+        }
+        Ok(id)
+    }
+
+    fn decrypt_slatepack(
+        &self,
+        slatepack: String,
+    ) -> Result<(Slatepack, Slate), GrinWalletInterfaceError> {
+        self.check_open()?;
+        if self.foreign_slatepacks.contains(&slatepack) {
+            return Err(GrinWalletInterfaceError::WalletLibWallet(
+                LibWalletError::SlatepackDecryption("no matching recipient".to_owned()),
+            ));
+        }
+        match self.slatepacks.get(&slatepack) {
+            Some(s) => Ok(s.clone()),
+            None => Err(MockWallet::generic_error("Unable to decode slatepack")),
+        }
+    }
+
+    fn encrypt_slatepack(
+        &self,
+        slate: Slate,
+        dest_slatepack_address: Option<String>,
+    ) -> Result<String, GrinWalletInterfaceError> {
+        self.check_open()?;
+        Ok(MockWallet::armor(
+            &slate,
+            &dest_slatepack_address.unwrap_or_default(),
+        ))
+    }
+
+    fn get_stored_tx(&self, tx_id: u32) -> Result<Option<Slate>, GrinWalletInterfaceError> {
+        self.check_open()?;
+        Ok(self.stored_txs.get(&tx_id).cloned())
+    }
+
+    fn kernel_height(
+        &self,
+        excess: Commitment,
+        min_height: Option<u64>,
+    ) -> Result<Option<u64>, GrinWalletInterfaceError> {
+        self.check_open()?;
+        Ok(self
+            .kernel_heights
+            .get(&excess.0.to_vec().to_hex())
+            .copied()
+            .filter(|height| *height >= min_height.unwrap_or(0)))
+    }
+
+    fn repost_tx(&mut self, tx_id: u32, fluff: bool) -> Result<(), GrinWalletInterfaceError> {
+        self.check_open()?;
+        self.record("repost_tx");
+        if !self.stored_txs.contains_key(&tx_id) {
+            return Err(GrinWalletInterfaceError::StoredTxNotFound(tx_id));
+        }
+        self.reposted_txs.push((tx_id, fluff));
+        Ok(())
+    }
+}
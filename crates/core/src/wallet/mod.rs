@@ -1,40 +1,79 @@
 /// Placeholder for all wallet calls
-/// Should eventually feature async calls that work via local wallet or remote owner API
+/// Async calls that work via local wallet or remote owner API
 use grin_wallet::cmd::wallet_args::inst_wallet;
 use grin_wallet_api::{Foreign, Owner};
 use grin_wallet_config::{self, GlobalWalletConfig};
 use grin_wallet_controller::command::InitArgs;
 use grin_wallet_impls::DefaultLCProvider;
-use grin_wallet_libwallet::{NodeClient, WalletInst, WalletLCProvider};
+use grin_wallet_libwallet::{
+    api_impl::owner, NodeClient, Slatepacker, SlatepackerArgs, WalletBackend as LibWalletBackend,
+    WalletInst, WalletLCProvider, WalletOutputBatch,
+};
 
 pub use grin_core::global;
-use grin_core::{self};
+use grin_core::{self, consensus};
 use grin_keychain as keychain;
-use grin_util::{file, Mutex, ZeroingString};
+use grin_util::secp::pedersen::Commitment;
+use grin_util::{file, Mutex, ToHex};
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc};
+use std::thread;
 
-use std::path::PathBuf;
-use std::sync::{Arc, RwLock};
+use iced_futures::futures::channel::mpsc as iced_mpsc;
+use iced_futures::futures::SinkExt;
 
 use dirs;
 
 // Re-exports
 pub use global::ChainTypes;
+pub use grin_util::ZeroingString;
 pub use grin_wallet_impls::HTTPNodeClient;
 pub use grin_wallet_libwallet::{
-    InitTxArgs, RetrieveTxQueryArgs, RetrieveTxQuerySortOrder, Slate, SlateState, Slatepack,
+    AcctPathMapping, InitTxArgs, IssueInvoiceTxArgs, OutputCommitMapping, OutputData, OutputStatus,
+    PaymentProof, RetrieveTxQueryArgs, RetrieveTxQuerySortOrder, Slate, SlateState, Slatepack,
     SlatepackAddress, StatusMessage, TxLogEntry, TxLogEntryType, WalletInfo,
 };
 
 use crate::error::GrinWalletInterfaceError;
 use crate::logger;
 
+pub mod backend;
+pub mod details;
+pub mod export;
+pub mod inspect;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+pub mod remote;
+pub mod subscriber;
+pub mod worker;
+pub use backend::WalletBackend;
+pub use details::{
+    coinbase_height, confirmations, tx_can_repost, tx_can_resend_slatepack, tx_confirmations,
+    tx_confirmed_height, tx_counterparty_address, tx_kernel_excess_hex, tx_maturity,
+    StoredTxSummary, TxMaturity,
+};
+pub use export::{write_tx_history, TxHistoryFormat, TxHistoryRecord};
+pub use inspect::{SlatepackInspection, SlatepackProblem};
+#[cfg(any(test, feature = "mock"))]
+pub use mock::MockWallet;
+pub use remote::RemoteOwnerApi;
+pub use worker::{CancelToken, WalletWorker};
+
 use std::convert::TryFrom;
 
+/// Max updater messages fetched from a remote wallet per poll
+const REMOTE_UPDATER_MESSAGE_COUNT: u32 = 100;
+
 /// Wallet configuration file name
 pub const WALLET_CONFIG_FILE_NAME: &str = "grin-wallet.toml";
 
 const WALLET_LOG_FILE_NAME: &str = "grin-wallet.log";
 
+/// Start of an armored slatepack, anything else read from a file is taken as binary
+const SLATEPACK_ARMOR_HEADER: &str = "BEGINSLATEPACK";
+
 const GRIN_HOME: &str = ".grin";
 /// Wallet data directory
 pub const GRIN_WALLET_DIR: &str = "wallet_data";
@@ -78,6 +117,226 @@ pub fn create_grin_wallet_path(chain_type: &global::ChainTypes, sub_dir: &str) -
     grin_path
 }
 
+/// Whether a wallet config file exists at the given top level directory
+pub fn config_exists(path: &str) -> bool {
+    grin_wallet_config::config_file_exists(&path)
+}
+
+/// Reads an API secret file as written by grin-wallet, if a path is given
+pub fn read_api_secret(path: Option<&PathBuf>) -> Option<String> {
+    file::get_first_line(path.map(|p| p.to_string_lossy().into_owned()))
+}
+
+/// Hex form of an output's commitment, as grin-wallet displays it
+pub fn output_commit_hex(output: &OutputCommitMapping) -> String {
+    output.commit.0.to_vec().to_hex()
+}
+
+/// Figures of a send as estimated by the wallet, before anything is locked
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TxEstimate {
+    pub amount: u64,
+    pub fee: u64,
+    /// Value of the selected inputs, i.e. amount, fee and change together
+    pub total_inputs: u64,
+    pub num_inputs: usize,
+    pub num_change_outputs: usize,
+}
+
+impl TxEstimate {
+    /// Reads a slate returned by `init_send_tx` with `estimate_only`, which carries the
+    /// value of the selected inputs as its amount. The estimate doesn't include the
+    /// number of inputs, so it's recovered from the fee: the fee is the accept fee base
+    /// times a weight of 1 per input, 21 per output and 3 per kernel.
+    pub fn from_estimate_slate(amount: u64, num_change_outputs: usize, slate: &Slate) -> Self {
+        let fee = slate.fee_fields.fee();
+        let total_inputs = slate.amount;
+        let num_change_outputs = if total_inputs > amount + fee {
+            num_change_outputs
+        } else {
+            0
+        };
+        let num_outputs = num_change_outputs + 1;
+        let weight = fee / global::get_accept_fee_base();
+        let num_inputs = (weight.saturating_sub(
+            num_outputs as u64 * consensus::BLOCK_OUTPUT_WEIGHT + consensus::BLOCK_KERNEL_WEIGHT,
+        ) / consensus::BLOCK_INPUT_WEIGHT)
+            .max(1) as usize;
+        TxEstimate {
+            amount,
+            fee,
+            total_inputs,
+            num_inputs,
+            num_change_outputs,
+        }
+    }
+
+    /// Works out a send spending all of `values`, the way the wallet's coin selection does
+    /// once its inputs are fixed: an exact match needs no change output, so pays the
+    /// smaller fee. `None` if the outputs can't cover amount and fee.
+    pub fn from_outputs(amount: u64, num_change_outputs: usize, values: &[u64]) -> Option<Self> {
+        let num_inputs = values.len();
+        let total_inputs: u64 = values.iter().sum();
+        if num_inputs == 0 {
+            return None;
+        }
+        let fee_without_change = grin_core::libtx::tx_fee(num_inputs, 1, 1);
+        let (fee, num_change_outputs) = if total_inputs == amount + fee_without_change {
+            (fee_without_change, 0)
+        } else {
+            (
+                grin_core::libtx::tx_fee(num_inputs, num_change_outputs + 1, 1),
+                num_change_outputs,
+            )
+        };
+        if total_inputs < amount + fee {
+            return None;
+        }
+        Some(TxEstimate {
+            amount,
+            fee,
+            total_inputs,
+            num_inputs,
+            num_change_outputs,
+        })
+    }
+
+    /// Amount leaving the wallet for good, amount plus fee
+    pub fn total_debited(&self) -> u64 {
+        self.amount + self.fee
+    }
+
+    pub fn change(&self) -> u64 {
+        self.total_inputs.saturating_sub(self.total_debited())
+    }
+}
+
+/// Largest amount a send funded by `outputs` can carry, spending them all without change.
+/// Like the wallet's own coin selection, only outputs eligible at `current_height` with
+/// `minimum_confirmations` count, and at most `max_outputs` of them, largest first.
+pub fn max_send_amount(
+    outputs: &[OutputCommitMapping],
+    current_height: u64,
+    minimum_confirmations: u64,
+    max_outputs: usize,
+) -> u64 {
+    let mut values: Vec<u64> = outputs
+        .iter()
+        .filter(|o| {
+            o.output
+                .eligible_to_spend(current_height, minimum_confirmations)
+        })
+        .map(|o| o.output.value)
+        .collect();
+    values.sort_unstable_by(|a, b| b.cmp(a));
+    values.truncate(max_outputs);
+    if values.is_empty() {
+        return 0;
+    }
+    let total: u64 = values.iter().sum();
+    total.saturating_sub(grin_core::libtx::tx_fee(values.len(), 1, 1))
+}
+
+/// Late locking selects inputs again when the slate is finalized, so it can't honour a
+/// choice of outputs
+pub fn check_late_lock(
+    init_args: &InitTxArgs,
+    selected_outputs: &Option<Vec<String>>,
+) -> Result<(), GrinWalletInterfaceError> {
+    if selected_outputs.is_some() && init_args.late_lock == Some(true) {
+        return Err(GrinWalletInterfaceError::LateLockWithCoinControl);
+    }
+    Ok(())
+}
+
+/// What a scan changed in the wallet's outputs, worked out from the outputs before and
+/// after it since the scan itself doesn't report it
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScanSummary {
+    pub outputs_before: usize,
+    pub outputs_after: usize,
+    /// Found on chain but missing from the wallet
+    pub restored: usize,
+    /// Not found unspent on chain, so dropped from the wallet or marked spent
+    pub deleted: usize,
+    /// Locked before, spendable again after
+    pub unlocked: usize,
+}
+
+impl ScanSummary {
+    pub fn between(before: &[OutputCommitMapping], after: &[OutputCommitMapping]) -> Self {
+        let before: HashMap<String, OutputStatus> = before
+            .iter()
+            .map(|o| (output_commit_hex(o), o.output.status.clone()))
+            .collect();
+        let after: HashMap<String, OutputStatus> = after
+            .iter()
+            .map(|o| (output_commit_hex(o), o.output.status.clone()))
+            .collect();
+
+        ScanSummary {
+            outputs_before: before.len(),
+            outputs_after: after.len(),
+            restored: after.keys().filter(|c| !before.contains_key(*c)).count(),
+            deleted: before
+                .iter()
+                .filter(|(c, s)| match after.get(*c) {
+                    None => true,
+                    Some(OutputStatus::Spent) => **s != OutputStatus::Spent,
+                    Some(_) => false,
+                })
+                .count(),
+            unlocked: before
+                .iter()
+                .filter(|(c, s)| {
+                    **s == OutputStatus::Locked && after.get(*c) == Some(&OutputStatus::Unspent)
+                })
+                .count(),
+        }
+    }
+}
+
+/// Writes a payment proof as pretty printed JSON, as grin-wallet exports them
+pub fn write_payment_proof(
+    path: &Path,
+    proof: &PaymentProof,
+) -> Result<(), GrinWalletInterfaceError> {
+    std::fs::write(path, serde_json::to_string_pretty(proof)?)?;
+    Ok(())
+}
+
+pub fn read_payment_proof(path: &Path) -> Result<PaymentProof, GrinWalletInterfaceError> {
+    Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+}
+
+/// File name grin-wallet gives a slatepack of `slate` at `state`, e.g. `<slate id>.S1.slatepack`
+pub fn slatepack_file_name(slate: &Slate, state: &SlateState) -> String {
+    format!("{}.{}.slatepack", slate.id, state)
+}
+
+pub fn write_slatepack_file(path: &Path, slatepack: &str) -> Result<(), GrinWalletInterfaceError> {
+    std::fs::write(path, slatepack)?;
+    Ok(())
+}
+
+/// Reads a slatepack file, armored or binary, returning the armored slatepack
+pub fn read_slatepack_file(path: &Path) -> Result<String, GrinWalletInterfaceError> {
+    let data = std::fs::read(path)?;
+    if let Ok(text) = std::str::from_utf8(&data) {
+        if text.trim_start().starts_with(SLATEPACK_ARMOR_HEADER) {
+            return Ok(text.trim().to_owned());
+        }
+    }
+    // Binary slatepacks are re-armored as is, decrypting is left to `decrypt_slatepack`
+    let packer = Slatepacker::new(SlatepackerArgs {
+        sender: None,
+        recipients: vec![],
+        dec_key: None,
+    });
+    let slatepack = packer.deser_slatepack(&data, false)?;
+    Ok(packer.armor_slatepack(&slatepack)?)
+}
+
 pub type WalletInterfaceHttpNodeClient = WalletInterface<
     DefaultLCProvider<'static, HTTPNodeClient, keychain::ExtKeychain>,
     HTTPNodeClient,
@@ -100,6 +359,12 @@ where
     pub check_node_foreign_api_secret_path: Option<String>,
     // Whether to use embedded node for check node
     use_embedded_node: bool,
+    // When set, all calls go to a grin-wallet instance elsewhere instead of owner_api
+    pub remote_owner_api: Option<RemoteOwnerApi>,
+    // Handed to the owner API, which pushes updater messages into it
+    status_tx: mpsc::Sender<StatusMessage>,
+    // Where updater messages end up, set once the UI subscription is running
+    status_ui_sender: Arc<Mutex<Option<iced_mpsc::Sender<subscriber::UIMessage>>>>,
 
     node_client: C,
 }
@@ -110,6 +375,10 @@ where
     C: NodeClient + 'static + Clone,
 {
     pub fn new(node_client: C) -> Self {
+        let (status_tx, status_rx) = mpsc::channel::<StatusMessage>();
+        let status_ui_sender = Arc::new(Mutex::new(None));
+        WalletInterface::spawn_status_forwarder(status_rx, status_ui_sender.clone());
+
         WalletInterface {
             chain_type: None,
             config: None,
@@ -119,56 +388,55 @@ where
             check_node_foreign_api_secret_path: None,
             node_client,
             use_embedded_node: true,
+            remote_owner_api: None,
+            status_tx,
+            status_ui_sender,
         }
     }
 
-    fn set_chain_type(&mut self, chain_type: global::ChainTypes) {
-        self.chain_type = Some(chain_type);
-    }
-
-    pub fn set_check_node_foreign_api_secret_path(&mut self, secret: &str) {
-        self.check_node_foreign_api_secret_path = Some(secret.to_owned())
+    /// Forwards updater messages from the owner API to the UI subscription, if any
+    fn spawn_status_forwarder(
+        status_rx: mpsc::Receiver<StatusMessage>,
+        ui_sender: Arc<Mutex<Option<iced_mpsc::Sender<subscriber::UIMessage>>>>,
+    ) {
+        let _ = thread::Builder::new()
+            .name("wallet_status_forwarder".to_string())
+            .spawn(move || {
+                while let Ok(m) = status_rx.recv() {
+                    let sender = ui_sender.lock().clone();
+                    if let Some(mut s) = sender {
+                        let msg = subscriber::UIMessage::UpdaterStatus(m);
+                        if let Err(e) = futures::executor::block_on(s.send(msg)) {
+                            error!("Unable to send wallet status message to UI: {}", e);
+                        }
+                    }
+                }
+            });
     }
 
-    pub fn config_exists(&self, path: &str) -> bool {
-        grin_wallet_config::config_file_exists(&path)
+    fn set_chain_type(&mut self, chain_type: global::ChainTypes) {
+        self.chain_type = Some(chain_type);
     }
 
     pub fn default_config_exists(&self) -> bool {
         match self.chain_type {
             Some(chain_type) => {
-                self.config_exists(get_grin_wallet_default_path(&chain_type).to_str().unwrap())
+                config_exists(get_grin_wallet_default_path(&chain_type).to_str().unwrap())
             }
             _ => false,
         }
     }
 
-    pub fn wallet_is_open(&self) -> bool {
-        self.wallet_is_open
-    }
-
-    pub fn set_use_embedded_node(
-        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
-        value: bool,
-    ) {
-        let mut w = wallet_interface.write().unwrap();
-        if w.use_embedded_node != value {
-            w.owner_api = None;
-        }
-        w.use_embedded_node = value;
-    }
-
     /// Sets the top level directory of the wallet and creates default config if config
     /// doesn't already exist. The initial config is created based off of the chain type.
     fn inst_wallet(
-        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        &mut self,
         chain_type: global::ChainTypes,
         top_level_directory: PathBuf,
     ) -> Result<
         Arc<Mutex<Box<dyn WalletInst<'static, L, C, keychain::ExtKeychain>>>>,
         GrinWalletInterfaceError,
     > {
-        let mut w = wallet_interface.write().unwrap();
         // path for config file
         let data_path = Some(top_level_directory.clone());
 
@@ -184,17 +452,17 @@ where
         let wallet_config = config.clone().members.unwrap().wallet;
 
         // Set node client address and Foreign API Secret if needed
-        if w.use_embedded_node {
-            w.node_client
+        if self.use_embedded_node {
+            self.node_client
                 .set_node_url(&wallet_config.check_node_api_http_addr);
 
             let check_node_secret =
-                file::get_first_line(w.check_node_foreign_api_secret_path.clone());
-            w.node_client.set_node_api_secret(check_node_secret);
+                file::get_first_line(self.check_node_foreign_api_secret_path.clone());
+            self.node_client.set_node_api_secret(check_node_secret);
         }
 
         let wallet_inst =
-            inst_wallet(wallet_config.clone(), w.node_client.clone()).unwrap_or_else(|e| {
+            inst_wallet(wallet_config.clone(), self.node_client.clone()).unwrap_or_else(|e| {
                 println!("{}", e);
                 std::process::exit(1);
             });
@@ -206,44 +474,217 @@ where
             lc.set_top_level_directory(top_level_directory.to_str().unwrap());
         }
 
-        w.config = Some(config);
+        self.config = Some(config);
 
         Ok(wallet_inst)
     }
 
     fn inst_apis(
-        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        &mut self,
         chain_type: global::ChainTypes,
         top_level_directory: PathBuf,
     ) -> Result<(), GrinWalletInterfaceError> {
-        let wallet_inst = WalletInterface::inst_wallet(
-            wallet_interface.clone(),
-            chain_type,
-            top_level_directory,
-        )?;
-        let mut w = wallet_interface.write().unwrap();
-        w.owner_api = Some(Owner::new(wallet_inst.clone(), None));
-        w.foreign_api = Some(Foreign::new(wallet_inst.clone(), None, None, false));
+        let wallet_inst = self.inst_wallet(chain_type, top_level_directory)?;
+        self.owner_api = Some(Owner::new(wallet_inst.clone(), Some(self.status_tx.clone())));
+        self.foreign_api = Some(Foreign::new(wallet_inst.clone(), None, None, false));
         global::set_local_chain_type(chain_type);
 
         Ok(())
     }
 
-    pub async fn init(
-        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+    pub fn encrypt_slatepack(
+        api: &Owner<L, C, keychain::ExtKeychain>,
+        dest: &str,
+        unenc_slate: &Slate,
+    ) -> Result<String, GrinWalletInterfaceError> {
+        let address = match SlatepackAddress::try_from(dest) {
+            Ok(a) => Some(a),
+            Err(_) => return Err(GrinWalletInterfaceError::InvalidSlatepackAddress),
+        };
+        // encrypt for recipient by default
+        let recipients = match address.clone() {
+            Some(a) => vec![a],
+            None => vec![],
+        };
+        Ok(api.create_slatepack_message(None, &unenc_slate, Some(0), recipients)?)
+    }
+
+    /// Builds and locks a send funded by exactly the outputs in `commits`. The wallet has
+    /// no input selection of its own, so every other spendable output of the account is
+    /// held as `Locked` while the slate is built with `selection_strategy_is_use_all`, and
+    /// released again afterwards. All of it happens under one wallet lock, so neither the
+    /// updater nor another call ever sees the held outputs.
+    ///
+    /// Holding them goes through the wallet database, the wallet has no other way to keep
+    /// outputs out of its selection. Should the process die before they're released they
+    /// stay `Locked` until a scan ("Check and Repair Wallet") returns them to unspent, as
+    /// it does any locked output that's unspent on chain.
+    fn init_send_tx_from_outputs(
+        api: &Owner<L, C, keychain::ExtKeychain>,
+        mut init_args: InitTxArgs,
+        commits: &[String],
+    ) -> Result<Slate, GrinWalletInterfaceError> {
+        init_args.selection_strategy_is_use_all = true;
+        let mut w_lock = api.wallet_inst.lock();
+        let w = w_lock.lc_provider()?.wallet_inst()?;
+        let held = Self::hold_outputs_except(&mut **w, commits, init_args.minimum_confirmations)?;
+        let res = owner::init_send_tx(&mut **w, None, init_args, false).and_then(|slate| {
+            owner::tx_lock_outputs(&mut **w, None, &slate)?;
+            Ok(slate)
+        });
+        // Release before reporting any failure, nothing may stay held
+        let released = Self::release_outputs(&mut **w, &held);
+        let slate = res?;
+        released?;
+        Ok(slate)
+    }
+
+    /// Estimates a send funded by exactly the outputs in `commits` from their values,
+    /// without touching the wallet database. Fails if any of `commits` can't be spent
+    /// with the send's minimum confirmations, as the send itself would.
+    fn estimate_tx_from_outputs(
+        api: &Owner<L, C, keychain::ExtKeychain>,
+        init_args: &InitTxArgs,
+        commits: &[String],
+    ) -> Result<TxEstimate, GrinWalletInterfaceError> {
+        let height = {
+            let mut w_lock = api.wallet_inst.lock();
+            let w = w_lock.lc_provider()?.wallet_inst()?;
+            w.last_confirmed_height()?
+        };
+        let (_, outputs) = api.retrieve_outputs(None, false, false, None)?;
+        let values = commits
+            .iter()
+            .map(|c| {
+                outputs
+                    .iter()
+                    .find(|o| {
+                        o.output
+                            .eligible_to_spend(height, init_args.minimum_confirmations)
+                            && output_commit_hex(o) == *c
+                    })
+                    .map(|o| o.output.value)
+                    .ok_or_else(|| GrinWalletInterfaceError::OutputNotSpendable(c.clone()))
+            })
+            .collect::<Result<Vec<u64>, _>>()?;
+        TxEstimate::from_outputs(
+            init_args.amount,
+            init_args.num_change_outputs as usize,
+            &values,
+        )
+        .ok_or(GrinWalletInterfaceError::SelectedOutputsInsufficient)
+    }
+
+    /// Locks every output of the active account the wallet's coin selection could pick
+    /// with `minimum_confirmations`, except `commits`, returning them as they were before.
+    /// Fails if any of `commits` isn't one it could pick.
+    fn hold_outputs_except<T>(
+        w: &mut T,
+        commits: &[String],
+        minimum_confirmations: u64,
+    ) -> Result<Vec<OutputData>, GrinWalletInterfaceError>
+    where
+        T: LibWalletBackend<'static, C, keychain::ExtKeychain> + ?Sized,
+    {
+        let parent_key_id = w.parent_key_id();
+        let height = w.last_confirmed_height()?;
+        let eligible: Vec<OutputData> = w
+            .iter()
+            .filter(|o| {
+                o.root_key_id == parent_key_id && o.eligible_to_spend(height, minimum_confirmations)
+            })
+            .collect();
+
+        if let Some(missing) = commits
+            .iter()
+            .find(|c| !eligible.iter().any(|o| o.commit.as_ref() == Some(*c)))
+        {
+            return Err(GrinWalletInterfaceError::OutputNotSpendable(
+                missing.clone(),
+            ));
+        }
+
+        let held: Vec<OutputData> = eligible
+            .into_iter()
+            .filter(|o| !o.commit.as_ref().map_or(false, |c| commits.contains(c)))
+            .collect();
+        let mut batch = w.batch(None)?;
+        for o in held.iter() {
+            let mut o = o.clone();
+            o.lock();
+            batch.save(o)?;
+        }
+        batch.commit()?;
+        Ok(held)
+    }
+
+    /// Puts outputs locked by `hold_outputs_except` back the way they were, unless
+    /// something else changed them since
+    fn release_outputs<T>(w: &mut T, held: &[OutputData]) -> Result<(), GrinWalletInterfaceError>
+    where
+        T: LibWalletBackend<'static, C, keychain::ExtKeychain> + ?Sized,
+    {
+        let current: Vec<OutputData> = w
+            .iter()
+            .filter(|o| o.status == OutputStatus::Locked)
+            .filter_map(|o| {
+                held.iter()
+                    .find(|h| h.key_id == o.key_id && h.mmr_index == o.mmr_index)
+                    .cloned()
+            })
+            .collect();
+        let mut batch = w.batch(None)?;
+        for o in current.into_iter() {
+            batch.save(o)?;
+        }
+        batch.commit()?;
+        Ok(())
+    }
+
+    /*pub fn tx_lock_outputs(
+        &self,
+        init_args: InitTxArgs,
+    ) -> Result<Slate, GrinWalletInterfaceError> {
+        if let Some(o) = &self.owner_api {
+            let slate = {
+                o.init_send_tx(None, init_args)?
+            };
+            return Ok(slate);
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }*/
+}
+
+impl<L, C> WalletBackend for WalletInterface<L, C>
+where
+    L: WalletLCProvider<'static, C, keychain::ExtKeychain>,
+    C: NodeClient + 'static + Clone,
+{
+    fn wallet_is_open(&self) -> bool {
+        self.wallet_is_open
+    }
+
+    fn set_use_embedded_node(&mut self, value: bool) {
+        if self.use_embedded_node != value {
+            self.owner_api = None;
+        }
+        self.use_embedded_node = value;
+    }
+
+    fn set_check_node_foreign_api_secret_path(&mut self, path: Option<String>) {
+        self.check_node_foreign_api_secret_path = path;
+    }
+
+    fn init(
+        &mut self,
         password: String,
         top_level_directory: PathBuf,
         display_name: String,
         chain_type: global::ChainTypes,
         recovery_phrase: Option<String>,
     ) -> Result<(String, String, String, global::ChainTypes), GrinWalletInterfaceError> {
-        WalletInterface::inst_apis(
-            wallet_interface.clone(),
-            chain_type,
-            top_level_directory.clone(),
-        )?;
-
-        let w = wallet_interface.read().unwrap();
+        self.inst_apis(chain_type, top_level_directory.clone())?;
 
         let recover_length = recovery_phrase.clone().map(|f| f.len()).unwrap_or(32);
         let recover_phrase = recovery_phrase.map(|f| ZeroingString::from(f));
@@ -251,17 +692,17 @@ where
         let args = InitArgs {
             list_length: recover_length,
             password: password.clone().into(),
-            config: w.config.clone().unwrap().clone().members.unwrap().wallet,
+            config: self.config.clone().unwrap().clone().members.unwrap().wallet,
             recovery_phrase: recover_phrase.clone(),
             restore: recover_phrase.is_some(),
         };
 
-        let (tld, ret_phrase) = match w.owner_api.as_ref() {
+        let (tld, ret_phrase) = match self.owner_api.as_ref() {
             Some(o) => {
                 let tld = {
                     let mut w_lock = o.wallet_inst.lock();
                     let p = w_lock.lc_provider()?;
-                    let logging_config = w
+                    let logging_config = self
                         .config
                         .clone()
                         .unwrap()
@@ -303,83 +744,125 @@ where
         Ok((tld, ret_phrase, display_name, chain_type))
     }
 
-    pub async fn open_wallet(
-        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+    fn open_wallet(
+        &mut self,
         password: String,
         top_level_directory: PathBuf,
         chain_type: global::ChainTypes,
     ) -> Result<(), GrinWalletInterfaceError> {
-        WalletInterface::inst_apis(
-            wallet_interface.clone(),
-            chain_type,
-            top_level_directory.clone(),
-        )?;
+        self.inst_apis(chain_type, top_level_directory.clone())?;
+        self.remote_owner_api = None;
 
-        let mut w = wallet_interface.write().unwrap();
-
-        if let Some(o) = &w.owner_api {
+        if let Some(o) = &self.owner_api {
             // ignoring secret key
             let _ = o.open_wallet(None, password.into(), false)?;
             // Start the updater
             o.start_updater(None, std::time::Duration::from_secs(60))?;
-            w.wallet_is_open = true;
+            self.wallet_is_open = true;
             // set wallet interface chain type
-            w.set_chain_type(chain_type);
+            self.set_chain_type(chain_type);
             return Ok(());
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
         }
     }
 
-    pub async fn close_wallet(
-        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+    /// Opens a wallet served by a remote grin-wallet owner listener. Any local wallet
+    /// APIs are dropped, so all subsequent calls are routed to the remote instance.
+    fn open_remote_wallet(
+        &mut self,
+        password: String,
+        mut remote_api: RemoteOwnerApi,
+        chain_type: global::ChainTypes,
     ) -> Result<(), GrinWalletInterfaceError> {
-        let mut w = wallet_interface.write().unwrap();
-        if let Some(o) = &w.owner_api {
+        remote_api.open_wallet(&password)?;
+        remote_api.start_updater(std::time::Duration::from_secs(60))?;
+
+        self.owner_api = None;
+        self.foreign_api = None;
+        self.remote_owner_api = Some(remote_api);
+        self.wallet_is_open = true;
+        self.set_chain_type(chain_type);
+        global::set_local_chain_type(chain_type);
+        Ok(())
+    }
+
+    fn close_wallet(&mut self) -> Result<(), GrinWalletInterfaceError> {
+        if let Some(mut r) = self.remote_owner_api.take() {
+            self.wallet_is_open = false;
+            return r.close_wallet();
+        }
+        if let Some(o) = &self.owner_api {
             o.close_wallet(None);
-            w.wallet_is_open = false;
+            self.wallet_is_open = false;
             return Ok(());
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
         }
     }
 
-    pub fn get_wallet_updater_status(
-        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
-    ) -> Result<Vec<StatusMessage>, GrinWalletInterfaceError> {
-        let w = wallet_interface.read().unwrap();
-        if let Some(o) = &w.owner_api {
-            let res = o.get_updater_messages(1)?;
-            return Ok(res);
+    fn change_password(
+        &mut self,
+        old_password: ZeroingString,
+        new_password: ZeroingString,
+    ) -> Result<(), GrinWalletInterfaceError> {
+        if self.remote_owner_api.is_some() {
+            return Err(GrinWalletInterfaceError::ChangePasswordUnsupported);
+        }
+        if let Some(o) = &self.owner_api {
+            {
+                let mut w_lock = o.wallet_inst.lock();
+                let lc = w_lock.lc_provider()?;
+                lc.change_password(None, old_password, new_password.clone())?;
+            }
+            // The open keychain was unlocked with the old password
+            o.close_wallet(None)?;
+            let _ = o.open_wallet(None, new_password, false)?;
+            return Ok(());
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
         }
     }
 
-    pub fn encrypt_slatepack(
-        api: &Owner<L, C, keychain::ExtKeychain>,
-        dest: &str,
-        unenc_slate: &Slate,
-    ) -> Result<String, GrinWalletInterfaceError> {
-        let address = match SlatepackAddress::try_from(dest) {
-            Ok(a) => Some(a),
-            Err(_) => return Err(GrinWalletInterfaceError::InvalidSlatepackAddress),
-        };
-        // encrypt for recipient by default
-        let recipients = match address.clone() {
-            Some(a) => vec![a],
-            None => vec![],
-        };
-        Ok(api.create_slatepack_message(None, &unenc_slate, Some(0), recipients)?)
+    fn get_recovery_phrase(
+        &self,
+        password: ZeroingString,
+    ) -> Result<ZeroingString, GrinWalletInterfaceError> {
+        if self.remote_owner_api.is_some() {
+            return Err(GrinWalletInterfaceError::RecoveryPhraseUnsupported);
+        }
+        if let Some(o) = &self.owner_api {
+            return Ok(o.get_mnemonic(None, password)?);
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    fn set_status_sender(&mut self, sender: iced_mpsc::Sender<subscriber::UIMessage>) {
+        *self.status_ui_sender.lock() = Some(sender);
+    }
+
+    fn poll_updater_status(&mut self) -> Result<(), GrinWalletInterfaceError> {
+        // Local owner API pushes its messages through status_tx already
+        if let Some(r) = &self.remote_owner_api {
+            for m in r.get_updater_messages(REMOTE_UPDATER_MESSAGE_COUNT)? {
+                let _ = self.status_tx.send(m);
+            }
+        }
+        Ok(())
     }
 
     /// Attempt to decode and decrypt a given slatepack
-    pub fn decrypt_slatepack(
-        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+    fn decrypt_slatepack(
+        &self,
         slatepack: String,
     ) -> Result<(Slatepack, Slate), GrinWalletInterfaceError> {
-        let w = wallet_interface.read().unwrap();
-        if let Some(o) = &w.owner_api {
+        if let Some(r) = &self.remote_owner_api {
+            let sp = r.decode_slatepack_message(&slatepack)?;
+            let slate = r.slate_from_slatepack_message(&slatepack)?;
+            return Ok((sp, slate));
+        }
+        if let Some(o) = &self.owner_api {
             let sp = o.decode_slatepack_message(None, slatepack.clone(), vec![0])?;
             let slate = o.slate_from_slatepack_message(None, slatepack, vec![0])?;
             return Ok((sp, slate));
@@ -388,11 +871,36 @@ where
         }
     }
 
-    pub async fn get_wallet_info(
-        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
-    ) -> Result<(bool, WalletInfo), GrinWalletInterfaceError> {
-        let w = wallet_interface.read().unwrap();
-        if let Some(o) = &w.owner_api {
+    fn encrypt_slatepack(
+        &self,
+        slate: Slate,
+        dest_slatepack_address: Option<String>,
+    ) -> Result<String, GrinWalletInterfaceError> {
+        if let Some(r) = &self.remote_owner_api {
+            let recipients = match dest_slatepack_address {
+                Some(d) => match SlatepackAddress::try_from(d.as_str()) {
+                    Ok(a) => vec![a],
+                    Err(_) => return Err(GrinWalletInterfaceError::InvalidSlatepackAddress),
+                },
+                None => vec![],
+            };
+            return r.create_slatepack_message(&slate, recipients);
+        }
+        if let Some(o) = &self.owner_api {
+            return match dest_slatepack_address {
+                Some(d) => WalletInterface::encrypt_slatepack(o, &d, &slate),
+                None => Ok(o.create_slatepack_message(None, &slate, Some(0), vec![])?),
+            };
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    fn get_wallet_info(&self) -> Result<(bool, WalletInfo), GrinWalletInterfaceError> {
+        if let Some(r) = &self.remote_owner_api {
+            return r.retrieve_summary_info(false, 2);
+        }
+        if let Some(o) = &self.owner_api {
             let res = o.retrieve_summary_info(None, false, 2)?;
             return Ok(res);
         } else {
@@ -400,36 +908,77 @@ where
         }
     }
 
-    pub async fn get_txs(
-        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+    fn get_txs(
+        &self,
         query_args: Option<RetrieveTxQueryArgs>,
     ) -> Result<(bool, Vec<TxLogEntry>), GrinWalletInterfaceError> {
-        let w = wallet_interface.read().unwrap();
-        if let Some(o) = &w.owner_api {
+        if let Some(r) = &self.remote_owner_api {
+            return r.retrieve_txs(true, query_args);
+        }
+        if let Some(o) = &self.owner_api {
             let res = o.retrieve_txs(None, true, None, None, query_args)?;
-            /*for tx in &mut res.1 {
-                if tx.amount_credited == 0 && tx.amount_debited == 0 {
-                    let saved_tx = o.get_stored_tx(None, Some(tx.id), None);
-                    if let Ok(st) = saved_tx {
-                        // Todo: have to check more things here, this is just for tx display
-                        if let Some(s) = st {
-                            println!("TWO: {}", s.amount);
-                            tx.amount_debited = s.amount;
-                        }
-                    }
-                }
-            };*/
             return Ok(res);
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
         }
     }
 
-    pub async fn get_slatepack_address(
-        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
-    ) -> Result<String, GrinWalletInterfaceError> {
-        let w = wallet_interface.read().unwrap();
-        if let Some(o) = &w.owner_api {
+    fn get_outputs(
+        &self,
+        include_spent: bool,
+    ) -> Result<(bool, Vec<OutputCommitMapping>), GrinWalletInterfaceError> {
+        if let Some(r) = &self.remote_owner_api {
+            return r.retrieve_outputs(include_spent, true);
+        }
+        if let Some(o) = &self.owner_api {
+            let res = o.retrieve_outputs(None, include_spent, true, None)?;
+            return Ok(res);
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    fn accounts(&self) -> Result<Vec<AcctPathMapping>, GrinWalletInterfaceError> {
+        if let Some(r) = &self.remote_owner_api {
+            return r.accounts();
+        }
+        if let Some(o) = &self.owner_api {
+            let res = o.accounts(None)?;
+            return Ok(res);
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    fn create_account(&mut self, label: String) -> Result<(), GrinWalletInterfaceError> {
+        if let Some(r) = &self.remote_owner_api {
+            return r.create_account_path(&label);
+        }
+        if let Some(o) = &self.owner_api {
+            o.create_account_path(None, &label)?;
+            return Ok(());
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    fn set_active_account(&mut self, label: String) -> Result<(), GrinWalletInterfaceError> {
+        if let Some(r) = &self.remote_owner_api {
+            return r.set_active_account(&label);
+        }
+        if let Some(o) = &self.owner_api {
+            o.set_active_account(None, &label)?;
+            return Ok(());
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    fn get_slatepack_address(&self) -> Result<String, GrinWalletInterfaceError> {
+        if let Some(r) = &self.remote_owner_api {
+            return Ok(r.get_slatepack_address(0)?.to_string());
+        }
+        if let Some(o) = &self.owner_api {
             let res = o.get_slatepack_address(None, 0)?;
             return Ok(res.to_string());
         } else {
@@ -437,38 +986,167 @@ where
         }
     }
 
-    pub async fn create_tx(
-        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+    fn create_tx(
+        &mut self,
+        init_args: InitTxArgs,
+        dest_slatepack_address: String,
+        selected_outputs: Option<Vec<String>>,
+    ) -> Result<(Slate, String), GrinWalletInterfaceError> {
+        let address = match SlatepackAddress::try_from(dest_slatepack_address.as_str()) {
+            Ok(a) => a,
+            Err(_) => return Err(GrinWalletInterfaceError::InvalidSlatepackAddress),
+        };
+        check_late_lock(&init_args, &selected_outputs)?;
+        if let Some(r) = &self.remote_owner_api {
+            // Holding outputs back needs direct access to the wallet database
+            if selected_outputs.is_some() {
+                return Err(GrinWalletInterfaceError::CoinControlUnsupported);
+            }
+            let slate = r.init_send_tx(init_args)?;
+            r.tx_lock_outputs(&slate)?;
+            let slatepack = r.create_slatepack_message(&slate, vec![address])?;
+            return Ok((slate, slatepack));
+        }
+        if let Some(o) = &self.owner_api {
+            let slate = match selected_outputs {
+                Some(commits) => {
+                    WalletInterface::init_send_tx_from_outputs(o, init_args, &commits)?
+                }
+                None => {
+                    let slate = o.init_send_tx(None, init_args)?;
+                    o.tx_lock_outputs(None, &slate)?;
+                    slate
+                }
+            };
+            let slatepack = WalletInterface::encrypt_slatepack(o, &dest_slatepack_address, &slate)?;
+            return Ok((slate, slatepack));
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    fn estimate_tx(
+        &self,
+        mut init_args: InitTxArgs,
+        selected_outputs: Option<Vec<String>>,
+    ) -> Result<TxEstimate, GrinWalletInterfaceError> {
+        let amount = init_args.amount;
+        let num_change_outputs = init_args.num_change_outputs as usize;
+        init_args.estimate_only = Some(true);
+        check_late_lock(&init_args, &selected_outputs)?;
+        if let Some(r) = &self.remote_owner_api {
+            if selected_outputs.is_some() {
+                return Err(GrinWalletInterfaceError::CoinControlUnsupported);
+            }
+            let slate = r.init_send_tx(init_args)?;
+            return Ok(TxEstimate::from_estimate_slate(
+                amount,
+                num_change_outputs,
+                &slate,
+            ));
+        }
+        if let Some(o) = &self.owner_api {
+            match selected_outputs {
+                Some(commits) => WalletInterface::estimate_tx_from_outputs(o, &init_args, &commits),
+                None => {
+                    let slate = o.init_send_tx(None, init_args)?;
+                    Ok(TxEstimate::from_estimate_slate(
+                        amount,
+                        num_change_outputs,
+                        &slate,
+                    ))
+                }
+            }
+        } else {
+            Err(GrinWalletInterfaceError::OwnerAPINotInstantiated)
+        }
+    }
+
+    fn issue_invoice(
+        &mut self,
+        args: IssueInvoiceTxArgs,
+        dest_slatepack_address: String,
+    ) -> Result<(Slate, String), GrinWalletInterfaceError> {
+        let address = match SlatepackAddress::try_from(dest_slatepack_address.as_str()) {
+            Ok(a) => a,
+            Err(_) => return Err(GrinWalletInterfaceError::InvalidSlatepackAddress),
+        };
+        if let Some(r) = &self.remote_owner_api {
+            let slate = r.issue_invoice_tx(args)?;
+            let slatepack = r.create_slatepack_message(&slate, vec![address])?;
+            return Ok((slate, slatepack));
+        }
+        if let Some(o) = &self.owner_api {
+            let slate = o.issue_invoice_tx(None, args)?;
+            let slatepack = WalletInterface::encrypt_slatepack(o, &dest_slatepack_address, &slate)?;
+            return Ok((slate, slatepack));
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    fn process_invoice(
+        &mut self,
+        slate: Slate,
         init_args: InitTxArgs,
         dest_slatepack_address: String,
     ) -> Result<String, GrinWalletInterfaceError> {
-        let w = wallet_interface.write().unwrap();
-        let _address = match SlatepackAddress::try_from(dest_slatepack_address.as_str()) {
-            Ok(a) => Some(a),
+        let address = match SlatepackAddress::try_from(dest_slatepack_address.as_str()) {
+            Ok(a) => a,
             Err(_) => return Err(GrinWalletInterfaceError::InvalidSlatepackAddress),
         };
-        if let Some(o) = &w.owner_api {
-            let slate = { o.init_send_tx(None, init_args)? };
-            o.tx_lock_outputs(None, &slate)?;
-            return WalletInterface::encrypt_slatepack(o, &dest_slatepack_address, &slate);
+        if let Some(r) = &self.remote_owner_api {
+            let ret_slate = r.process_invoice_tx(&slate, init_args)?;
+            r.tx_lock_outputs(&ret_slate)?;
+            return r.create_slatepack_message(&ret_slate, vec![address]);
+        }
+        if let Some(o) = &self.owner_api {
+            let ret_slate = o.process_invoice_tx(None, &slate, init_args)?;
+            o.tx_lock_outputs(None, &ret_slate)?;
+            return WalletInterface::encrypt_slatepack(o, &dest_slatepack_address, &ret_slate);
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
         }
     }
 
-    pub async fn receive_tx_from_s1(
-        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+    fn finalize_invoice(&mut self, slate: Slate) -> Result<(), GrinWalletInterfaceError> {
+        if let Some(r) = &self.remote_owner_api {
+            // The remote foreign listener posts the finalized transaction itself
+            r.finalize_invoice_tx(&slate)?;
+            return Ok(());
+        }
+        let ret_slate = match &self.foreign_api {
+            Some(f) => f.finalize_tx(&slate, false)?,
+            None => return Err(GrinWalletInterfaceError::ForeignAPINotInstantiated),
+        };
+        if let Some(o) = &self.owner_api {
+            o.post_tx(None, &ret_slate, true)?;
+            Ok(())
+        } else {
+            Err(GrinWalletInterfaceError::OwnerAPINotInstantiated)
+        }
+    }
+
+    fn receive_tx_from_s1(
+        &mut self,
         slate: Slate,
         dest_slatepack_address: String,
     ) -> Result<Option<String>, GrinWalletInterfaceError> {
-        let w = wallet_interface.write().unwrap();
+        if let Some(r) = &self.remote_owner_api {
+            let address = match SlatepackAddress::try_from(dest_slatepack_address.as_str()) {
+                Ok(a) => a,
+                Err(_) => return Err(GrinWalletInterfaceError::InvalidSlatepackAddress),
+            };
+            let ret_slate = r.receive_tx(&slate)?;
+            return Ok(Some(r.create_slatepack_message(&ret_slate, vec![address])?));
+        }
         let ret_slate;
-        if let Some(f) = &w.foreign_api {
+        if let Some(f) = &self.foreign_api {
             ret_slate = f.receive_tx(&slate, None, None)?;
         } else {
             return Err(GrinWalletInterfaceError::ForeignAPINotInstantiated);
         }
-        if let Some(o) = &w.owner_api {
+        if let Some(o) = &self.owner_api {
             let encrypted =
                 WalletInterface::encrypt_slatepack(o, &dest_slatepack_address, &ret_slate)?;
             return Ok(Some(encrypted));
@@ -477,13 +1155,17 @@ where
         }
     }
 
-    pub async fn finalize_from_s2(
-        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+    fn finalize_from_s2(
+        &mut self,
         slate: Slate,
         send_to_chain: bool,
     ) -> Result<Option<String>, GrinWalletInterfaceError> {
-        let w = wallet_interface.write().unwrap();
-        if let Some(o) = &w.owner_api {
+        if let Some(r) = &self.remote_owner_api {
+            let ret_slate = r.finalize_tx(&slate)?;
+            r.post_tx(&ret_slate, true)?;
+            return Ok(None);
+        }
+        if let Some(o) = &self.owner_api {
             let ret_slate = o.finalize_tx(None, &slate)?;
             o.post_tx(None, &ret_slate, true)?;
             return Ok(None);
@@ -492,12 +1174,53 @@ where
         }
     }
 
-    pub async fn cancel_tx(
-        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
-        id: u32,
-    ) -> Result<u32, GrinWalletInterfaceError> {
-        let w = wallet_interface.write().unwrap();
-        if let Some(o) = &w.owner_api {
+    fn scan(
+        &mut self,
+        start_height: Option<u64>,
+        delete_unconfirmed: bool,
+    ) -> Result<(), GrinWalletInterfaceError> {
+        if let Some(r) = &self.remote_owner_api {
+            return r.scan(start_height, delete_unconfirmed);
+        }
+        if let Some(o) = &self.owner_api {
+            o.scan(None, start_height, delete_unconfirmed)?;
+            return Ok(());
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    fn retrieve_payment_proof(&self, tx_id: u32) -> Result<PaymentProof, GrinWalletInterfaceError> {
+        if let Some(r) = &self.remote_owner_api {
+            return r.retrieve_payment_proof(tx_id);
+        }
+        if let Some(o) = &self.owner_api {
+            return Ok(o.retrieve_payment_proof(None, true, Some(tx_id), None)?);
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    fn verify_payment_proof(
+        &self,
+        proof: PaymentProof,
+    ) -> Result<(bool, bool), GrinWalletInterfaceError> {
+        if let Some(r) = &self.remote_owner_api {
+            return r.verify_payment_proof(&proof);
+        }
+        if let Some(o) = &self.owner_api {
+            return Ok(o.verify_payment_proof(None, &proof)?);
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    fn cancel_tx(&mut self, id: u32) -> Result<u32, GrinWalletInterfaceError> {
+        if let Some(r) = &self.remote_owner_api {
+            r.cancel_tx(id)?;
+            return Ok(id);
+        }
+        if let Some(o) = &self.owner_api {
             o.cancel_tx(None, Some(id), None)?;
             return Ok(id);
         } else {
@@ -505,23 +1228,154 @@ where
         }
     }
 
-    /*pub async fn tx_lock_outputs(
-        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
-        init_args: InitTxArgs,
-    ) -> Result<Slate, GrinWalletInterfaceError> {
-        let w = wallet_interface.write().unwrap();
-        if let Some(o) = &w.owner_api {
-            let slate = {
-                o.init_send_tx(None, init_args)?
+    fn get_stored_tx(&self, tx_id: u32) -> Result<Option<Slate>, GrinWalletInterfaceError> {
+        if let Some(r) = &self.remote_owner_api {
+            return r.get_stored_tx(tx_id);
+        }
+        if let Some(o) = &self.owner_api {
+            return Ok(o.get_stored_tx(None, Some(tx_id), None)?);
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    fn kernel_height(
+        &self,
+        excess: Commitment,
+        min_height: Option<u64>,
+    ) -> Result<Option<u64>, GrinWalletInterfaceError> {
+        // Neither owner API looks up kernels, so the wallet's node is asked directly. A
+        // remote wallet's node isn't known here, so its kernels can't be found.
+        if self.remote_owner_api.is_some() {
+            return Ok(None);
+        }
+        if let Some(o) = &self.owner_api {
+            let mut node_client = {
+                let mut w_lock = o.wallet_inst.lock();
+                let w = w_lock.lc_provider()?.wallet_inst()?;
+                w.w2n_client().clone()
             };
-            return Ok(slate);
+            let kernel = node_client.get_kernel(&excess, min_height, None)?;
+            return Ok(kernel.map(|(_, height, _)| height));
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
         }
-    }*/
+    }
 
-    /*pub async fn get_recovery_phrase(wallet_interface: Arc<RwLock<WalletInterface<L, C>>>, password: String) -> String {
-        let mut w = wallet_interface.read().unwrap();
-        w.owner_api.get_mnemonic(name, password.into())
-    }*/
+    fn repost_tx(&mut self, tx_id: u32, fluff: bool) -> Result<(), GrinWalletInterfaceError> {
+        let slate = self
+            .get_stored_tx(tx_id)?
+            .ok_or(GrinWalletInterfaceError::StoredTxNotFound(tx_id))?;
+        if let Some(r) = &self.remote_owner_api {
+            return r.post_tx(&slate, fluff);
+        }
+        if let Some(o) = &self.owner_api {
+            o.post_tx(None, &slate, fluff)?;
+            return Ok(());
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        read_slatepack_file, slatepack_file_name, write_slatepack_file, MockWallet, OutputStatus,
+        ScanSummary, Slate, SlateState, TxEstimate,
+    };
+    use grin_core::core::FeeFields;
+    use grin_core::libtx::tx_fee;
+
+    #[test]
+    fn test_estimate_from_slate() {
+        let mut slate = Slate::blank(2, false);
+        slate.amount = 12_000_000_000;
+        slate.fee_fields = FeeFields::new(0, tx_fee(12, 2, 1)).unwrap();
+        let estimate = TxEstimate::from_estimate_slate(5_000_000_000, 1, &slate);
+        assert_eq!(estimate.num_inputs, 12);
+        assert_eq!(estimate.num_change_outputs, 1);
+
+        // Without change only the payment output is paid for
+        slate.amount = 5_000_000_000 + tx_fee(3, 1, 1);
+        slate.fee_fields = FeeFields::new(0, tx_fee(3, 1, 1)).unwrap();
+        let estimate = TxEstimate::from_estimate_slate(5_000_000_000, 1, &slate);
+        assert_eq!(estimate.num_inputs, 3);
+        assert_eq!(estimate.num_change_outputs, 0);
+    }
+
+    #[test]
+    fn test_estimate_from_outputs() {
+        let estimate = TxEstimate::from_outputs(5, 1, &[10_000_000_000, 2]).unwrap();
+        assert_eq!(estimate.num_inputs, 2);
+        assert_eq!(estimate.num_change_outputs, 1);
+        assert_eq!(estimate.fee, tx_fee(2, 2, 1));
+        assert_eq!(estimate.change(), 10_000_000_002 - 5 - tx_fee(2, 2, 1));
+
+        // Spending the outputs exactly leaves no change, so pays the smaller fee
+        let exact = 10_000_000_000 + tx_fee(1, 1, 1);
+        let estimate = TxEstimate::from_outputs(10_000_000_000, 1, &[exact]).unwrap();
+        assert_eq!(estimate.num_change_outputs, 0);
+        assert_eq!(estimate.fee, tx_fee(1, 1, 1));
+        assert_eq!(estimate.change(), 0);
+
+        assert_eq!(
+            TxEstimate::from_outputs(10_000_000_000, 1, &[exact - 1]),
+            None
+        );
+        assert_eq!(TxEstimate::from_outputs(0, 1, &[]), None);
+    }
+
+    #[test]
+    fn test_scan_summary() {
+        let before = vec![
+            MockWallet::output(0, 10, OutputStatus::Unspent, None),
+            MockWallet::output(1, 10, OutputStatus::Locked, None),
+            MockWallet::output(2, 10, OutputStatus::Locked, None),
+            MockWallet::output(3, 10, OutputStatus::Unspent, None),
+            MockWallet::output(4, 10, OutputStatus::Spent, None),
+        ];
+        let after = vec![
+            MockWallet::output(0, 10, OutputStatus::Spent, None),
+            MockWallet::output(1, 10, OutputStatus::Spent, None),
+            MockWallet::output(2, 10, OutputStatus::Unspent, None),
+            MockWallet::output(4, 10, OutputStatus::Spent, None),
+            MockWallet::output(5, 10, OutputStatus::Unspent, None),
+        ];
+        // Marked spent counts as much as dropped, already spent doesn't count again
+        assert_eq!(
+            ScanSummary::between(&before, &after),
+            ScanSummary {
+                outputs_before: 5,
+                outputs_after: 5,
+                restored: 1,
+                deleted: 3,
+                unlocked: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_slatepack_files() {
+        let slate = Slate::blank(2, false);
+        let file_name = slatepack_file_name(&slate, &SlateState::Standard1);
+        assert_eq!(file_name, format!("{}.S1.slatepack", slate.id));
+
+        let path = std::env::temp_dir().join(format!("grin-gui-test-{}", file_name));
+        write_slatepack_file(&path, "BEGINSLATEPACK. saved ENDSLATEPACK.").unwrap();
+        assert_eq!(
+            read_slatepack_file(&path).unwrap(),
+            "BEGINSLATEPACK. saved ENDSLATEPACK."
+        );
+
+        // Reading trims whatever surrounds the armor, anything else is taken as binary
+        std::fs::write(&path, "\nBEGINSLATEPACK. saved ENDSLATEPACK.\n").unwrap();
+        assert_eq!(
+            read_slatepack_file(&path).unwrap(),
+            "BEGINSLATEPACK. saved ENDSLATEPACK."
+        );
+        std::fs::write(&path, [0u8, 1, 2, 3]).unwrap();
+        assert!(read_slatepack_file(&path).is_err());
+        let _ = std::fs::remove_file(&path);
+    }
 }
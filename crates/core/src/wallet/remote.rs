@@ -0,0 +1,737 @@
+/// Client for a grin-wallet instance running elsewhere, driven over the Owner API v3.
+///
+/// The v3 Owner API only accepts encrypted requests: the client first performs an ECDH
+/// exchange via `init_secure_api`, after which every call is wrapped in an
+/// `encrypted_request_v3` envelope using the shared key. Receiving a transaction is a
+/// Foreign API operation, so that goes to the (unencrypted) Foreign API v2 listener.
+use grin_util::secp::key::{PublicKey, SecretKey};
+use grin_util::{from_hex, static_secp_instance, ToHex};
+use grin_wallet_api::{EncryptedRequest, EncryptedResponse, JsonId};
+use grin_wallet_libwallet::{
+    AcctPathMapping, InitTxArgs, IssueInvoiceTxArgs, OutputCommitMapping, PaymentProof,
+    RetrieveTxQueryArgs, RetrieveTxQuerySortOrder, Slate, SlateVersion, Slatepack,
+    SlatepackAddress, StatusMessage, TxLogEntry, TxLogEntryType, VersionedSlate, WalletInfo,
+};
+
+use isahc::auth::{Authentication, Credentials};
+use isahc::config::Configurable;
+use isahc::{ReadResponseExt, Request, RequestExt};
+
+use rand::RngCore;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use crate::error::GrinWalletInterfaceError;
+
+/// Basic auth user name expected by grin-wallet listeners
+const API_USER_NAME: &str = "grin";
+
+const OWNER_API_V3_PATH: &str = "/v3/owner";
+const FOREIGN_API_V2_PATH: &str = "/v2/foreign";
+
+pub struct RemoteOwnerApi {
+    owner_api_url: String,
+    foreign_api_url: Option<String>,
+    owner_api_secret: Option<String>,
+    foreign_api_secret: Option<String>,
+    // Shared key derived during `init_secure_api`
+    shared_key: Option<SecretKey>,
+    // Token returned by `open_wallet`, passed to every subsequent call
+    token: Option<String>,
+    next_id: AtomicU32,
+}
+
+impl RemoteOwnerApi {
+    /// Base urls are given without the API path, e.g. `http://127.0.0.1:3420`
+    pub fn new(
+        owner_api_url: &str,
+        foreign_api_url: Option<&str>,
+        owner_api_secret: Option<String>,
+        foreign_api_secret: Option<String>,
+    ) -> Self {
+        Self {
+            owner_api_url: format!("{}{}", owner_api_url.trim_end_matches('/'), OWNER_API_V3_PATH),
+            foreign_api_url: foreign_api_url
+                .map(|u| format!("{}{}", u.trim_end_matches('/'), FOREIGN_API_V2_PATH)),
+            owner_api_secret,
+            foreign_api_secret,
+            shared_key: None,
+            token: None,
+            next_id: AtomicU32::new(1),
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.token.is_some()
+    }
+
+    fn next_id(&self) -> u32 {
+        self.next_id.fetch_add(1, Ordering::SeqCst)
+    }
+
+    fn post(
+        url: &str,
+        secret: &Option<String>,
+        body: String,
+    ) -> Result<Value, GrinWalletInterfaceError> {
+        let mut builder = Request::post(url).header("Content-Type", "application/json");
+        if let Some(s) = secret {
+            builder = builder
+                .authentication(Authentication::basic())
+                .credentials(Credentials::new(API_USER_NAME, s.as_str()));
+        }
+        let mut response = builder.body(body)?.send()?;
+        if !response.status().is_success() {
+            return Err(GrinWalletInterfaceError::RemoteOwnerApi(format!(
+                "{} returned HTTP {}",
+                url,
+                response.status()
+            )));
+        }
+        let text = response.text()?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Unwraps a JSON-RPC response of the form `{"result": {"Ok": ..}}`
+    fn unwrap_result<T: DeserializeOwned>(response: Value) -> Result<T, GrinWalletInterfaceError> {
+        if let Some(e) = response.get("error") {
+            return Err(GrinWalletInterfaceError::RemoteOwnerApi(e.to_string()));
+        }
+        let result = response.get("result").ok_or_else(|| {
+            GrinWalletInterfaceError::RemoteOwnerApi(format!("Malformed response: {}", response))
+        })?;
+        if let Some(e) = result.get("Err") {
+            return Err(GrinWalletInterfaceError::RemoteOwnerApi(e.to_string()));
+        }
+        match result.get("Ok") {
+            Some(ok) => Ok(serde_json::from_value(ok.clone())?),
+            None => Err(GrinWalletInterfaceError::RemoteOwnerApi(format!(
+                "Malformed response: {}",
+                response
+            ))),
+        }
+    }
+
+    fn secp_err(e: grin_util::secp::Error) -> GrinWalletInterfaceError {
+        GrinWalletInterfaceError::RemoteOwnerApi(format!("ECDH failure: {}", e))
+    }
+
+    /// ECDH handshake with the owner listener, deriving the key used to encrypt all
+    /// further requests
+    pub fn init_secure_api(&mut self) -> Result<(), GrinWalletInterfaceError> {
+        let secp_inst = static_secp_instance();
+        let secp = secp_inst.lock();
+
+        let mut bytes = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        let sec_key = SecretKey::from_slice(&secp, &bytes).map_err(RemoteOwnerApi::secp_err)?;
+        let pub_key = PublicKey::from_secret_key(&secp, &sec_key).map_err(RemoteOwnerApi::secp_err)?;
+
+        let req = json!({
+            "jsonrpc": "2.0",
+            "method": "init_secure_api",
+            "id": self.next_id(),
+            "params": {
+                "ecdh_pubkey": pub_key.serialize_vec(&secp, true).to_hex(),
+            }
+        });
+        let res = RemoteOwnerApi::post(&self.owner_api_url, &self.owner_api_secret, req.to_string())?;
+        let server_pub_key_hex: String = RemoteOwnerApi::unwrap_result(res)?;
+
+        let server_pub_key_bytes = from_hex(&server_pub_key_hex).map_err(|e| {
+            GrinWalletInterfaceError::RemoteOwnerApi(format!("Invalid server public key: {}", e))
+        })?;
+        let mut shared_pub_key =
+            PublicKey::from_slice(&secp, &server_pub_key_bytes).map_err(RemoteOwnerApi::secp_err)?;
+        shared_pub_key
+            .mul_assign(&secp, &sec_key)
+            .map_err(RemoteOwnerApi::secp_err)?;
+        let x_coord = shared_pub_key.serialize_vec(&secp, true);
+        let shared_key =
+            SecretKey::from_slice(&secp, &x_coord[1..]).map_err(RemoteOwnerApi::secp_err)?;
+
+        self.shared_key = Some(shared_key);
+        Ok(())
+    }
+
+    /// Encrypts and sends an Owner API call, returning the decrypted `Ok` value
+    fn call<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Value,
+    ) -> Result<T, GrinWalletInterfaceError> {
+        let key = self.shared_key.as_ref().ok_or_else(|| {
+            GrinWalletInterfaceError::RemoteOwnerApi("Secure API not initialized".to_owned())
+        })?;
+        let id = JsonId::IntId(self.next_id());
+        let inner = json!({
+            "jsonrpc": "2.0",
+            "method": method,
+            "id": id,
+            "params": params,
+        });
+        let enc_req = EncryptedRequest::from_json(&id, &inner, key)?;
+        let res = RemoteOwnerApi::post(
+            &self.owner_api_url,
+            &self.owner_api_secret,
+            enc_req.as_json_str()?,
+        )?;
+        // Failures to decrypt come back as plain JSON-RPC errors
+        if let Some(e) = res.get("error") {
+            return Err(GrinWalletInterfaceError::RemoteOwnerApi(e.to_string()));
+        }
+        let enc_res: EncryptedResponse = serde_json::from_value(res)?;
+        RemoteOwnerApi::unwrap_result(enc_res.decrypt(key)?)
+    }
+
+    fn token(&self) -> Result<&str, GrinWalletInterfaceError> {
+        self.token.as_deref().ok_or_else(|| {
+            GrinWalletInterfaceError::RemoteOwnerApi("Remote wallet is not open".to_owned())
+        })
+    }
+
+    fn slate_to_json(slate: &Slate) -> Result<Value, GrinWalletInterfaceError> {
+        let versioned = VersionedSlate::into_version(slate.clone(), SlateVersion::V4)?;
+        Ok(serde_json::to_value(versioned)?)
+    }
+
+    fn slate_from_json(value: Value) -> Result<Slate, GrinWalletInterfaceError> {
+        let versioned: VersionedSlate = serde_json::from_value(value)?;
+        Ok(Slate::from(versioned))
+    }
+
+    pub fn open_wallet(&mut self, password: &str) -> Result<(), GrinWalletInterfaceError> {
+        self.init_secure_api()?;
+        let token: String = self.call(
+            "open_wallet",
+            json!({
+                "name": null,
+                "password": password,
+            }),
+        )?;
+        self.token = Some(token);
+        Ok(())
+    }
+
+    pub fn close_wallet(&mut self) -> Result<(), GrinWalletInterfaceError> {
+        if self.token.is_some() {
+            let _: Value = self.call("close_wallet", json!({ "name": null }))?;
+        }
+        self.token = None;
+        self.shared_key = None;
+        Ok(())
+    }
+
+    pub fn start_updater(&self, frequency: Duration) -> Result<(), GrinWalletInterfaceError> {
+        let _: Value = self.call(
+            "start_updater",
+            json!({
+                "token": self.token()?,
+                "frequency": frequency.as_millis() as u64,
+            }),
+        )?;
+        Ok(())
+    }
+
+    pub fn get_updater_messages(
+        &self,
+        count: u32,
+    ) -> Result<Vec<StatusMessage>, GrinWalletInterfaceError> {
+        self.call("get_updater_messages", json!({ "count": count }))
+    }
+
+    pub fn retrieve_summary_info(
+        &self,
+        refresh_from_node: bool,
+        minimum_confirmations: u64,
+    ) -> Result<(bool, WalletInfo), GrinWalletInterfaceError> {
+        self.call(
+            "retrieve_summary_info",
+            json!({
+                "token": self.token()?,
+                "refresh_from_node": refresh_from_node,
+                "minimum_confirmations": minimum_confirmations,
+            }),
+        )
+    }
+
+    /// The v3 RPC has no query arguments, so the subset the GUI uses is applied here
+    pub fn retrieve_txs(
+        &self,
+        refresh_from_node: bool,
+        query_args: Option<RetrieveTxQueryArgs>,
+    ) -> Result<(bool, Vec<TxLogEntry>), GrinWalletInterfaceError> {
+        let (refreshed, mut txs): (bool, Vec<TxLogEntry>) = self.call(
+            "retrieve_txs",
+            json!({
+                "token": self.token()?,
+                "refresh_from_node": refresh_from_node,
+                "tx_id": null,
+                "tx_slate_id": null,
+            }),
+        )?;
+        if let Some(q) = query_args {
+            if q.exclude_cancelled == Some(true) {
+                txs.retain(|t| {
+                    t.tx_type != TxLogEntryType::TxReceivedCancelled
+                        && t.tx_type != TxLogEntryType::TxSentCancelled
+                });
+            }
+            if q.include_outstanding_only == Some(true) {
+                txs.retain(|t| !t.confirmed);
+            }
+            if let Some(RetrieveTxQuerySortOrder::Desc) = q.sort_order {
+                txs.sort_by(|a, b| b.id.cmp(&a.id));
+            }
+        }
+        Ok((refreshed, txs))
+    }
+
+    pub fn retrieve_outputs(
+        &self,
+        include_spent: bool,
+        refresh_from_node: bool,
+    ) -> Result<(bool, Vec<OutputCommitMapping>), GrinWalletInterfaceError> {
+        self.call(
+            "retrieve_outputs",
+            json!({
+                "token": self.token()?,
+                "include_spent": include_spent,
+                "refresh_from_node": refresh_from_node,
+                "tx_id": null,
+            }),
+        )
+    }
+
+    pub fn get_slatepack_address(
+        &self,
+        derivation_index: u32,
+    ) -> Result<SlatepackAddress, GrinWalletInterfaceError> {
+        self.call(
+            "get_slatepack_address",
+            json!({
+                "token": self.token()?,
+                "derivation_index": derivation_index,
+            }),
+        )
+    }
+
+    pub fn accounts(&self) -> Result<Vec<AcctPathMapping>, GrinWalletInterfaceError> {
+        self.call("accounts", json!({ "token": self.token()? }))
+    }
+
+    pub fn create_account_path(&self, label: &str) -> Result<(), GrinWalletInterfaceError> {
+        let _: Value = self.call(
+            "create_account_path",
+            json!({
+                "token": self.token()?,
+                "label": label,
+            }),
+        )?;
+        Ok(())
+    }
+
+    pub fn set_active_account(&self, label: &str) -> Result<(), GrinWalletInterfaceError> {
+        let _: Value = self.call(
+            "set_active_account",
+            json!({
+                "token": self.token()?,
+                "label": label,
+            }),
+        )?;
+        Ok(())
+    }
+
+    pub fn init_send_tx(&self, args: InitTxArgs) -> Result<Slate, GrinWalletInterfaceError> {
+        let res: Value = self.call(
+            "init_send_tx",
+            json!({
+                "token": self.token()?,
+                "args": args,
+            }),
+        )?;
+        RemoteOwnerApi::slate_from_json(res)
+    }
+
+    pub fn issue_invoice_tx(
+        &self,
+        args: IssueInvoiceTxArgs,
+    ) -> Result<Slate, GrinWalletInterfaceError> {
+        let res: Value = self.call(
+            "issue_invoice_tx",
+            json!({
+                "token": self.token()?,
+                "args": args,
+            }),
+        )?;
+        RemoteOwnerApi::slate_from_json(res)
+    }
+
+    pub fn process_invoice_tx(
+        &self,
+        slate: &Slate,
+        args: InitTxArgs,
+    ) -> Result<Slate, GrinWalletInterfaceError> {
+        let res: Value = self.call(
+            "process_invoice_tx",
+            json!({
+                "token": self.token()?,
+                "slate": RemoteOwnerApi::slate_to_json(slate)?,
+                "args": args,
+            }),
+        )?;
+        RemoteOwnerApi::slate_from_json(res)
+    }
+
+    pub fn tx_lock_outputs(&self, slate: &Slate) -> Result<(), GrinWalletInterfaceError> {
+        let _: Value = self.call(
+            "tx_lock_outputs",
+            json!({
+                "token": self.token()?,
+                "slate": RemoteOwnerApi::slate_to_json(slate)?,
+            }),
+        )?;
+        Ok(())
+    }
+
+    pub fn finalize_tx(&self, slate: &Slate) -> Result<Slate, GrinWalletInterfaceError> {
+        let res: Value = self.call(
+            "finalize_tx",
+            json!({
+                "token": self.token()?,
+                "slate": RemoteOwnerApi::slate_to_json(slate)?,
+            }),
+        )?;
+        RemoteOwnerApi::slate_from_json(res)
+    }
+
+    pub fn post_tx(&self, slate: &Slate, fluff: bool) -> Result<(), GrinWalletInterfaceError> {
+        let _: Value = self.call(
+            "post_tx",
+            json!({
+                "token": self.token()?,
+                "slate": RemoteOwnerApi::slate_to_json(slate)?,
+                "fluff": fluff,
+            }),
+        )?;
+        Ok(())
+    }
+
+    pub fn cancel_tx(&self, tx_id: u32) -> Result<(), GrinWalletInterfaceError> {
+        let _: Value = self.call(
+            "cancel_tx",
+            json!({
+                "token": self.token()?,
+                "tx_id": tx_id,
+                "tx_slate_id": null,
+            }),
+        )?;
+        Ok(())
+    }
+
+    pub fn get_stored_tx(&self, tx_id: u32) -> Result<Option<Slate>, GrinWalletInterfaceError> {
+        let slate: Option<Value> = self.call(
+            "get_stored_tx",
+            json!({
+                "token": self.token()?,
+                "id": tx_id,
+                "slate_id": null,
+            }),
+        )?;
+        slate.map(RemoteOwnerApi::slate_from_json).transpose()
+    }
+
+    pub fn scan(
+        &self,
+        start_height: Option<u64>,
+        delete_unconfirmed: bool,
+    ) -> Result<(), GrinWalletInterfaceError> {
+        let _: Value = self.call(
+            "scan",
+            json!({
+                "token": self.token()?,
+                "start_height": start_height,
+                "delete_unconfirmed": delete_unconfirmed,
+            }),
+        )?;
+        Ok(())
+    }
+
+    pub fn retrieve_payment_proof(
+        &self,
+        tx_id: u32,
+    ) -> Result<PaymentProof, GrinWalletInterfaceError> {
+        self.call(
+            "retrieve_payment_proof",
+            json!({
+                "token": self.token()?,
+                "refresh_from_node": true,
+                "tx_id": tx_id,
+                "tx_slate_id": null,
+            }),
+        )
+    }
+
+    pub fn verify_payment_proof(
+        &self,
+        proof: &PaymentProof,
+    ) -> Result<(bool, bool), GrinWalletInterfaceError> {
+        self.call(
+            "verify_payment_proof",
+            json!({
+                "token": self.token()?,
+                "proof": proof,
+            }),
+        )
+    }
+
+    pub fn create_slatepack_message(
+        &self,
+        slate: &Slate,
+        recipients: Vec<SlatepackAddress>,
+    ) -> Result<String, GrinWalletInterfaceError> {
+        let recipients: Vec<String> = recipients.iter().map(|r| r.to_string()).collect();
+        self.call(
+            "create_slatepack_message",
+            json!({
+                "token": self.token()?,
+                "slate": RemoteOwnerApi::slate_to_json(slate)?,
+                "sender_index": 0,
+                "recipients": recipients,
+            }),
+        )
+    }
+
+    pub fn decode_slatepack_message(
+        &self,
+        message: &str,
+    ) -> Result<Slatepack, GrinWalletInterfaceError> {
+        self.call(
+            "decode_slatepack_message",
+            json!({
+                "token": self.token()?,
+                "message": message,
+                "secret_indices": [0],
+            }),
+        )
+    }
+
+    pub fn slate_from_slatepack_message(
+        &self,
+        message: &str,
+    ) -> Result<Slate, GrinWalletInterfaceError> {
+        let res: Value = self.call(
+            "slate_from_slatepack_message",
+            json!({
+                "token": self.token()?,
+                "message": message,
+                "secret_indices": [0],
+            }),
+        )?;
+        RemoteOwnerApi::slate_from_json(res)
+    }
+
+    /// Receive goes through the remote wallet's Foreign API v2 listener
+    pub fn receive_tx(&self, slate: &Slate) -> Result<Slate, GrinWalletInterfaceError> {
+        let url = self
+            .foreign_api_url
+            .as_ref()
+            .ok_or(GrinWalletInterfaceError::RemoteForeignApiNotConfigured)?;
+        let req = json!({
+            "jsonrpc": "2.0",
+            "method": "receive_tx",
+            "id": self.next_id(),
+            "params": [RemoteOwnerApi::slate_to_json(slate)?, null, null],
+        });
+        let res = RemoteOwnerApi::post(url, &self.foreign_api_secret, req.to_string())?;
+        let slate: Value = RemoteOwnerApi::unwrap_result(res)?;
+        RemoteOwnerApi::slate_from_json(slate)
+    }
+
+    /// Invoices are finalized by the issuer's Foreign API v2 listener, which also posts
+    /// the transaction
+    pub fn finalize_invoice_tx(&self, slate: &Slate) -> Result<Slate, GrinWalletInterfaceError> {
+        let url = self
+            .foreign_api_url
+            .as_ref()
+            .ok_or(GrinWalletInterfaceError::RemoteForeignApiNotConfigured)?;
+        let req = json!({
+            "jsonrpc": "2.0",
+            "method": "finalize_tx",
+            "id": self.next_id(),
+            "params": [RemoteOwnerApi::slate_to_json(slate)?],
+        });
+        let res = RemoteOwnerApi::post(url, &self.foreign_api_secret, req.to_string())?;
+        let slate: Value = RemoteOwnerApi::unwrap_result(res)?;
+        RemoteOwnerApi::slate_from_json(slate)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// Minimal stand-in for a grin-wallet owner listener. Performs its own half of the
+    /// ECDH exchange and answers a handful of encrypted calls.
+    struct StandIn {
+        shared_key: Option<SecretKey>,
+        methods_seen: Arc<Mutex<Vec<String>>>,
+        raw_bodies: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl StandIn {
+        fn handle(&mut self, body: &str) -> Value {
+            self.raw_bodies.lock().unwrap().push(body.to_owned());
+            let req: Value = serde_json::from_str(body).unwrap();
+            let method = req["method"].as_str().unwrap().to_owned();
+            match method.as_str() {
+                "init_secure_api" => {
+                    let secp_inst = static_secp_instance();
+                    let secp = secp_inst.lock();
+                    let sec_key = SecretKey::from_slice(&secp, &[7u8; 32]).unwrap();
+                    let pub_key = PublicKey::from_secret_key(&secp, &sec_key).unwrap();
+
+                    let client_bytes =
+                        from_hex(req["params"]["ecdh_pubkey"].as_str().unwrap()).unwrap();
+                    let mut shared = PublicKey::from_slice(&secp, &client_bytes).unwrap();
+                    shared.mul_assign(&secp, &sec_key).unwrap();
+                    let x_coord = shared.serialize_vec(&secp, true);
+                    self.shared_key = Some(SecretKey::from_slice(&secp, &x_coord[1..]).unwrap());
+
+                    self.methods_seen.lock().unwrap().push(method);
+                    json!({
+                        "jsonrpc": "2.0",
+                        "id": req["id"],
+                        "result": { "Ok": pub_key.serialize_vec(&secp, true).to_hex() }
+                    })
+                }
+                "encrypted_request_v3" => {
+                    let key = self.shared_key.clone().unwrap();
+                    let enc_req: EncryptedRequest = serde_json::from_value(req).unwrap();
+                    let inner = enc_req.decrypt(&key).unwrap();
+                    let inner_method = inner["method"].as_str().unwrap().to_owned();
+                    let result = match inner_method.as_str() {
+                        "open_wallet" => json!({ "Ok": "d202964900000000d302964900000000d402964900000000d502964900000000" }),
+                        "retrieve_summary_info" => {
+                            assert_eq!(inner["params"]["minimum_confirmations"], 10);
+                            json!({ "Ok": [true, {
+                                "last_confirmed_height": "1000",
+                                "minimum_confirmations": "10",
+                                "total": "60000000000",
+                                "amount_awaiting_finalization": "0",
+                                "amount_awaiting_confirmation": "0",
+                                "amount_immature": "0",
+                                "amount_currently_spendable": "60000000000",
+                                "amount_locked": "0",
+                                "amount_reverted": "0"
+                            }]})
+                        }
+                        _ => json!({ "Err": { "GenericError": "unsupported" } }),
+                    };
+                    self.methods_seen.lock().unwrap().push(inner_method);
+                    let id: JsonId = serde_json::from_value(inner["id"].clone()).unwrap();
+                    let res = json!({ "jsonrpc": "2.0", "id": inner["id"], "result": result });
+                    EncryptedResponse::from_json(&id, &res, &key)
+                        .unwrap()
+                        .as_json_value()
+                        .unwrap()
+                }
+                _ => panic!("unexpected method {}", method),
+            }
+        }
+
+        fn serve(&mut self, mut stream: TcpStream) {
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut content_length = 0;
+            let mut expect_continue = false;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                let lower = line.to_lowercase();
+                if let Some(v) = lower.strip_prefix("content-length:") {
+                    content_length = v.trim().parse().unwrap();
+                }
+                if lower.starts_with("expect:") && lower.contains("100-continue") {
+                    expect_continue = true;
+                }
+            }
+            if expect_continue {
+                stream.write_all(b"HTTP/1.1 100 Continue\r\n\r\n").unwrap();
+            }
+            let mut body = vec![0u8; content_length];
+            reader.read_exact(&mut body).unwrap();
+            let res = self.handle(&String::from_utf8(body).unwrap()).to_string();
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                res.len(),
+                res
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_remote_summary_info_round_trip() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let methods_seen = Arc::new(Mutex::new(vec![]));
+        let raw_bodies = Arc::new(Mutex::new(vec![]));
+        let mut stand_in = StandIn {
+            shared_key: None,
+            methods_seen: methods_seen.clone(),
+            raw_bodies: raw_bodies.clone(),
+        };
+        let handle = thread::spawn(move || {
+            // init_secure_api, open_wallet, retrieve_summary_info
+            for stream in listener.incoming().take(3) {
+                stand_in.serve(stream.unwrap());
+            }
+        });
+
+        let mut api = RemoteOwnerApi::new(
+            &format!("http://{}/", addr),
+            None,
+            Some("secret".to_owned()),
+            None,
+        );
+        api.open_wallet("password").unwrap();
+        assert!(api.is_open());
+        let (refreshed, info) = api.retrieve_summary_info(false, 10).unwrap();
+        handle.join().unwrap();
+
+        assert!(refreshed);
+        assert_eq!(info.last_confirmed_height, 1000);
+        assert_eq!(info.amount_currently_spendable, 60_000_000_000);
+        assert_eq!(
+            *methods_seen.lock().unwrap(),
+            vec!["init_secure_api", "open_wallet", "retrieve_summary_info"]
+        );
+        // Nothing but the handshake should go over the wire in the clear
+        let bodies = raw_bodies.lock().unwrap();
+        assert!(bodies[1].contains("encrypted_request_v3"));
+        assert!(!bodies[2].contains("retrieve_summary_info"));
+        assert!(!bodies[1].contains("password"));
+    }
+
+    #[test]
+    fn test_receive_requires_foreign_url() {
+        let api = RemoteOwnerApi::new("http://127.0.0.1:3420", None, None, None);
+        let slate = Slate::blank(2, false);
+        match api.receive_tx(&slate) {
+            Err(GrinWalletInterfaceError::RemoteForeignApiNotConfigured) => {}
+            other => panic!("unexpected result: {:?}", other.map(|s| s.id)),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use iced_futures::{
+    self,
+    futures::{channel::mpsc, stream::StreamExt},
+};
+use std::hash::Hash;
+
+use super::StatusMessage;
+
+// Mirrors node::subscriber, see there for reference
+
+#[derive(Clone, Debug)]
+pub enum UIMessage {
+    None,
+    UpdaterStatus(StatusMessage),
+}
+
+pub enum State {
+    Ready,
+    Listening { receiver: mpsc::Receiver<UIMessage> },
+    Finished,
+}
+
+/// Streams wallet updater messages. The first item carries the sender the wallet
+/// backend should push its messages into.
+pub fn subscriber<I: 'static + Hash + Copy + Send>(
+    id: I,
+) -> iced::Subscription<(I, UIMessage, Option<mpsc::Sender<UIMessage>>)> {
+    iced::Subscription::from_recipe(WalletSubscriber { id })
+}
+
+pub struct WalletSubscriber<I> {
+    id: I,
+}
+
+impl<H, I, T> iced_native::subscription::Recipe<H, I> for WalletSubscriber<T>
+where
+    T: 'static + Hash + Copy + Send,
+    H: std::hash::Hasher,
+{
+    type Output = (T, UIMessage, Option<mpsc::Sender<UIMessage>>);
+
+    fn hash(&self, state: &mut H) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+        self.id.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: futures::stream::BoxStream<'static, I>,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        let id = self.id;
+        Box::pin(futures::stream::unfold(
+            State::Ready,
+            move |state| async move {
+                match state {
+                    State::Ready => {
+                        // Scans can produce bursts of messages, give them some room
+                        let (sender, receiver) = mpsc::channel::<UIMessage>(100);
+                        Some((
+                            (id, UIMessage::None, Some(sender)),
+                            State::Listening { receiver },
+                        ))
+                    }
+                    State::Listening { mut receiver } => match receiver.next().await {
+                        Some(msg) => Some(((id, msg, None), State::Listening { receiver })),
+                        // All senders gone, nothing more will arrive
+                        None => Some(((id, UIMessage::None, None), State::Finished)),
+                    },
+                    State::Finished => {
+                        let _: () = iced::futures::future::pending().await;
+                        None
+                    }
+                }
+            },
+        ))
+    }
+}
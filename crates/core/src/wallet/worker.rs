@@ -0,0 +1,345 @@
+/// Runs all wallet calls on a dedicated thread, so blocking Owner API and node requests
+/// never hold up the UI thread.
+///
+/// The worker thread owns the backend outright. The UI side holds a cheaply cloneable
+/// `WalletWorker` handle, submits closures over the backend and awaits their replies.
+/// Jobs run one at a time in submission order.
+use futures::channel::oneshot;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+use crate::error::GrinWalletInterfaceError;
+
+use super::WalletBackend;
+
+/// How long the UI waits for an ordinary wallet call before giving up on it
+pub const DEFAULT_JOB_TIMEOUT: Duration = Duration::from_secs(60);
+
+type WalletJob = Box<dyn FnOnce(&mut dyn WalletBackend) + Send>;
+
+/// Cancels a job submitted via `WalletWorker::run_cancellable`.
+///
+/// A job that hasn't started yet is skipped entirely and resolves with
+/// `WalletWorkerCancelled`. A job that is already running can't be interrupted (Owner
+/// API calls are not interruptible), but can check the token between its own steps, and
+/// claims it with `start` before a step that can't be stopped, after which `cancel` is
+/// refused. The awaiting UI future only resolves once the job has really returned, so
+/// the UI knows when the worker is free again.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicU8>);
+
+const TOKEN_PENDING: u8 = 0;
+const TOKEN_CANCELLED: u8 = 1;
+const TOKEN_STARTED: u8 = 2;
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Cancels the job unless it has already started a step that can't be stopped,
+    /// returning whether it's cancelled
+    pub fn cancel(&self) -> bool {
+        self.transition(TOKEN_CANCELLED) != TOKEN_STARTED
+    }
+
+    /// Claims the token for a step that can't be stopped. `false` if the job was
+    /// cancelled first and mustn't go on.
+    pub fn start(&self) -> bool {
+        self.transition(TOKEN_STARTED) != TOKEN_CANCELLED
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst) == TOKEN_CANCELLED
+    }
+
+    /// Whether the job has started a step that can't be stopped
+    pub fn is_started(&self) -> bool {
+        self.0.load(Ordering::SeqCst) == TOKEN_STARTED
+    }
+
+    // Moves a pending token to `state`, returning the state it ends up in
+    fn transition(&self, state: u8) -> u8 {
+        match self
+            .0
+            .compare_exchange(TOKEN_PENDING, state, Ordering::SeqCst, Ordering::SeqCst)
+        {
+            Ok(_) => state,
+            Err(current) => current,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct WalletWorker {
+    job_tx: mpsc::Sender<WalletJob>,
+    // Mirrors `WalletBackend::wallet_is_open` after every job, readable without a round trip
+    wallet_open: Arc<AtomicBool>,
+    timeout: Duration,
+}
+
+impl WalletWorker {
+    /// Spawns the worker thread, which runs until every handle has been dropped
+    pub fn spawn(backend: Box<dyn WalletBackend>) -> Self {
+        let (job_tx, job_rx) = mpsc::channel::<WalletJob>();
+        let wallet_open = Arc::new(AtomicBool::new(backend.wallet_is_open()));
+        let thread_wallet_open = wallet_open.clone();
+
+        thread::Builder::new()
+            .name("wallet_worker".to_string())
+            .spawn(move || {
+                let mut backend = backend;
+                while let Ok(job) = job_rx.recv() {
+                    job(backend.as_mut());
+                    thread_wallet_open.store(backend.wallet_is_open(), Ordering::SeqCst);
+                }
+                debug!("Wallet worker shutting down");
+            })
+            .expect("could not spawn wallet worker thread");
+
+        WalletWorker {
+            job_tx,
+            wallet_open,
+            timeout: DEFAULT_JOB_TIMEOUT,
+        }
+    }
+
+    /// Default timeout applied by `run`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Whether the wallet was open as of the last completed job
+    pub fn wallet_is_open(&self) -> bool {
+        self.wallet_open.load(Ordering::SeqCst)
+    }
+
+    /// Queues `f` on the worker thread. The returned future resolves with its result, or
+    /// with `WalletWorkerTimeout` if no reply arrives within the default timeout.
+    pub fn run<F, T>(
+        &self,
+        f: F,
+    ) -> impl Future<Output = Result<T, GrinWalletInterfaceError>> + Send + 'static
+    where
+        F: FnOnce(&mut dyn WalletBackend) -> Result<T, GrinWalletInterfaceError> + Send + 'static,
+        T: Send + 'static,
+    {
+        self.submit(f, self.timeout, None)
+    }
+
+    /// As `run`, but for long jobs such as scans: takes its own timeout and can be
+    /// stopped through `cancel`
+    pub fn run_cancellable<F, T>(
+        &self,
+        f: F,
+        timeout: Duration,
+        cancel: CancelToken,
+    ) -> impl Future<Output = Result<T, GrinWalletInterfaceError>> + Send + 'static
+    where
+        F: FnOnce(&mut dyn WalletBackend) -> Result<T, GrinWalletInterfaceError> + Send + 'static,
+        T: Send + 'static,
+    {
+        self.submit(f, timeout, Some(cancel))
+    }
+
+    fn submit<F, T>(
+        &self,
+        f: F,
+        timeout: Duration,
+        cancel: Option<CancelToken>,
+    ) -> impl Future<Output = Result<T, GrinWalletInterfaceError>> + Send + 'static
+    where
+        F: FnOnce(&mut dyn WalletBackend) -> Result<T, GrinWalletInterfaceError> + Send + 'static,
+        T: Send + 'static,
+    {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        let job: WalletJob = Box::new(move |backend| {
+            // Nobody waits for it anymore, e.g. it timed out behind a long scan
+            if reply_tx.is_canceled() {
+                return;
+            }
+            if cancel.map(|c| c.is_cancelled()).unwrap_or(false) {
+                let _ = reply_tx.send(Err(GrinWalletInterfaceError::WalletWorkerCancelled));
+                return;
+            }
+            // Receiver may be gone if the UI stopped waiting meanwhile, that's fine
+            let _ = reply_tx.send(f(backend));
+        });
+        let sent = self.job_tx.send(job).is_ok();
+
+        async move {
+            if !sent {
+                return Err(GrinWalletInterfaceError::WalletWorkerStopped);
+            }
+            match async_std::future::timeout(timeout, reply_rx).await {
+                Ok(Ok(res)) => res,
+                Ok(Err(_)) => Err(GrinWalletInterfaceError::WalletWorkerStopped),
+                Err(_) => Err(GrinWalletInterfaceError::WalletWorkerTimeout),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wallet::{global, MockWallet};
+    use futures::executor::block_on;
+    use std::path::PathBuf;
+    use std::sync::mpsc as std_mpsc;
+
+    fn open_worker() -> WalletWorker {
+        let worker = WalletWorker::spawn(Box::new(MockWallet::new("password").with_received(5)));
+        block_on(worker.run(|w| {
+            w.open_wallet(
+                "password".to_owned(),
+                PathBuf::new(),
+                global::ChainTypes::Testnet,
+            )
+        }))
+        .unwrap();
+        worker
+    }
+
+    #[test]
+    fn test_worker_runs_jobs_and_tracks_open_status() {
+        let worker = open_worker();
+        assert!(worker.wallet_is_open());
+
+        let (_, info) = block_on(worker.run(|w| w.get_wallet_info())).unwrap();
+        assert_eq!(info.amount_currently_spendable, 5);
+
+        block_on(worker.run(|w| w.close_wallet())).unwrap();
+        assert!(!worker.wallet_is_open());
+    }
+
+    #[test]
+    fn test_worker_times_out_slow_jobs() {
+        let worker = open_worker().with_timeout(Duration::from_millis(50));
+        let res = block_on(worker.run(|w| {
+            thread::sleep(Duration::from_millis(500));
+            w.get_wallet_info()
+        }));
+        assert!(matches!(
+            res,
+            Err(GrinWalletInterfaceError::WalletWorkerTimeout)
+        ));
+    }
+
+    #[test]
+    fn test_worker_skips_jobs_nobody_waits_for() {
+        let worker = open_worker();
+
+        let (release_tx, release_rx) = std_mpsc::channel::<()>();
+        let busy = worker.run(move |_| {
+            let _ = release_rx.recv();
+            Ok(())
+        });
+
+        // Times out while queued behind the busy job
+        let (ran_tx, ran_rx) = std_mpsc::channel::<()>();
+        let queued = worker
+            .clone()
+            .with_timeout(Duration::from_millis(50))
+            .run(move |_| {
+                let _ = ran_tx.send(());
+                Ok(())
+            });
+        assert!(matches!(
+            block_on(queued),
+            Err(GrinWalletInterfaceError::WalletWorkerTimeout)
+        ));
+
+        release_tx.send(()).unwrap();
+        block_on(busy).unwrap();
+        block_on(worker.run(|_| Ok(()))).unwrap();
+        assert!(ran_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_worker_cancels_queued_jobs() {
+        let worker = open_worker();
+
+        // Hold the worker busy until the queued job has been cancelled
+        let (release_tx, release_rx) = std_mpsc::channel::<()>();
+        let busy = worker.run(move |_| {
+            let _ = release_rx.recv();
+            Ok(())
+        });
+
+        let token = CancelToken::new();
+        let (ran_tx, ran_rx) = std_mpsc::channel::<()>();
+        let queued = worker.run_cancellable(
+            move |_| {
+                let _ = ran_tx.send(());
+                Ok(())
+            },
+            Duration::from_secs(10),
+            token.clone(),
+        );
+        token.cancel();
+
+        release_tx.send(()).unwrap();
+        block_on(busy).unwrap();
+        assert!(matches!(
+            block_on(queued),
+            Err(GrinWalletInterfaceError::WalletWorkerCancelled)
+        ));
+        assert!(ran_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_cancel_token_start() {
+        let token = CancelToken::new();
+        assert!(token.start());
+        assert!(token.is_started());
+        assert!(!token.cancel());
+        assert!(!token.is_cancelled());
+
+        let token = CancelToken::new();
+        assert!(token.cancel());
+        assert!(!token.start());
+        assert!(token.is_cancelled());
+        assert!(token.cancel());
+    }
+
+    #[test]
+    fn test_worker_waits_for_cancelled_running_job() {
+        let worker = open_worker();
+
+        let token = CancelToken::new();
+        let job_token = token.clone();
+        let (started_tx, started_rx) = std_mpsc::channel::<()>();
+        let (release_tx, release_rx) = std_mpsc::channel::<()>();
+        let running = worker.run_cancellable(
+            move |_| {
+                let _ = started_tx.send(());
+                let _ = release_rx.recv();
+                // A long job checks the token between its steps
+                if job_token.is_cancelled() {
+                    return Err(GrinWalletInterfaceError::WalletWorkerCancelled);
+                }
+                Ok(())
+            },
+            Duration::from_secs(10),
+            token.clone(),
+        );
+
+        let (done_tx, done_rx) = std_mpsc::channel();
+        thread::spawn(move || done_tx.send(block_on(running)));
+        started_rx.recv().unwrap();
+        token.cancel();
+        // Still running, so the UI side is still waiting on it
+        assert!(done_rx.recv_timeout(Duration::from_millis(50)).is_err());
+
+        release_tx.send(()).unwrap();
+        assert!(matches!(
+            done_rx.recv().unwrap(),
+            Err(GrinWalletInterfaceError::WalletWorkerCancelled)
+        ));
+    }
+}
@@ -5,5 +5,7 @@ pub mod style;
 pub mod widget;
 
 pub use widget::header;
+pub use widget::qr_code;
+pub use widget::reveal;
 pub use widget::table_row;
 
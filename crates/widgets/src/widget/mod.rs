@@ -1,2 +1,4 @@
 pub mod header;
+pub mod qr_code;
+pub mod reveal;
 pub mod table_row;
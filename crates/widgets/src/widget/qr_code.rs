@@ -0,0 +1,188 @@
+use iced::Color;
+use iced_native::{
+    layout, renderer, widget::Tree, Element, Layout, Length, Point, Rectangle, Size, Widget,
+};
+use qrcode::types::QrError;
+use std::fmt::Write;
+
+/// Light modules kept around the code so scanners can find its edges
+const QUIET_ZONE: usize = 2;
+
+/// Pixels per module in exported PNGs
+const PNG_MODULE_SIZE: u32 = 8;
+
+/// Encoded QR code, kept in state so it isn't re-encoded on every draw
+#[derive(Debug, Clone)]
+pub struct QrData {
+    // Modules per side, without the quiet zone
+    width: usize,
+    // Row by row, true where the module is dark
+    modules: Vec<bool>,
+}
+
+impl QrData {
+    /// Encodes `data`, failing if it doesn't fit in a QR code
+    pub fn new(data: impl AsRef<[u8]>) -> Result<Self, QrError> {
+        let code = qrcode::QrCode::new(data)?;
+        let modules = code
+            .to_colors()
+            .into_iter()
+            .map(|c| c == qrcode::Color::Dark)
+            .collect();
+
+        Ok(QrData {
+            width: code.width(),
+            modules,
+        })
+    }
+
+    /// Whether the module at `x`, `y` counting the quiet zone is dark
+    fn is_dark(&self, x: usize, y: usize) -> bool {
+        let (x, y) = (x.wrapping_sub(QUIET_ZONE), y.wrapping_sub(QUIET_ZONE));
+        x < self.width && y < self.width && self.modules[y * self.width + x]
+    }
+
+    /// Modules per side, counting the quiet zone
+    pub fn side(&self) -> usize {
+        self.width + 2 * QUIET_ZONE
+    }
+
+    /// SVG with one unit per module, to be scaled by whatever displays it
+    pub fn to_svg(&self) -> String {
+        let side = self.side();
+        let mut svg = format!(
+            r##"<?xml version="1.0" standalone="yes"?><svg xmlns="http://www.w3.org/2000/svg" version="1.1" width="{px}" height="{px}" viewBox="0 0 {side} {side}" shape-rendering="crispEdges"><rect width="{side}" height="{side}" fill="#fff"/><path fill="#000" d=""##,
+            px = side * PNG_MODULE_SIZE as usize,
+            side = side,
+        );
+        for y in 0..side {
+            for x in 0..side {
+                if self.is_dark(x, y) {
+                    let _ = write!(svg, "M{},{}h1v1h-1z", x, y);
+                }
+            }
+        }
+        svg.push_str(r#""/></svg>"#);
+        svg
+    }
+
+    pub fn to_png(&self) -> Result<Vec<u8>, image::ImageError> {
+        let px = self.side() as u32 * PNG_MODULE_SIZE;
+        let image = image::GrayImage::from_fn(px, px, |x, y| {
+            let dark = self.is_dark(
+                (x / PNG_MODULE_SIZE) as usize,
+                (y / PNG_MODULE_SIZE) as usize,
+            );
+            image::Luma([if dark { 0 } else { 255 }])
+        });
+
+        let mut png = vec![];
+        image::DynamicImage::ImageLuma8(image).write_to(&mut png, image::ImageOutputFormat::Png)?;
+        Ok(png)
+    }
+}
+
+/// Draws a [`QrData`] as dark modules on a light square, whatever the theme
+#[allow(missing_debug_implementations)]
+pub struct QrCode<'a> {
+    data: &'a QrData,
+    cell_size: u16,
+}
+
+impl<'a> QrCode<'a> {
+    pub fn new(data: &'a QrData) -> Self {
+        QrCode { data, cell_size: 4 }
+    }
+
+    /// Sets the size in pixels of a single module
+    pub fn cell_size(mut self, cell_size: u16) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    fn side(&self) -> f32 {
+        (self.data.side() * self.cell_size as usize) as f32
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for QrCode<'a>
+where
+    Renderer: iced_native::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, _renderer: &Renderer, _limits: &layout::Limits) -> layout::Node {
+        let side = self.side();
+        layout::Node::new(Size::new(side, side))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let cell = self.cell_size as f32;
+        let quad = |bounds| iced_native::renderer::Quad {
+            bounds,
+            border_radius: 0.0_f32.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        };
+
+        renderer.fill_quad(
+            quad(Rectangle {
+                width: self.side(),
+                height: self.side(),
+                ..bounds
+            }),
+            Color::WHITE,
+        );
+
+        // One quad per horizontal run of dark modules rather than per module
+        let origin = QUIET_ZONE as f32 * cell;
+        for (y, row) in self.data.modules.chunks(self.data.width).enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                if !row[x] {
+                    x += 1;
+                    continue;
+                }
+                let start = x;
+                while x < row.len() && row[x] {
+                    x += 1;
+                }
+                renderer.fill_quad(
+                    quad(Rectangle {
+                        x: bounds.x + origin + start as f32 * cell,
+                        y: bounds.y + origin + y as f32 * cell,
+                        width: (x - start) as f32 * cell,
+                        height: cell,
+                    }),
+                    Color::BLACK,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<QrCode<'a>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + iced_native::Renderer,
+    Message: 'a,
+{
+    fn from(qr_code: QrCode<'a>) -> Element<'a, Message, Renderer> {
+        Element::new(qr_code)
+    }
+}
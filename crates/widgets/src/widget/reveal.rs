@@ -0,0 +1,107 @@
+use iced_native::{
+    layout, renderer, widget::Tree, Element, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+/// Shows its hidden content, swapping in the revealed content while the cursor is over
+/// it. Sized to fit the larger of the two so the layout doesn't jump on hover.
+#[allow(missing_debug_implementations)]
+pub struct Reveal<'a, Message, Renderer>
+where
+    Renderer: 'a + iced_native::Renderer,
+    Message: 'a,
+{
+    // Hidden content first, revealed content second
+    contents: [Element<'a, Message, Renderer>; 2],
+}
+
+impl<'a, Message, Renderer> Reveal<'a, Message, Renderer>
+where
+    Renderer: 'a + iced_native::Renderer,
+    Message: 'a,
+{
+    /// Creates a [`Reveal`] showing `hidden` until hovered, then `revealed`.
+    pub fn new<H, R>(hidden: H, revealed: R) -> Self
+    where
+        H: Into<Element<'a, Message, Renderer>>,
+        R: Into<Element<'a, Message, Renderer>>,
+    {
+        Reveal {
+            contents: [hidden.into(), revealed.into()],
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Reveal<'a, Message, Renderer>
+where
+    Renderer: 'a + iced_native::Renderer,
+    Message: 'a,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.contents.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.contents);
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let children: Vec<layout::Node> = self
+            .contents
+            .iter()
+            .map(|c| c.as_widget().layout(renderer, limits))
+            .collect();
+        let size = children.iter().fold(Size::ZERO, |size, c| {
+            Size::new(
+                size.width.max(c.size().width),
+                size.height.max(c.size().height),
+            )
+        });
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let index = if layout.bounds().contains(cursor_position) {
+            1
+        } else {
+            0
+        };
+
+        self.contents[index].as_widget().draw(
+            &tree.children[index],
+            renderer,
+            theme,
+            style,
+            layout.children().nth(index).unwrap(),
+            cursor_position,
+            viewport,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<Reveal<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + iced_native::Renderer,
+    Message: 'a,
+{
+    fn from(reveal: Reveal<'a, Message, Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(reveal)
+    }
+}
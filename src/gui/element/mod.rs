@@ -4,6 +4,7 @@ pub mod settings;
 pub mod wallet;
 pub mod node;
 pub mod modal;
+pub mod qr_code;
 
 // Default values used on multiple elements.
 pub static SMALLER_FONT_SIZE: u16 = 12;
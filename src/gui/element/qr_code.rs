@@ -0,0 +1,163 @@
+use crate::log_error;
+use native_dialog::FileDialog;
+
+use {
+    super::SMALLER_FONT_SIZE,
+    crate::gui::{GrinGui, Interaction, Message},
+    crate::localization::localized_string,
+    crate::Result,
+    anyhow::Context,
+    grin_gui_core::theme::{Button, Column, Element, Row, Text},
+    grin_gui_widgets::qr_code::{QrCode, QrData},
+    iced::{alignment, Alignment, Command, Length},
+    std::path::{Path, PathBuf},
+};
+
+/// Roughly how wide a QR code is drawn, whatever its number of modules
+const QR_CODE_SIZE: usize = 240;
+
+/// Smallest module size still scannable from a screen
+const MIN_CELL_SIZE: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Svg,
+}
+
+impl ImageFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Svg => "svg",
+        }
+    }
+
+    fn filter_name(&self) -> &'static str {
+        match self {
+            ImageFormat::Png => "PNG",
+            ImageFormat::Svg => "SVG",
+        }
+    }
+
+    fn label_key(&self) -> &'static str {
+        match self {
+            ImageFormat::Png => "export-qr-png",
+            ImageFormat::Svg => "export-qr-svg",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+    /// Contents, format and suggested file name without extension
+    Export(String, ImageFormat, String),
+    ExportFile(String, ImageFormat, PathBuf),
+}
+
+pub fn handle_message(
+    grin_gui: &mut GrinGui,
+    message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+    match message {
+        LocalViewInteraction::Export(contents, format, file_name) => match FileDialog::new()
+            .set_filename(&format!("{}.{}", file_name, format.extension()))
+            .add_filter(format.filter_name(), &[format.extension()])
+            .show_save_single_file()
+        {
+            Ok(Some(path)) => {
+                return Ok(Command::perform(async {}, move |_| {
+                    Message::Interaction(Interaction::QrCodeViewInteraction(
+                        LocalViewInteraction::ExportFile(contents, format, path),
+                    ))
+                }));
+            }
+            Ok(None) => {}
+            Err(e) => {
+                log::debug!("qr_code.rs::LocalViewInteraction::Export {}", e);
+            }
+        },
+        LocalViewInteraction::ExportFile(contents, format, path) => {
+            log::debug!("Interaction::QrCodeViewInteraction(ExportFile)");
+            grin_gui.error.take();
+            if let Err(e) =
+                write_qr_code(&contents, format, &path).context("Failed to export QR code")
+            {
+                log_error(&e);
+                grin_gui.error = Some(e);
+            }
+        }
+    }
+
+    Ok(Command::none())
+}
+
+fn write_qr_code(contents: &str, format: ImageFormat, path: &Path) -> Result<()> {
+    let data = QrData::new(contents)?;
+    let bytes = match format {
+        ImageFormat::Png => data.to_png()?,
+        ImageFormat::Svg => data.to_svg().into_bytes(),
+    };
+    std::fs::write(path, bytes)?;
+    Ok(())
+}
+
+/// QR code of `contents` as encoded in `data`, with buttons to export it. `file_name` is
+/// suggested when saving, without an extension.
+pub fn qr_code_column<'a>(
+    data: &'a QrData,
+    contents: &str,
+    file_name: &str,
+) -> Column<'a, Message> {
+    let cell_size = (QR_CODE_SIZE / data.side()).max(MIN_CELL_SIZE);
+    let qr_code: Element<Message> = QrCode::new(data).cell_size(cell_size as u16).into();
+
+    let mut export_row = Row::new().spacing(10);
+    for format in [ImageFormat::Png, ImageFormat::Svg] {
+        let export_button: Element<Interaction> = Button::new(
+            Text::new(localized_string(format.label_key()))
+                .size(SMALLER_FONT_SIZE)
+                .horizontal_alignment(alignment::Horizontal::Center),
+        )
+        .style(grin_gui_core::theme::ButtonStyle::NormalText)
+        .on_press(Interaction::QrCodeViewInteraction(
+            LocalViewInteraction::Export(contents.to_owned(), format, file_name.to_owned()),
+        ))
+        .into();
+
+        export_row = export_row.push(export_button.map(Message::Interaction));
+    }
+
+    Column::new()
+        .spacing(5)
+        .width(Length::Shrink)
+        .align_items(Alignment::Center)
+        .push(qr_code)
+        .push(export_row)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{write_qr_code, ImageFormat};
+
+    #[test]
+    fn test_write_qr_code_too_long() {
+        let path = std::env::temp_dir().join("grin-gui-qr-code-too-long.png");
+        assert!(write_qr_code(&"x".repeat(8000), ImageFormat::Png, &path).is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_write_qr_code_formats() {
+        for (format, file_name, header) in [
+            (ImageFormat::Png, "grin-gui-qr-code.png", &b"\x89PNG"[..]),
+            (ImageFormat::Svg, "grin-gui-qr-code.svg", &b"<?xml"[..]),
+        ] {
+            let path = std::env::temp_dir().join(file_name);
+            write_qr_code("grin1qqqqqqqqqqqqqqqqqqqq", format, &path).unwrap();
+            let written = std::fs::read(&path).unwrap();
+            let _ = std::fs::remove_file(&path);
+            assert!(written.starts_with(header));
+        }
+    }
+}
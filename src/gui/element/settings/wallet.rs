@@ -1,20 +1,57 @@
 use {
-    super::{DEFAULT_FONT_SIZE},
-    crate::gui::{GrinGui, Message},
+    super::super::{BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, SMALLER_FONT_SIZE},
+    crate::gui::{GrinGui, Interaction, Message},
     crate::localization::localized_string,
-    grin_gui_core::{theme::ColorPalette},
-    grin_gui_core::theme::{Button, Column, Container, PickList, Row, Scrollable, Text, TextInput},
-    iced::Length,
-    iced::widget::{
-        button, pick_list, scrollable, text_input, Checkbox, Space,
+    crate::log_error,
+    crate::Result,
+    anyhow::Context,
+    grin_gui_core::config::Config,
+    grin_gui_core::theme::{
+        Button, Column, Container, Element, Reveal, Row, Scrollable, Text, TextInput,
     },
+    grin_gui_core::wallet::ZeroingString,
+    iced::widget::Space,
+    iced::{alignment, Command, Length},
     serde::{Deserialize, Serialize},
+    std::sync::{Arc, RwLock},
+    std::time::Duration,
 };
 
-#[derive(Debug, Clone)]
+/// How long the recovery phrase stays on screen
+const RECOVERY_PHRASE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Words per row of the recovery phrase grid
+const RECOVERY_PHRASE_COLUMNS: usize = 4;
+
+/// Recovery phrase on its way to the screen. Interactions get debug logged, so this
+/// keeps the words out of the log.
+#[derive(Clone)]
+pub struct RecoveryPhrase(ZeroingString);
+
+impl std::fmt::Debug for RecoveryPhrase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RecoveryPhrase(..)")
+    }
+}
+
+#[derive(Clone)]
 pub struct StateContainer {
     pub mode: Mode,
     // scrollable_state: scrollable::State,
+    pub current_password: ZeroingString,
+    pub new_password: ZeroingString,
+    pub confirm_password: ZeroingString,
+    // Localization key of the reason the form was rejected
+    password_error: Option<&'static str>,
+    pub password_changed: bool,
+
+    pub phrase_password: ZeroingString,
+    pub recovery_phrase: Option<ZeroingString>,
+    // Bumped each time the phrase is shown, so an earlier timeout doesn't hide it early
+    recovery_phrase_shown: usize,
+
+    // Threshold as typed, kept only while it isn't a valid one
+    pub confirmations_threshold_value: Option<String>,
 }
 
 impl Default for StateContainer {
@@ -22,13 +59,35 @@ impl Default for StateContainer {
         Self {
             mode: Mode::Wallet,
             // scrollable_state: Default::default(),
+            current_password: "".into(),
+            new_password: "".into(),
+            confirm_password: "".into(),
+            password_error: None,
+            password_changed: false,
+            phrase_password: "".into(),
+            recovery_phrase: None,
+            recovery_phrase_shown: 0,
+            confirmations_threshold_value: None,
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub enum LocalViewInteraction {
     SelectMode(Mode),
+    CurrentPassword(String),
+    NewPassword(String),
+    ConfirmPassword(String),
+    ChangePassword,
+    PasswordChangedOk,
+    PasswordChangeError(Arc<RwLock<Option<anyhow::Error>>>),
+    PhrasePassword(String),
+    ShowRecoveryPhrase,
+    RecoveryPhraseOk(RecoveryPhrase),
+    /// Hides the phrase if it's still the one shown the given time
+    HideRecoveryPhrase(usize),
+    RecoveryPhraseError(Arc<RwLock<Option<anyhow::Error>>>),
+    ConfirmationsThreshold(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -41,32 +100,406 @@ pub enum Mode {
 pub fn handle_message(
     grin_gui: &mut GrinGui,
     message: LocalViewInteraction,
-) {
+) -> Result<Command<Message>> {
+    let state = &mut grin_gui.wallet_settings_state;
+
     match message {
         LocalViewInteraction::SelectMode(mode) => {
             log::debug!("Interaction::ModeSelectedSettings({:?})", mode);
             // Set Mode
-            grin_gui.wallet_settings_state.mode = mode;
+            state.mode = mode;
+        }
+        LocalViewInteraction::CurrentPassword(s) => {
+            state.current_password = s.into();
+            state.password_changed = false;
+        }
+        LocalViewInteraction::NewPassword(s) => {
+            state.new_password = s.into();
+            state.password_changed = false;
+        }
+        LocalViewInteraction::ConfirmPassword(s) => {
+            state.confirm_password = s.into();
+            state.password_changed = false;
+        }
+        LocalViewInteraction::ChangePassword => {
+            log::debug!("Interaction::WalletSettingsViewInteraction(ChangePassword)");
+            grin_gui.error.take();
+            state.password_error = None;
+            state.password_changed = false;
+
+            if !grin_gui.wallet_interface.wallet_is_open() {
+                state.password_error = Some("change-password-wallet-closed");
+                return Ok(Command::none());
+            }
+            if state.new_password.is_empty() {
+                state.password_error = Some("change-password-empty");
+                return Ok(Command::none());
+            }
+            if *state.new_password != *state.confirm_password {
+                state.password_error = Some("change-password-mismatch");
+                return Ok(Command::none());
+            }
+
+            let old_password = state.current_password.clone();
+            let new_password = state.new_password.clone();
+            let fut = grin_gui
+                .wallet_interface
+                .run(move |w| w.change_password(old_password, new_password));
+
+            return Ok(Command::perform(fut, |r| {
+                match r.context("Failed to change wallet password") {
+                    Ok(()) => Message::Interaction(Interaction::WalletSettingsViewInteraction(
+                        LocalViewInteraction::PasswordChangedOk,
+                    )),
+                    Err(e) => Message::Interaction(Interaction::WalletSettingsViewInteraction(
+                        LocalViewInteraction::PasswordChangeError(Arc::new(RwLock::new(Some(e)))),
+                    )),
+                }
+            }));
+        }
+        LocalViewInteraction::PasswordChangedOk => {
+            state.current_password = "".into();
+            state.new_password = "".into();
+            state.confirm_password = "".into();
+            state.password_changed = true;
+        }
+        LocalViewInteraction::PasswordChangeError(err) => {
+            grin_gui.error = err.write().unwrap().take();
+            if let Some(e) = grin_gui.error.as_ref() {
+                log_error(e);
+            }
+        }
+        LocalViewInteraction::PhrasePassword(s) => {
+            state.phrase_password = s.into();
+        }
+        LocalViewInteraction::ShowRecoveryPhrase => {
+            log::debug!("Interaction::WalletSettingsViewInteraction(ShowRecoveryPhrase)");
+            grin_gui.error.take();
+
+            let password = state.phrase_password.clone();
+            state.phrase_password = "".into();
+            let fut = grin_gui
+                .wallet_interface
+                .run(move |w| w.get_recovery_phrase(password));
+
+            return Ok(Command::perform(fut, |r| {
+                match r.context("Failed to retrieve recovery phrase") {
+                    Ok(phrase) => Message::Interaction(Interaction::WalletSettingsViewInteraction(
+                        LocalViewInteraction::RecoveryPhraseOk(RecoveryPhrase(phrase)),
+                    )),
+                    Err(e) => Message::Interaction(Interaction::WalletSettingsViewInteraction(
+                        LocalViewInteraction::RecoveryPhraseError(Arc::new(RwLock::new(Some(e)))),
+                    )),
+                }
+            }));
+        }
+        LocalViewInteraction::RecoveryPhraseOk(phrase) => {
+            state.recovery_phrase = Some(phrase.0);
+            state.recovery_phrase_shown += 1;
+
+            let shown = state.recovery_phrase_shown;
+            return Ok(Command::perform(
+                async_std::task::sleep(RECOVERY_PHRASE_TIMEOUT),
+                move |_| {
+                    Message::Interaction(Interaction::WalletSettingsViewInteraction(
+                        LocalViewInteraction::HideRecoveryPhrase(shown),
+                    ))
+                },
+            ));
+        }
+        LocalViewInteraction::HideRecoveryPhrase(shown) => {
+            if shown == state.recovery_phrase_shown {
+                state.recovery_phrase = None;
+            }
+        }
+        LocalViewInteraction::RecoveryPhraseError(err) => {
+            grin_gui.error = err.write().unwrap().take();
+            if let Some(e) = grin_gui.error.as_ref() {
+                log_error(e);
+            }
+        }
+        LocalViewInteraction::ConfirmationsThreshold(value) => {
+            match (
+                value.trim().parse::<u64>(),
+                grin_gui.config.current_wallet_index,
+            ) {
+                (Ok(threshold), Some(i)) if threshold > 0 => {
+                    grin_gui.config.wallets[i].confirmations_threshold = threshold;
+                    let _ = grin_gui.config.save();
+                    state.confirmations_threshold_value = None;
+                }
+                _ => state.confirmations_threshold_value = Some(value),
+            }
+        }
+    }
+
+    Ok(Command::none())
+}
+
+fn password_input<'a>(
+    label: &str,
+    value: &'a ZeroingString,
+    on_change: fn(String) -> LocalViewInteraction,
+) -> Column<'a, Message> {
+    let label = Container::new(Text::new(localized_string(label)).size(DEFAULT_FONT_SIZE))
+        .style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+    let input: Element<Interaction> = TextInput::new("", value, move |s| {
+        Interaction::WalletSettingsViewInteraction(on_change(s))
+    })
+    .size(DEFAULT_FONT_SIZE)
+    .padding(6)
+    .width(Length::Units(200))
+    .style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+    .password()
+    .into();
+
+    Column::new()
+        .push(label)
+        .push(Space::new(Length::Units(0), Length::Units(5)))
+        .push(input.map(Message::Interaction))
+}
+
+/// Words laid out in numbered cells, each masked until hovered
+fn recovery_phrase_grid<'a>(phrase: &'a ZeroingString) -> Column<'a, Message> {
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    let mut grid = Column::new().spacing(5);
+
+    for (row_index, row_words) in words.chunks(RECOVERY_PHRASE_COLUMNS).enumerate() {
+        let mut row = Row::new().spacing(5);
+        for (i, word) in row_words.iter().enumerate() {
+            let n = row_index * RECOVERY_PHRASE_COLUMNS + i + 1;
+            let hidden = Text::new(format!("{}. \u{2022}\u{2022}\u{2022}\u{2022}\u{2022}", n))
+                .size(DEFAULT_FONT_SIZE);
+            let revealed = Text::new(format!("{}. {}", n, word)).size(DEFAULT_FONT_SIZE);
+            let cell = Container::new(Reveal::new(hidden, revealed))
+                .width(Length::Units(120))
+                .padding(5)
+                .style(grin_gui_core::theme::ContainerStyle::BrightBackground);
+            row = row.push(cell);
         }
+        grid = grid.push(row);
     }
+
+    grid
 }
 
-pub fn data_container<'a>(
-    state: &'a StateContainer,
-) -> Container<'a, Message> {
-   
-    let language_container = {
-        let title = Container::new(Text::new(localized_string("todo")).size(DEFAULT_FONT_SIZE))
-            .style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+pub fn data_container<'a>(state: &'a StateContainer, config: &'a Config) -> Container<'a, Message> {
+    let change_password_container = {
+        let title =
+            Container::new(Text::new(localized_string("change-password")).size(DEFAULT_FONT_SIZE))
+                .style(grin_gui_core::theme::ContainerStyle::NormalBackground);
 
-        Column::new()
+        let mut column = Column::new()
             .push(title)
-            .push(Space::new(Length::Units(0), Length::Units(5)))
+            .push(Space::new(Length::Units(0), Length::Units(10)))
+            .push(password_input(
+                "change-password-current",
+                &state.current_password,
+                LocalViewInteraction::CurrentPassword,
+            ))
+            .push(Space::new(Length::Units(0), Length::Units(10)))
+            .push(password_input(
+                "change-password-new",
+                &state.new_password,
+                LocalViewInteraction::NewPassword,
+            ))
+            .push(Space::new(Length::Units(0), Length::Units(10)))
+            .push(password_input(
+                "change-password-confirm",
+                &state.confirm_password,
+                LocalViewInteraction::ConfirmPassword,
+            ))
+            .push(Space::new(Length::Units(0), Length::Units(10)));
+
+        if let Some(error_key) = state.password_error {
+            let error = Text::new(localized_string(error_key))
+                .size(SMALLER_FONT_SIZE)
+                .style(grin_gui_core::theme::text::TextStyle::Warning);
+            column = column
+                .push(
+                    Container::new(error)
+                        .style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+                )
+                .push(Space::new(Length::Units(0), Length::Units(10)));
+        }
+
+        if state.password_changed {
+            let changed =
+                Text::new(localized_string("change-password-success")).size(SMALLER_FONT_SIZE);
+            column = column
+                .push(
+                    Container::new(changed)
+                        .style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+                )
+                .push(Space::new(Length::Units(0), Length::Units(10)));
+        }
+
+        let submit_button_label_container = Container::new(
+            Text::new(localized_string("change-password-submit")).size(DEFAULT_FONT_SIZE),
+        )
+        .width(Length::Units(BUTTON_WIDTH))
+        .height(Length::Units(BUTTON_HEIGHT))
+        .center_x()
+        .center_y()
+        .align_x(alignment::Horizontal::Center);
+
+        let submit_button: Element<Interaction> = Button::new(submit_button_label_container)
+            .style(grin_gui_core::theme::ButtonStyle::Primary)
+            .on_press(Interaction::WalletSettingsViewInteraction(
+                LocalViewInteraction::ChangePassword,
+            ))
+            .into();
+
+        let submit_container = Container::new(submit_button.map(Message::Interaction)).padding(1);
+        let submit_container = Container::new(submit_container)
+            .style(grin_gui_core::theme::ContainerStyle::Segmented)
+            .padding(1);
+
+        column.push(submit_container)
+    };
+
+    let recovery_phrase_container = {
+        let title = Container::new(
+            Text::new(localized_string("show-recovery-phrase")).size(DEFAULT_FONT_SIZE),
+        )
+        .style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+        let column = Column::new()
+            .push(title)
+            .push(Space::new(Length::Units(0), Length::Units(10)));
+
+        match state.recovery_phrase.as_ref() {
+            Some(phrase) => {
+                let instruction = Text::new(localized_string("show-recovery-phrase-hover"))
+                    .size(SMALLER_FONT_SIZE);
+
+                let hide_button_label_container = Container::new(
+                    Text::new(localized_string("hide-recovery-phrase")).size(DEFAULT_FONT_SIZE),
+                )
+                .width(Length::Units(BUTTON_WIDTH))
+                .height(Length::Units(BUTTON_HEIGHT))
+                .center_x()
+                .center_y()
+                .align_x(alignment::Horizontal::Center);
+
+                let hide_button: Element<Interaction> = Button::new(hide_button_label_container)
+                    .style(grin_gui_core::theme::ButtonStyle::Primary)
+                    .on_press(Interaction::WalletSettingsViewInteraction(
+                        LocalViewInteraction::HideRecoveryPhrase(state.recovery_phrase_shown),
+                    ))
+                    .into();
+
+                let hide_container =
+                    Container::new(hide_button.map(Message::Interaction)).padding(1);
+                let hide_container = Container::new(hide_container)
+                    .style(grin_gui_core::theme::ContainerStyle::Segmented)
+                    .padding(1);
+
+                column
+                    .push(
+                        Container::new(instruction)
+                            .style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+                    )
+                    .push(Space::new(Length::Units(0), Length::Units(10)))
+                    .push(recovery_phrase_grid(phrase))
+                    .push(Space::new(Length::Units(0), Length::Units(10)))
+                    .push(hide_container)
+            }
+            None => {
+                let show_button_label_container = Container::new(
+                    Text::new(localized_string("show-recovery-phrase-submit"))
+                        .size(DEFAULT_FONT_SIZE),
+                )
+                .width(Length::Units(BUTTON_WIDTH))
+                .height(Length::Units(BUTTON_HEIGHT))
+                .center_x()
+                .center_y()
+                .align_x(alignment::Horizontal::Center);
+
+                let show_button: Element<Interaction> = Button::new(show_button_label_container)
+                    .style(grin_gui_core::theme::ButtonStyle::Primary)
+                    .on_press(Interaction::WalletSettingsViewInteraction(
+                        LocalViewInteraction::ShowRecoveryPhrase,
+                    ))
+                    .into();
+
+                let show_container =
+                    Container::new(show_button.map(Message::Interaction)).padding(1);
+                let show_container = Container::new(show_container)
+                    .style(grin_gui_core::theme::ContainerStyle::Segmented)
+                    .padding(1);
+
+                column
+                    .push(password_input(
+                        "show-recovery-phrase-password",
+                        &state.phrase_password,
+                        LocalViewInteraction::PhrasePassword,
+                    ))
+                    .push(Space::new(Length::Units(0), Length::Units(10)))
+                    .push(show_container)
+            }
+        }
     };
 
-    let scrollable = Scrollable::new(language_container)
-    .height(Length::Fill)
-    .style(grin_gui_core::theme::ScrollableStyle::Primary);
+    let mut settings_column = Column::new()
+        .push(change_password_container)
+        .push(Space::new(Length::Units(0), Length::Units(30)))
+        .push(recovery_phrase_container);
+
+    if let Some(wallet) = config.current_wallet_index.map(|i| &config.wallets[i]) {
+        let title = Container::new(
+            Text::new(localized_string("confirmations-threshold")).size(DEFAULT_FONT_SIZE),
+        )
+        .style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+        let help =
+            Text::new(localized_string("confirmations-threshold-help")).size(SMALLER_FONT_SIZE);
+
+        let value = state
+            .confirmations_threshold_value
+            .clone()
+            .unwrap_or_else(|| wallet.confirmations_threshold.to_string());
+        let input: Element<Interaction> = TextInput::new("", &value, |s| {
+            Interaction::WalletSettingsViewInteraction(
+                LocalViewInteraction::ConfirmationsThreshold(s),
+            )
+        })
+        .size(DEFAULT_FONT_SIZE)
+        .padding(6)
+        .width(Length::Units(100))
+        .style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+        .into();
+
+        let mut column = Column::new()
+            .push(title)
+            .push(Space::new(Length::Units(0), Length::Units(10)))
+            .push(
+                Container::new(help).style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+            )
+            .push(Space::new(Length::Units(0), Length::Units(5)))
+            .push(input.map(Message::Interaction));
+
+        if state.confirmations_threshold_value.is_some() {
+            let error = Text::new(localized_string("confirmations-threshold-error"))
+                .size(SMALLER_FONT_SIZE)
+                .style(grin_gui_core::theme::text::TextStyle::Warning);
+            column = column
+                .push(Space::new(Length::Units(0), Length::Units(5)))
+                .push(
+                    Container::new(error)
+                        .style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+                );
+        }
+
+        settings_column = settings_column
+            .push(Space::new(Length::Units(0), Length::Units(30)))
+            .push(column);
+    }
+
+    let scrollable = Scrollable::new(settings_column)
+        .height(Length::Fill)
+        .style(grin_gui_core::theme::ScrollableStyle::Primary);
 
     let col = Column::new()
         .push(Space::new(Length::Units(0), Length::Units(10)))
@@ -82,3 +515,141 @@ pub fn data_container<'a>(
         .height(Length::Shrink)
         .style(grin_gui_core::theme::ContainerStyle::NormalBackground)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gui::test::{mock_gui, open_wallet, run, TestDir};
+    use crate::gui::update::handle_message;
+    use futures::executor::block_on;
+    use grin_gui_core::node::ChainTypes::Testnet;
+    use grin_gui_core::wallet::MockWallet;
+    use iced_native::command::Action;
+    use std::path::PathBuf;
+
+    fn settings_message(interaction: LocalViewInteraction) -> Message {
+        Message::Interaction(Interaction::WalletSettingsViewInteraction(interaction))
+    }
+
+    #[test]
+    fn test_change_password() {
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, MockWallet::new("password"));
+        open_wallet(&mut grin_gui, "password");
+
+        for interaction in [
+            LocalViewInteraction::CurrentPassword("password".to_owned()),
+            LocalViewInteraction::NewPassword("new password".to_owned()),
+            LocalViewInteraction::ConfirmPassword("new passwrod".to_owned()),
+            LocalViewInteraction::ChangePassword,
+        ] {
+            run(&mut grin_gui, settings_message(interaction));
+        }
+        assert!(!grin_gui.wallet_settings_state.password_changed);
+
+        for interaction in [
+            LocalViewInteraction::ConfirmPassword("new password".to_owned()),
+            LocalViewInteraction::ChangePassword,
+        ] {
+            run(&mut grin_gui, settings_message(interaction));
+        }
+        assert!(grin_gui.error.is_none());
+        assert!(grin_gui.wallet_settings_state.password_changed);
+        assert!(grin_gui.wallet_settings_state.current_password.is_empty());
+
+        block_on(grin_gui.wallet_interface.run(|w| w.close_wallet())).unwrap();
+        let res = block_on(
+            grin_gui
+                .wallet_interface
+                .run(|w| w.open_wallet("password".to_owned(), PathBuf::new(), Testnet)),
+        );
+        assert!(res.is_err());
+        open_wallet(&mut grin_gui, "new password");
+        assert!(grin_gui.wallet_interface.wallet_is_open());
+    }
+
+    #[test]
+    fn test_show_recovery_phrase() {
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, MockWallet::new("password"));
+        open_wallet(&mut grin_gui, "password");
+
+        run(
+            &mut grin_gui,
+            settings_message(LocalViewInteraction::PhrasePassword("wrong".to_owned())),
+        );
+        run(
+            &mut grin_gui,
+            settings_message(LocalViewInteraction::ShowRecoveryPhrase),
+        );
+        assert!(grin_gui.error.is_some());
+        assert!(grin_gui.wallet_settings_state.recovery_phrase.is_none());
+
+        // Step through by hand, `run` would sit out the hide timeout
+        run(
+            &mut grin_gui,
+            settings_message(LocalViewInteraction::PhrasePassword("password".to_owned())),
+        );
+        let command = handle_message(
+            &mut grin_gui,
+            settings_message(LocalViewInteraction::ShowRecoveryPhrase),
+        )
+        .unwrap();
+        for action in command.actions() {
+            if let Action::Future(f) = action {
+                let _ = handle_message(&mut grin_gui, block_on(f)).unwrap();
+            }
+        }
+        assert!(grin_gui.error.is_none());
+        assert!(grin_gui.wallet_settings_state.phrase_password.is_empty());
+        let phrase = grin_gui
+            .wallet_settings_state
+            .recovery_phrase
+            .as_ref()
+            .unwrap();
+        assert_eq!(phrase.split_whitespace().count(), 24);
+
+        // A stale timeout leaves the phrase alone, the current one hides it
+        run(
+            &mut grin_gui,
+            settings_message(LocalViewInteraction::HideRecoveryPhrase(0)),
+        );
+        assert!(grin_gui.wallet_settings_state.recovery_phrase.is_some());
+        run(
+            &mut grin_gui,
+            settings_message(LocalViewInteraction::HideRecoveryPhrase(1)),
+        );
+        assert!(grin_gui.wallet_settings_state.recovery_phrase.is_none());
+    }
+
+    #[test]
+    fn test_confirmations_threshold() {
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, MockWallet::new("password"));
+        let index = grin_gui.config.current_wallet_index.unwrap();
+
+        // Threshold is kept per wallet, and only once it's a valid one
+        for value in ["0", "three"] {
+            run(
+                &mut grin_gui,
+                settings_message(LocalViewInteraction::ConfirmationsThreshold(
+                    value.to_owned(),
+                )),
+            );
+            assert_eq!(
+                grin_gui.wallet_settings_state.confirmations_threshold_value,
+                Some(value.to_owned())
+            );
+            assert_eq!(grin_gui.config.wallets[index].confirmations_threshold, 10);
+        }
+        run(
+            &mut grin_gui,
+            settings_message(LocalViewInteraction::ConfirmationsThreshold("3".to_owned())),
+        );
+        assert!(grin_gui
+            .wallet_settings_state
+            .confirmations_threshold_value
+            .is_none());
+        assert_eq!(grin_gui.config.wallets[index].confirmations_threshold, 3);
+    }
+}
@@ -3,7 +3,7 @@ pub mod setup;
 
 use {
     crate::gui::{Message},
-    grin_gui_core::config::Config,
+    grin_gui_core::config::{AddressBook, Config, SentSlatepacks, TxNotes},
     grin_gui_core::theme::ColorPalette,
     grin_gui_core::theme::{Container, Column},
     iced::Length,
@@ -54,12 +54,19 @@ impl StateContainer {
 pub fn data_container<'a>(
     state: &'a StateContainer,
     config: &'a Config,
+    address_book: &'a AddressBook,
+    tx_notes: &'a TxNotes,
+    sent_slatepacks: &'a SentSlatepacks,
 ) -> Container<'a, Message> {
     let content = match &state.mode {
         Mode::Init => setup::data_container(&state.setup_state, config),
-        Mode::Operation => {
-            operation::data_container(&state.operation_state, config)
-        }
+        Mode::Operation => operation::data_container(
+            &state.operation_state,
+            config,
+            address_book,
+            tx_notes,
+            sent_slatepacks,
+        ),
         Mode::CreateWallet(default_display_name) => {
             setup::wallet_setup::data_container(&state.setup_state.setup_wallet_state, default_display_name)
         }
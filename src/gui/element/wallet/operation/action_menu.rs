@@ -20,7 +20,7 @@ use {
     crate::localization::localized_string,
     crate::Result,
     anyhow::Context,
-    grin_gui_core::wallet::{StatusMessage, WalletInfo, WalletInterface},
+    grin_gui_core::wallet::{StatusMessage, WalletInfo},
     grin_gui_core::{node::amount_to_hr_string, theme::ColorPalette},
     grin_gui_core::theme::{Container, Button, Element, Column, PickList, Row, Scrollable, Text, TextInput, Header, TableRow},
     iced::{Alignment, Command, Length},
@@ -49,6 +49,10 @@ impl Default for StateContainer {
 pub enum Action {
     CreateTx,
     ApplyTx,
+    ShowOutputs,
+    CreateInvoice,
+    VerifyProof,
+    ScanWallet,
 }
 
 #[derive(Debug, Clone)]
@@ -73,6 +77,17 @@ pub fn handle_message<'a>(
             );
             match action {
                 Action::CreateTx => {
+                    // Each send starts from the wallet's default parameters
+                    let defaults = grin_gui
+                        .config
+                        .current_wallet_index
+                        .map(|i| grin_gui.config.wallets[i].send_defaults.clone())
+                        .unwrap_or_default();
+                    grin_gui
+                        .wallet_state
+                        .operation_state
+                        .create_tx_state
+                        .load_send_defaults(&defaults);
                     grin_gui.wallet_state.operation_state.mode =
                         crate::gui::element::wallet::operation::Mode::CreateTx
                 }
@@ -80,6 +95,28 @@ pub fn handle_message<'a>(
                     grin_gui.wallet_state.operation_state.mode =
                         crate::gui::element::wallet::operation::Mode::ApplyTx
                 }
+                Action::CreateInvoice => {
+                    grin_gui.wallet_state.operation_state.mode =
+                        crate::gui::element::wallet::operation::Mode::CreateInvoice
+                }
+                Action::VerifyProof => {
+                    grin_gui.wallet_state.operation_state.mode =
+                        crate::gui::element::wallet::operation::Mode::VerifyProof
+                }
+                Action::ScanWallet => {
+                    grin_gui.wallet_state.operation_state.mode =
+                        crate::gui::element::wallet::operation::Mode::Scan
+                }
+                Action::ShowOutputs => {
+                    grin_gui.wallet_state.operation_state.mode =
+                        crate::gui::element::wallet::operation::Mode::Outputs;
+                    let fut = async {};
+                    return Ok(Command::perform(fut, |_| {
+                        Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+                            super::outputs::LocalViewInteraction::Refresh,
+                        ))
+                    }));
+                }
             }
         }
     }
@@ -135,10 +172,97 @@ pub fn data_container<'a>(
         .style(grin_gui_core::theme::ContainerStyle::Segmented)
         .padding(1);
 
+    let outputs_container =
+        Container::new(Text::new(localized_string("outputs")).size(DEFAULT_FONT_SIZE))
+            .width(button_width)
+            .align_y(alignment::Vertical::Center)
+            .align_x(alignment::Horizontal::Center);
+
+    let outputs_button: Element<Interaction> =
+        Button::new(outputs_container)
+            .width(button_width)
+            .style(grin_gui_core::theme::ButtonStyle::Primary)
+            .on_press(Interaction::WalletOperationHomeActionMenuViewInteraction(
+                LocalViewInteraction::SelectAction(Action::ShowOutputs),
+            ))
+            .into();
+
+    let outputs_container = Container::new(outputs_button.map(Message::Interaction)).padding(1);
+    let outputs_container = Container::new(outputs_container)
+        .style(grin_gui_core::theme::ContainerStyle::Segmented)
+        .padding(1);
+
+    let invoice_container = Container::new(
+        Text::new(localized_string("wallet-create-invoice")).size(DEFAULT_FONT_SIZE),
+    )
+    .width(button_width)
+    .align_y(alignment::Vertical::Center)
+    .align_x(alignment::Horizontal::Center);
+
+    let invoice_button: Element<Interaction> = Button::new(invoice_container)
+        .width(button_width)
+        .style(grin_gui_core::theme::ButtonStyle::Primary)
+        .on_press(Interaction::WalletOperationHomeActionMenuViewInteraction(
+            LocalViewInteraction::SelectAction(Action::CreateInvoice),
+        ))
+        .into();
+
+    let invoice_container = Container::new(invoice_button.map(Message::Interaction)).padding(1);
+    let invoice_container = Container::new(invoice_container)
+        .style(grin_gui_core::theme::ContainerStyle::Segmented)
+        .padding(1);
+
+    let verify_proof_container =
+        Container::new(Text::new(localized_string("verify-proof")).size(DEFAULT_FONT_SIZE))
+            .width(button_width)
+            .align_y(alignment::Vertical::Center)
+            .align_x(alignment::Horizontal::Center);
+
+    let verify_proof_button: Element<Interaction> = Button::new(verify_proof_container)
+        .width(button_width)
+        .style(grin_gui_core::theme::ButtonStyle::Primary)
+        .on_press(Interaction::WalletOperationHomeActionMenuViewInteraction(
+            LocalViewInteraction::SelectAction(Action::VerifyProof),
+        ))
+        .into();
+
+    let verify_proof_container =
+        Container::new(verify_proof_button.map(Message::Interaction)).padding(1);
+    let verify_proof_container = Container::new(verify_proof_container)
+        .style(grin_gui_core::theme::ContainerStyle::Segmented)
+        .padding(1);
+
+    let scan_container =
+        Container::new(Text::new(localized_string("wallet-scan")).size(DEFAULT_FONT_SIZE))
+            .width(button_width)
+            .align_y(alignment::Vertical::Center)
+            .align_x(alignment::Horizontal::Center);
+
+    let scan_button: Element<Interaction> = Button::new(scan_container)
+        .width(button_width)
+        .style(grin_gui_core::theme::ButtonStyle::Primary)
+        .on_press(Interaction::WalletOperationHomeActionMenuViewInteraction(
+            LocalViewInteraction::SelectAction(Action::ScanWallet),
+        ))
+        .into();
+
+    let scan_container = Container::new(scan_button.map(Message::Interaction)).padding(1);
+    let scan_container = Container::new(scan_container)
+        .style(grin_gui_core::theme::ContainerStyle::Segmented)
+        .padding(1);
+
     let menu_column = Row::new()
         .push(create_container)
         .push(Space::with_width(Length::Units(DEFAULT_PADDING)))
-        .push(apply_container);
+        .push(invoice_container)
+        .push(Space::with_width(Length::Units(DEFAULT_PADDING)))
+        .push(apply_container)
+        .push(Space::with_width(Length::Units(DEFAULT_PADDING)))
+        .push(outputs_container)
+        .push(Space::with_width(Length::Units(DEFAULT_PADDING)))
+        .push(verify_proof_container)
+        .push(Space::with_width(Length::Units(DEFAULT_PADDING)))
+        .push(scan_container);
 
     Container::new(menu_column)
 }
@@ -2,12 +2,17 @@ use super::tx_list::{self, ExpandType};
 use crate::log_error;
 use async_std::prelude::FutureExt;
 use grin_gui_core::{
-    config::Config,
-    wallet::{TxLogEntry, TxLogEntryType},
+    config::{AddressBook, Config},
+    error::GrinWalletInterfaceError,
+    wallet::{
+        read_slatepack_file, SlatepackInspection, SlatepackProblem, TxLogEntry, TxLogEntryType,
+        WalletBackend,
+    },
 };
 use grin_gui_widgets::widget::header;
 use iced_aw::Card;
 use iced_native::Widget;
+use native_dialog::FileDialog;
 use std::path::PathBuf;
 
 use super::tx_list::{HeaderState, TxList};
@@ -25,7 +30,7 @@ use {
         Button, Column, Container, Element, Header, PickList, Row, Scrollable, TableRow, Text,
         TextInput,
     },
-    grin_gui_core::wallet::{StatusMessage, WalletInfo, WalletInterface},
+    grin_gui_core::wallet::{Slate, Slatepack, StatusMessage, WalletInfo},
     grin_gui_core::{node::amount_to_hr_string, theme::ColorPalette},
     iced::widget::{button, pick_list, scrollable, text_input, Checkbox, Space},
     iced::{alignment, Alignment, Command, Length},
@@ -40,6 +45,12 @@ pub struct StateContainer {
     pub address_value: String,
     // Slatepack read result
     pub slatepack_read_result: String,
+    // slatepack pasted into the form, decoded as it's typed
+    pub slatepack_value: String,
+    // what the pasted or read slatepack holds
+    pub inspection: Option<SlatepackInspection>,
+    // why the pasted or read slatepack can't be decoded
+    pub inspection_error: Option<String>,
 }
 
 impl Default for StateContainer {
@@ -50,6 +61,9 @@ impl Default for StateContainer {
             // address_state: Default::default(),
             address_value: Default::default(),
             slatepack_read_result: localized_string("tx-slatepack-read-result-default"),
+            slatepack_value: Default::default(),
+            inspection: None,
+            inspection_error: None,
         }
     }
 }
@@ -64,6 +78,17 @@ pub enum LocalViewInteraction {
     ApplyTransaction(String),
     ReadFromClipboardSuccess(String),
     ReadFromClipboardFailure,
+    OpenSlatepackFile,
+    ReadSlatepackFile(PathBuf),
+    SlatepackDecodeSuccess(Box<(Slatepack, Slate)>),
+    Slatepack(String),
+    Continue,
+    /// Decoded and checked. Carries the text for slatepacks typed into the form, `None`
+    /// for ones read from the clipboard or a file.
+    SlatepackInspected(Option<String>, Box<SlatepackInspection>),
+    /// Couldn't be decoded, with whether that's because it's encrypted for another
+    /// address
+    SlatepackInspectFailure(Option<String>, bool),
 }
 
 pub fn handle_message<'a>(
@@ -79,20 +104,91 @@ pub fn handle_message<'a>(
         }
         LocalViewInteraction::ReadFromClipboardSuccess(value) => {
             debug!("Read from clipboard: {}", value);
-            let w = grin_gui.wallet_interface.clone();
-            let decode_res = WalletInterface::decrypt_slatepack(w, value);
-            match decode_res {
-                Err(e) => {
-                    state.slatepack_read_result = localized_string("tx-slatepack-read-failure")
-                }
-                Ok(s) => {
-                    debug!("{}", s.0);
-                    grin_gui.wallet_state.operation_state.apply_tx_confirm_state.slatepack_parsed = Some(s);
-                    grin_gui.wallet_state.operation_state.mode =
-                        crate::gui::element::wallet::operation::Mode::ApplyTxConfirm;
+            let fut = grin_gui
+                .wallet_interface
+                .run(move |w| inspect_slatepack(w, value));
+            return Ok(Command::perform(fut, slatepack_inspected(None)));
+        }
+        LocalViewInteraction::OpenSlatepackFile => match FileDialog::new()
+            .add_filter("Slatepack", &["slatepack"])
+            .show_open_single_file()
+        {
+            Ok(Some(path)) => {
+                return Ok(Command::perform(async {}, move |_| {
+                    Message::Interaction(Interaction::WalletOperationApplyTxViewInteraction(
+                        LocalViewInteraction::ReadSlatepackFile(path),
+                    ))
+                }));
+            }
+            Ok(None) => {}
+            Err(e) => {
+                log::debug!("apply_tx.rs::LocalViewInteraction::OpenSlatepackFile {}", e);
+            }
+        },
+        LocalViewInteraction::ReadSlatepackFile(path) => {
+            debug!("Read slatepack file: {}", path.display());
+            let fut = grin_gui.wallet_interface.run(move |w| {
+                let slatepack = read_slatepack_file(&path)?;
+                inspect_slatepack(w, slatepack)
+            });
+            return Ok(Command::perform(fut, slatepack_inspected(None)));
+        }
+        LocalViewInteraction::SlatepackDecodeSuccess(s) => {
+            debug!("{}", s.0);
+            confirm(grin_gui, *s);
+        }
+        LocalViewInteraction::Slatepack(value) => {
+            state.slatepack_value = value.clone();
+            state.inspection = None;
+            state.inspection_error = None;
+            // Only decode once the whole armored message is in
+            if value.contains("BEGINSLATEPACK") && value.contains("ENDSLATEPACK") {
+                let pasted = Some(value.clone());
+                let fut = grin_gui
+                    .wallet_interface
+                    .run(move |w| inspect_slatepack(w, value));
+                return Ok(Command::perform(fut, slatepack_inspected(pasted)));
+            }
+        }
+        LocalViewInteraction::Continue => {
+            if let Some(inspection) = state.inspection.as_ref() {
+                if inspection.problems.is_empty() {
+                    let parsed = (inspection.slatepack.clone(), inspection.slate.clone());
+                    confirm(grin_gui, parsed);
                 }
             }
         }
+        LocalViewInteraction::SlatepackInspected(pasted, inspection) => match pasted {
+            // The form has moved on since this was sent off
+            Some(value) if value != state.slatepack_value => {}
+            Some(_) => state.inspection = Some(*inspection),
+            None if inspection.problems.is_empty() => {
+                confirm(grin_gui, (inspection.slatepack, inspection.slate))
+            }
+            None => {
+                state.inspection_error = None;
+                state.inspection = Some(*inspection);
+            }
+        },
+        LocalViewInteraction::SlatepackInspectFailure(pasted, other_recipient) => {
+            if pasted
+                .as_ref()
+                .map_or(false, |value| *value != state.slatepack_value)
+            {
+                return Ok(Command::none());
+            }
+            state.inspection = None;
+            state.inspection_error = if other_recipient {
+                Some(localized_string("tx-inspect-other-recipient"))
+            } else if pasted.is_some() {
+                Some(localized_string("tx-inspect-undecodable"))
+            } else {
+                None
+            };
+            if pasted.is_none() {
+                state.slatepack_read_result = localized_string("tx-slatepack-read-failure");
+            }
+        }
         LocalViewInteraction::ReadFromClipboardFailure => {
             error!("Failed to read from clipboard");
         }
@@ -102,7 +198,133 @@ pub fn handle_message<'a>(
     Ok(Command::none())
 }
 
-pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+/// Moves on to accepting a decoded slatepack
+fn confirm(grin_gui: &mut GrinGui, parsed: (Slatepack, Slate)) {
+    let confirm_state = &mut grin_gui.wallet_state.operation_state.apply_tx_confirm_state;
+    confirm_state.slatepack_parsed = Some(parsed);
+    confirm_state.contact_name_value.clear();
+    confirm_state.contact_notes_value.clear();
+    grin_gui.wallet_state.operation_state.mode =
+        crate::gui::element::wallet::operation::Mode::ApplyTxConfirm;
+}
+
+/// Decodes a slatepack and checks it against the wallet's address and transactions
+fn inspect_slatepack(
+    w: &mut dyn WalletBackend,
+    slatepack: String,
+) -> std::result::Result<SlatepackInspection, GrinWalletInterfaceError> {
+    let (slatepack, slate) = w.decrypt_slatepack(slatepack)?;
+    let address = w.get_slatepack_address()?;
+    let (_, txs) = w.get_txs(None)?;
+    Ok(SlatepackInspection::new(slatepack, slate, &address, &txs))
+}
+
+fn slatepack_inspected(
+    pasted: Option<String>,
+) -> impl FnOnce(std::result::Result<SlatepackInspection, GrinWalletInterfaceError>) -> Message {
+    move |inspect_res| {
+        Message::Interaction(Interaction::WalletOperationApplyTxViewInteraction(
+            match inspect_res {
+                Ok(i) => LocalViewInteraction::SlatepackInspected(pasted, Box::new(i)),
+                Err(e) => {
+                    debug!("Failed to decode slatepack: {}", e);
+                    LocalViewInteraction::SlatepackInspectFailure(
+                        pasted,
+                        e.is_slatepack_decryption(),
+                    )
+                }
+            },
+        ))
+    }
+}
+
+/// What the slatepack holds, and anything that stops it being applied
+fn inspection_container<'a>(
+    inspection: &SlatepackInspection,
+    address_book: &AddressBook,
+) -> Element<'a, Message> {
+    let slate = &inspection.slate;
+    let none = || localized_string("none");
+
+    let sender = match inspection.slatepack.sender.as_ref().map(|s| s.to_string()) {
+        None => none(),
+        Some(address) => match address_book.find_by_address(&address) {
+            Some(contact) => format!("{} ({})", contact.name, address),
+            None => address,
+        },
+    };
+    let fee = match inspection.fee() {
+        0 => none(),
+        fee => amount_to_hr_string(fee, false),
+    };
+    let for_this_wallet = match inspection.is_for_this_wallet() {
+        Some(true) => localized_string("yes"),
+        Some(false) => localized_string("no"),
+        None => localized_string("tx-inspect-unencrypted"),
+    };
+
+    let rows = vec![
+        (
+            localized_string("tx-inspect-state"),
+            slate.state.to_string(),
+        ),
+        (
+            localized_string("tx-inspect-amount"),
+            amount_to_hr_string(slate.amount, false),
+        ),
+        (localized_string("tx-inspect-fee"), fee),
+        (localized_string("tx-inspect-sender"), sender),
+        (
+            localized_string("tx-ttl-cutoff"),
+            inspection
+                .ttl_cutoff_height()
+                .map(|h| h.to_string())
+                .unwrap_or_else(none),
+        ),
+        (
+            localized_string("tx-inspect-payment-proof"),
+            inspection.payment_proof_recipient().unwrap_or_else(none),
+        ),
+        (
+            localized_string("tx-inspect-for-this-wallet"),
+            for_this_wallet,
+        ),
+    ];
+
+    let mut column = Column::new().spacing(5);
+    for (label, value) in rows {
+        let row = Row::new()
+            .push(
+                Text::new(label)
+                    .size(SMALLER_FONT_SIZE)
+                    .width(Length::Units(200)),
+            )
+            .push(Text::new(value).size(SMALLER_FONT_SIZE));
+        column = column.push(row);
+    }
+    for problem in inspection.problems.iter() {
+        let key = match problem {
+            SlatepackProblem::WrongChain => "tx-inspect-wrong-chain",
+            SlatepackProblem::AlreadyFinalized => "tx-inspect-already-finalized",
+            SlatepackProblem::AlreadyApplied => "tx-inspect-already-applied",
+        };
+        column = column.push(
+            Text::new(localized_string(key))
+                .size(DEFAULT_FONT_SIZE)
+                .style(grin_gui_core::theme::text::TextStyle::Warning),
+        );
+    }
+
+    Container::new(column)
+        .style(grin_gui_core::theme::ContainerStyle::NormalBackground)
+        .into()
+}
+
+pub fn data_container<'a>(
+    config: &'a Config,
+    state: &'a StateContainer,
+    address_book: &'a AddressBook,
+) -> Container<'a, Message> {
     let unit_spacing = 15;
 
     // Title row
@@ -198,10 +420,38 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
         //.push(paste_slatepack_button.map(Message::Interaction))
         .spacing(DEFAULT_PADDING);
 
-    let slatepack_area = Column::new()
+    let slatepack_input: Element<Interaction> = TextInput::new(
+        "BEGINSLATEPACK. ... ENDSLATEPACK.",
+        &state.slatepack_value,
+        |s| Interaction::WalletOperationApplyTxViewInteraction(LocalViewInteraction::Slatepack(s)),
+    )
+    .size(DEFAULT_FONT_SIZE)
+    .padding(6)
+    .width(Length::Units(400))
+    .style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+    .into();
+
+    let mut slatepack_area = Column::new()
         .push(slatepack_paste_name_container)
         .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
-        .push(paste_slatepack_row);
+        .push(paste_slatepack_row)
+        .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
+        .push(slatepack_input.map(Message::Interaction));
+
+    if let Some(error) = state.inspection_error.as_ref() {
+        slatepack_area = slatepack_area
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
+            .push(
+                Text::new(error.clone())
+                    .size(DEFAULT_FONT_SIZE)
+                    .style(grin_gui_core::theme::text::TextStyle::Warning),
+            );
+    }
+    if let Some(inspection) = state.inspection.as_ref() {
+        slatepack_area = slatepack_area
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
+            .push(inspection_container(inspection, address_book));
+    }
 
     let slatepack_area_container = Container::new(slatepack_area);
 
@@ -216,9 +466,21 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
             .center_y()
             .align_x(alignment::Horizontal::Center);
 
+    // With nothing pasted, Continue reads the clipboard. A pasted slatepack can only be
+    // taken on once it's decoded without problems.
     let mut submit_button = Button::new(submit_button_label_container)
-        .style(grin_gui_core::theme::ButtonStyle::Primary)
-        .on_press(Interaction::ReadSlatepackFromClipboard);
+        .style(grin_gui_core::theme::ButtonStyle::Primary);
+    if state.slatepack_value.trim().is_empty() {
+        submit_button = submit_button.on_press(Interaction::ReadSlatepackFromClipboard);
+    } else if state
+        .inspection
+        .as_ref()
+        .map_or(false, |i| i.problems.is_empty())
+    {
+        submit_button = submit_button.on_press(Interaction::WalletOperationApplyTxViewInteraction(
+            LocalViewInteraction::Continue,
+        ));
+    }
     /*let submit_button = submit_button.on_press(Interaction::WalletOperationApplyTxViewInteraction(
         LocalViewInteraction::ApplyTransaction("_".into()),
     ));*/
@@ -250,9 +512,31 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
         .style(grin_gui_core::theme::ContainerStyle::Segmented)
         .padding(1);
 
+    let open_file_button_label_container =
+        Container::new(Text::new(localized_string("open-slatepack-file")).size(DEFAULT_FONT_SIZE))
+            .height(button_height)
+            .center_x()
+            .center_y()
+            .padding(iced::Padding::from([0, 10]))
+            .align_x(alignment::Horizontal::Center);
+
+    let open_file_button: Element<Interaction> = Button::new(open_file_button_label_container)
+        .style(grin_gui_core::theme::ButtonStyle::Primary)
+        .on_press(Interaction::WalletOperationApplyTxViewInteraction(
+            LocalViewInteraction::OpenSlatepackFile,
+        ))
+        .into();
+
+    let open_file_container = Container::new(open_file_button.map(Message::Interaction)).padding(1);
+    let open_file_container = Container::new(open_file_container)
+        .style(grin_gui_core::theme::ContainerStyle::Segmented)
+        .padding(1);
+
     let button_row = Row::new()
         .push(submit_container)
         .push(Space::new(Length::Units(unit_spacing), Length::Units(0)))
+        .push(open_file_container)
+        .push(Space::new(Length::Units(unit_spacing), Length::Units(0)))
         .push(cancel_container);
 
     let column = Column::new()
@@ -303,3 +587,153 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
         DEFAULT_PADDING, // left
     ]))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gui::element::wallet::operation;
+    use crate::gui::test::{mock_gui, open_wallet, run, TestDir};
+    use grin_gui_core::wallet::{
+        mock::MOCK_SLATEPACK_ADDRESS, MockWallet, SlateState, SlatepackAddress,
+    };
+
+    const FRESH: &str = "BEGINSLATEPACK. fresh ENDSLATEPACK.";
+    const FOREIGN: &str = "BEGINSLATEPACK. foreign ENDSLATEPACK.";
+    const DONE: &str = "BEGINSLATEPACK. done ENDSLATEPACK.";
+
+    fn mock() -> MockWallet {
+        let mut mock = MockWallet::new("password");
+        let sender = Slatepack {
+            sender: Some(SlatepackAddress::try_from(MOCK_SLATEPACK_ADDRESS).unwrap()),
+            ..Default::default()
+        };
+        let mut fresh = Slate::blank(2, false);
+        fresh.amount = 1_000_000_000;
+        let mut done = fresh.clone();
+        done.state = SlateState::Standard3;
+        mock.slatepacks
+            .insert(FRESH.to_owned(), (sender.clone(), fresh));
+        mock.slatepacks.insert(DONE.to_owned(), (sender, done));
+        mock.foreign_slatepacks.push(FOREIGN.to_owned());
+        mock
+    }
+
+    fn apply(grin_gui: &mut GrinGui, interaction: LocalViewInteraction) {
+        run(
+            grin_gui,
+            Message::Interaction(Interaction::WalletOperationApplyTxViewInteraction(
+                interaction,
+            )),
+        );
+    }
+
+    #[test]
+    fn test_live_slatepack_inspection() {
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, mock());
+        open_wallet(&mut grin_gui, "password");
+        grin_gui.wallet_state.operation_state.mode = operation::Mode::ApplyTx;
+
+        // Nothing is decoded until the whole message is in
+        apply(
+            &mut grin_gui,
+            LocalViewInteraction::Slatepack(FRESH[..20].to_owned()),
+        );
+        let state = &grin_gui.wallet_state.operation_state.apply_tx_state;
+        assert!(state.inspection.is_none());
+        assert!(state.inspection_error.is_none());
+
+        apply(
+            &mut grin_gui,
+            LocalViewInteraction::Slatepack(FOREIGN.to_owned()),
+        );
+        let state = &grin_gui.wallet_state.operation_state.apply_tx_state;
+        assert!(state.inspection.is_none());
+        assert_eq!(
+            state.inspection_error,
+            Some(localized_string("tx-inspect-other-recipient"))
+        );
+
+        // Problems are shown, and keep the slatepack from being accepted, however it's read
+        apply(
+            &mut grin_gui,
+            LocalViewInteraction::Slatepack(DONE.to_owned()),
+        );
+        apply(&mut grin_gui, LocalViewInteraction::Continue);
+        let state = &grin_gui.wallet_state.operation_state.apply_tx_state;
+        assert!(state.inspection_error.is_none());
+        assert_eq!(
+            state.inspection.as_ref().unwrap().problems,
+            vec![SlatepackProblem::AlreadyFinalized]
+        );
+        apply(
+            &mut grin_gui,
+            LocalViewInteraction::ReadFromClipboardSuccess(DONE.to_owned()),
+        );
+        assert_eq!(
+            grin_gui.wallet_state.operation_state.mode,
+            operation::Mode::ApplyTx
+        );
+
+        apply(
+            &mut grin_gui,
+            LocalViewInteraction::Slatepack(FRESH.to_owned()),
+        );
+        let inspection = grin_gui
+            .wallet_state
+            .operation_state
+            .apply_tx_state
+            .inspection
+            .clone()
+            .unwrap();
+        assert!(inspection.problems.is_empty());
+        assert_eq!(inspection.slate.amount, 1_000_000_000);
+        assert_eq!(inspection.is_for_this_wallet(), None);
+
+        apply(&mut grin_gui, LocalViewInteraction::Continue);
+        assert_eq!(
+            grin_gui.wallet_state.operation_state.mode,
+            operation::Mode::ApplyTxConfirm
+        );
+        let (_, slate) = grin_gui
+            .wallet_state
+            .operation_state
+            .apply_tx_confirm_state
+            .slatepack_parsed
+            .as_ref()
+            .unwrap();
+        assert_eq!(slate.id, inspection.slate.id);
+    }
+
+    #[test]
+    fn test_read_slatepack_file() {
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, mock());
+        open_wallet(&mut grin_gui, "password");
+        grin_gui.wallet_state.operation_state.mode = operation::Mode::ApplyTx;
+
+        let path = dir.join("garbage.slatepack");
+        std::fs::write(&path, [0u8, 1, 2, 3]).unwrap();
+        apply(&mut grin_gui, LocalViewInteraction::ReadSlatepackFile(path));
+        assert_eq!(
+            grin_gui.wallet_state.operation_state.mode,
+            operation::Mode::ApplyTx
+        );
+        assert_eq!(
+            grin_gui
+                .wallet_state
+                .operation_state
+                .apply_tx_state
+                .slatepack_read_result,
+            localized_string("tx-slatepack-read-failure")
+        );
+
+        let path = dir.join("fresh.S1.slatepack");
+        std::fs::write(&path, format!("\n{}\n", FRESH)).unwrap();
+        apply(&mut grin_gui, LocalViewInteraction::ReadSlatepackFile(path));
+        assert_eq!(
+            grin_gui.wallet_state.operation_state.mode,
+            operation::Mode::ApplyTxConfirm
+        );
+    }
+}
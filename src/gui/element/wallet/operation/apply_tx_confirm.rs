@@ -1,15 +1,18 @@
+use super::create_tx;
 use super::tx_list::{self, ExpandType};
 use crate::log_error;
 use async_std::prelude::FutureExt;
 use grin_gui_core::{
-    config::Config,
-    wallet::{Slate, SlateState, Slatepack, TxLogEntry, TxLogEntryType},
+    config::{AddressBook, Config, Contact},
+    fs::PersistentData,
+    wallet::{slatepack_file_name, Slate, SlateState, Slatepack, TxLogEntry, TxLogEntryType},
 };
-use grin_gui_widgets::widget::header;
+use grin_gui_widgets::{qr_code::QrData, widget::header};
 use iced_aw::Card;
 use iced_native::Widget;
 use std::path::PathBuf;
 
+use super::invoice_success::InvoiceStep;
 use super::tx_list::{HeaderState, TxList};
 
 use {
@@ -25,7 +28,7 @@ use {
         Button, Column, Container, Element, Header, PickList, Row, Scrollable, TableRow, Text,
         TextInput,
     },
-    grin_gui_core::wallet::{StatusMessage, WalletInfo, WalletInterface},
+    grin_gui_core::wallet::{StatusMessage, WalletInfo},
     grin_gui_core::{node::amount_to_hr_string, theme::ColorPalette},
     iced::widget::{button, pick_list, scrollable, text_input, Checkbox, Space},
     iced::{alignment, Alignment, Command, Length},
@@ -42,6 +45,9 @@ pub struct StateContainer {
     pub slatepack_read_result: String,
     // Actual read slatepack
     pub slatepack_parsed: Option<(Slatepack, Slate)>,
+    // name and notes to save an unknown sender under
+    pub contact_name_value: String,
+    pub contact_notes_value: String,
 }
 
 impl Default for StateContainer {
@@ -53,6 +59,8 @@ impl Default for StateContainer {
             address_value: Default::default(),
             slatepack_read_result: localized_string("tx-slatepack-read-result-default"),
             slatepack_parsed: None,
+            contact_name_value: Default::default(),
+            contact_notes_value: Default::default(),
         }
     }
 }
@@ -65,7 +73,12 @@ pub enum LocalViewInteraction {
     Back,
     Accept,
     TxAcceptSuccess(Option<String>),
+    InvoicePaySuccess(String),
+    InvoiceFinalizeSuccess,
     TxAcceptFailure(Arc<RwLock<Option<anyhow::Error>>>),
+    ContactName(String),
+    ContactNotes(String),
+    SaveSenderContact,
 }
 
 pub fn handle_message<'a>(
@@ -99,11 +112,10 @@ pub fn handle_message<'a>(
             let out_slate = slate.clone();
             match slate.state {
                 SlateState::Standard1 => {
-                    let fut = move || {
-                        WalletInterface::receive_tx_from_s1(w, out_slate, sp_sending_address)
-                    };
+                    let fut =
+                        w.run(move |w| w.receive_tx_from_s1(out_slate, sp_sending_address));
 
-                    return Ok(Command::perform(fut(), |r| {
+                    return Ok(Command::perform(fut, |r| {
                         match r.context("Failed to Progress Transaction") {
                             Ok(ret) => Message::Interaction(
                                 Interaction::WalletOperationApplyTxConfirmViewInteraction(
@@ -121,9 +133,9 @@ pub fn handle_message<'a>(
                     }));
                 }
                 SlateState::Standard2 => {
-                    let fut = move || WalletInterface::finalize_from_s2(w, out_slate, true);
+                    let fut = w.run(move |w| w.finalize_from_s2(out_slate, true));
 
-                    return Ok(Command::perform(fut(), |r| {
+                    return Ok(Command::perform(fut, |r| {
                         match r.context("Failed to Progress Transaction") {
                             Ok(ret) => Message::Interaction(
                                 Interaction::WalletOperationApplyTxConfirmViewInteraction(
@@ -140,6 +152,57 @@ pub fn handle_message<'a>(
                         }
                     }));
                 }
+                SlateState::Invoice1 => {
+                    // Paid like any other send, with the wallet's send parameters
+                    let wallet = grin_gui
+                        .config
+                        .current_wallet_index
+                        .map(|i| &grin_gui.config.wallets[i]);
+                    let args = create_tx::init_tx_args(
+                        wallet.and_then(|w| w.active_account.clone()),
+                        slate.amount,
+                        &wallet.map(|w| w.send_defaults.clone()).unwrap_or_default(),
+                    );
+                    let fut =
+                        w.run(move |w| w.process_invoice(out_slate, args, sp_sending_address));
+
+                    return Ok(Command::perform(fut, |r| {
+                        match r.context("Failed to Pay Invoice") {
+                            Ok(ret) => Message::Interaction(
+                                Interaction::WalletOperationApplyTxConfirmViewInteraction(
+                                    LocalViewInteraction::InvoicePaySuccess(ret),
+                                ),
+                            ),
+                            Err(e) => Message::Interaction(
+                                Interaction::WalletOperationApplyTxConfirmViewInteraction(
+                                    LocalViewInteraction::TxAcceptFailure(Arc::new(RwLock::new(
+                                        Some(e),
+                                    ))),
+                                ),
+                            ),
+                        }
+                    }));
+                }
+                SlateState::Invoice2 => {
+                    let fut = w.run(move |w| w.finalize_invoice(out_slate));
+
+                    return Ok(Command::perform(fut, |r| {
+                        match r.context("Failed to Finalize Invoice") {
+                            Ok(()) => Message::Interaction(
+                                Interaction::WalletOperationApplyTxConfirmViewInteraction(
+                                    LocalViewInteraction::InvoiceFinalizeSuccess,
+                                ),
+                            ),
+                            Err(e) => Message::Interaction(
+                                Interaction::WalletOperationApplyTxConfirmViewInteraction(
+                                    LocalViewInteraction::TxAcceptFailure(Arc::new(RwLock::new(
+                                        Some(e),
+                                    ))),
+                                ),
+                            ),
+                        }
+                    }));
+                }
                 _ => {
                     log::error!("Slate state not yet supported");
                     return Ok(Command::none());
@@ -148,25 +211,97 @@ pub fn handle_message<'a>(
         }
         LocalViewInteraction::TxAcceptSuccess(slate) => {
             log::debug!("{:?}", slate);
-            grin_gui
-                .wallet_state
-                .operation_state
-                .apply_tx_success_state
-                .encrypted_slate = slate;
+            // Only a received S1 has a slatepack to send back, as S2
+            let parsed = state.slatepack_parsed.as_ref().map(|(_, s)| s.clone());
+            if let (Some(s), Some(slatepack)) = (parsed.as_ref(), slate.as_ref()) {
+                super::remember_slatepack(grin_gui, s, &SlateState::Standard2, slatepack);
+            }
+            // A finalized S2 leaves the other party nothing to be sent again
+            if let Some(s) = parsed.as_ref() {
+                if matches!(s.state, SlateState::Standard2) {
+                    super::forget_slatepacks(grin_gui, &[s.id.to_string()]);
+                }
+            }
+            let success_state = &mut grin_gui.wallet_state.operation_state.apply_tx_success_state;
+            success_state.slatepack_qr = slate.as_ref().and_then(|s| QrData::new(s).ok());
+            success_state.encrypted_slate = slate;
+            if let Some(s) = parsed.as_ref() {
+                success_state.slatepack_file_name = slatepack_file_name(s, &SlateState::Standard2);
+            }
             grin_gui.wallet_state.operation_state.mode =
                 crate::gui::element::wallet::operation::Mode::ApplyTxSuccess;
         }
+        LocalViewInteraction::InvoicePaySuccess(slatepack) => {
+            let parsed = state.slatepack_parsed.as_ref().map(|(_, s)| s.clone());
+            if let Some(s) = parsed.as_ref() {
+                super::remember_slatepack(grin_gui, s, &SlateState::Invoice2, &slatepack);
+            }
+            let success_state = &mut grin_gui.wallet_state.operation_state.invoice_success_state;
+            success_state.step = InvoiceStep::Paid;
+            success_state.slatepack_qr = QrData::new(&slatepack).ok();
+            success_state.encrypted_slate = Some(slatepack);
+            if let Some(s) = parsed.as_ref() {
+                success_state.slatepack_file_name = slatepack_file_name(s, &SlateState::Invoice2);
+            }
+            grin_gui.wallet_state.operation_state.mode =
+                crate::gui::element::wallet::operation::Mode::InvoiceSuccess;
+        }
+        LocalViewInteraction::InvoiceFinalizeSuccess => {
+            if let Some((_, s)) = state.slatepack_parsed.as_ref() {
+                let slate_id = s.id.to_string();
+                super::forget_slatepacks(grin_gui, &[slate_id]);
+            }
+            let success_state = &mut grin_gui.wallet_state.operation_state.invoice_success_state;
+            success_state.step = InvoiceStep::Finalized;
+            success_state.encrypted_slate = None;
+            success_state.slatepack_qr = None;
+            grin_gui.wallet_state.operation_state.mode =
+                crate::gui::element::wallet::operation::Mode::InvoiceSuccess;
+        }
         LocalViewInteraction::TxAcceptFailure(err) => {
             grin_gui.error = err.write().unwrap().take();
             if let Some(e) = grin_gui.error.as_ref() {
                 log_error(e);
             }
         }
+        LocalViewInteraction::ContactName(s) => state.contact_name_value = s,
+        LocalViewInteraction::ContactNotes(s) => state.contact_notes_value = s,
+        LocalViewInteraction::SaveSenderContact => {
+            log::debug!(
+                "Interaction::WalletOperationApplyTxConfirmViewInteraction(SaveSenderContact)"
+            );
+            grin_gui.error.take();
+
+            let name = state.contact_name_value.trim();
+            let sender = state
+                .slatepack_parsed
+                .as_ref()
+                .and_then(|(slatepack, _)| slatepack.sender.as_ref());
+            if let (Some(sender), false) = (sender, name.is_empty()) {
+                grin_gui.address_book.upsert(Contact::new(
+                    name.to_owned(),
+                    sender.to_string(),
+                    state.contact_notes_value.trim().to_owned(),
+                ));
+                if let Err(e) = grin_gui
+                    .address_book
+                    .save()
+                    .context("Failed to save contact")
+                {
+                    log_error(&e);
+                    grin_gui.error = Some(e);
+                }
+            }
+        }
     }
     Ok(Command::none())
 }
 
-pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+pub fn data_container<'a>(
+    config: &'a Config,
+    state: &'a StateContainer,
+    address_book: &'a AddressBook,
+) -> Container<'a, Message> {
     let unit_spacing = 15;
 
     if state.slatepack_parsed.is_none() {
@@ -178,13 +313,22 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
     // Decode/parse/etc fields for display here
     let (slatepack, slate) = state.slatepack_parsed.as_ref().unwrap();
 
-    let sp_sending_address = match &slatepack.sender {
-        None => "None".to_string(),
-        Some(s) => s.to_string(),
+    let sender_contact = slatepack
+        .sender
+        .as_ref()
+        .and_then(|s| address_book.find_by_address(&s.to_string()));
+
+    let sp_sending_address = match (&slatepack.sender, sender_contact) {
+        (None, _) => "None".to_string(),
+        (Some(s), Some(contact)) => format!("{} ({})", contact.name, s),
+        (Some(s), None) => s.to_string(),
     };
 
     let amount = amount_to_hr_string(slate.amount, false);
 
+    let contact_name_value = &state.contact_name_value;
+    let contact_notes_value = &state.contact_notes_value;
+
     let mut state_text = slate.state.to_string();
 
     // TODO: What's displayed here should change based on the slate state
@@ -194,13 +338,22 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
             "You are the payee, and are finalizing the transaction and sending it to the chain for validation - Standard workflow"
         }
         SlateState::Standard3 => "This transaction is finalised - Standard workflow",
+        SlateState::Invoice1 => {
+            "You are the payer, paying this invoice locks the outputs to fund it - Invoice workflow"
+        }
+        SlateState::Invoice2 => {
+            "You issued this invoice, and are finalizing the payment and sending it to the chain for validation - Invoice workflow"
+        }
+        SlateState::Invoice3 => "This invoice is finalised - Invoice workflow",
         _ => "Support still in development",
     };
 
     state_text = format!("{} - {}", state_text, state_text_append);
 
-    let hide_continue =
-        slate.state != SlateState::Standard1 && slate.state != SlateState::Standard2;
+    let hide_continue = !matches!(
+        slate.state,
+        SlateState::Standard1 | SlateState::Standard2 | SlateState::Invoice1 | SlateState::Invoice2
+    );
 
     // Title row
     let title = Text::new(localized_string("apply-tx-confirm"))
@@ -262,6 +415,58 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
         .push(sender_address_label_container)
         .push(sender_address_container);
 
+    // Offer to remember senders that aren't contacts yet
+    let save_contact_row = if slatepack.sender.is_some() && sender_contact.is_none() {
+        let name_input: Element<Interaction> =
+            TextInput::new(&localized_string("contact-name"), contact_name_value, |s| {
+                Interaction::WalletOperationApplyTxConfirmViewInteraction(
+                    LocalViewInteraction::ContactName(s),
+                )
+            })
+            .size(DEFAULT_FONT_SIZE)
+            .padding(6)
+            .width(Length::Units(200))
+            .style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+            .into();
+
+        let notes_input: Element<Interaction> = TextInput::new(
+            &localized_string("contact-notes"),
+            contact_notes_value,
+            |s| {
+                Interaction::WalletOperationApplyTxConfirmViewInteraction(
+                    LocalViewInteraction::ContactNotes(s),
+                )
+            },
+        )
+        .size(DEFAULT_FONT_SIZE)
+        .padding(6)
+        .width(Length::Units(300))
+        .style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+        .into();
+
+        let mut save_button =
+            Button::new(Text::new(localized_string("save-sender-contact")).size(DEFAULT_FONT_SIZE))
+                .style(grin_gui_core::theme::ButtonStyle::Bordered);
+        if !contact_name_value.trim().is_empty() {
+            save_button =
+                save_button.on_press(Interaction::WalletOperationApplyTxConfirmViewInteraction(
+                    LocalViewInteraction::SaveSenderContact,
+                ));
+        }
+        let save_button: Element<Interaction> = save_button.into();
+
+        Some(
+            Row::new()
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .push(name_input.map(Message::Interaction))
+                .push(notes_input.map(Message::Interaction))
+                .push(save_button.map(Message::Interaction)),
+        )
+    } else {
+        None
+    };
+
     let amount_label = Text::new(format!("{}: ", localized_string("apply-tx-amount")))
         .size(DEFAULT_FONT_SIZE)
         .horizontal_alignment(alignment::Horizontal::Left);
@@ -335,11 +540,19 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
         .push(Space::new(Length::Units(unit_spacing), Length::Units(0)))
         .push(cancel_container);
 
-    let column = Column::new()
+    let mut column = Column::new()
         .push(state_row)
         .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
         .push(sender_address_row)
-        .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
+        .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+
+    if let Some(save_contact_row) = save_contact_row {
+        column = column
+            .push(save_contact_row)
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+    }
+
+    let column = column
         .push(amount_row)
         .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
         .push(button_row)
@@ -381,3 +594,133 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
         DEFAULT_PADDING, // left
     ]))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gui::element::wallet::operation::{self, apply_tx, create_invoice};
+    use crate::gui::test::{mock_gui, open_wallet, run, TestDir};
+    use futures::executor::block_on;
+    use grin_gui_core::wallet::{mock::MOCK_SLATEPACK_ADDRESS, MockWallet, SlatepackAddress};
+
+    fn sender() -> Slatepack {
+        Slatepack {
+            sender: Some(SlatepackAddress::try_from(MOCK_SLATEPACK_ADDRESS).unwrap()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_invoice_flow() {
+        let mut mock = MockWallet::new("password").with_received(5_000_000_000);
+        let mut invoice = Slate::blank(2, true);
+        invoice.amount = 2_000_000_000;
+        let mut paid = invoice.clone();
+        paid.state = SlateState::Invoice2;
+        mock.slatepacks
+            .insert("I1".to_owned(), (sender(), invoice.clone()));
+        mock.slatepacks.insert("I2".to_owned(), (sender(), paid));
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, mock);
+        open_wallet(&mut grin_gui, "password");
+
+        // Issue
+        for interaction in [
+            create_invoice::LocalViewInteraction::PayerAddress(MOCK_SLATEPACK_ADDRESS.to_owned()),
+            create_invoice::LocalViewInteraction::Amount("1".to_owned()),
+            create_invoice::LocalViewInteraction::CreateInvoice,
+        ] {
+            run(
+                &mut grin_gui,
+                Message::Interaction(Interaction::WalletOperationCreateInvoiceViewInteraction(
+                    interaction,
+                )),
+            );
+        }
+        assert!(grin_gui.error.is_none());
+        let success_state = &grin_gui.wallet_state.operation_state.invoice_success_state;
+        assert_eq!(success_state.step, InvoiceStep::Issued);
+        assert!(success_state.encrypted_slate.is_some());
+
+        // Pay, then finalize on what is the issuer's side in practice
+        for (slatepack, step) in [("I1", InvoiceStep::Paid), ("I2", InvoiceStep::Finalized)] {
+            for message in [
+                Message::Interaction(Interaction::WalletOperationApplyTxViewInteraction(
+                    apply_tx::LocalViewInteraction::ReadFromClipboardSuccess(slatepack.to_owned()),
+                )),
+                Message::Interaction(Interaction::WalletOperationApplyTxConfirmViewInteraction(
+                    LocalViewInteraction::Accept,
+                )),
+            ] {
+                run(&mut grin_gui, message);
+            }
+            assert!(grin_gui.error.is_none());
+            assert_eq!(
+                grin_gui.wallet_state.operation_state.mode,
+                operation::Mode::InvoiceSuccess
+            );
+            assert_eq!(
+                grin_gui
+                    .wallet_state
+                    .operation_state
+                    .invoice_success_state
+                    .step,
+                step
+            );
+        }
+
+        let (_, info) = block_on(grin_gui.wallet_interface.run(|w| w.get_wallet_info())).unwrap();
+        assert_eq!(info.amount_locked, 2_000_000_000);
+        // Finalized, so the payment kept for the issuer is dropped
+        assert!(grin_gui
+            .sent_slatepacks
+            .get(&invoice.id.to_string(), "I2")
+            .is_none());
+    }
+
+    #[test]
+    fn test_save_sender_contact() {
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, MockWallet::new("password"));
+        open_wallet(&mut grin_gui, "password");
+        run(
+            &mut grin_gui,
+            Message::Interaction(Interaction::WalletOperationApplyTxViewInteraction(
+                apply_tx::LocalViewInteraction::SlatepackDecodeSuccess(Box::new((
+                    sender(),
+                    Slate::blank(2, false),
+                ))),
+            )),
+        );
+
+        let confirm = |grin_gui: &mut GrinGui, interaction| {
+            run(
+                grin_gui,
+                Message::Interaction(Interaction::WalletOperationApplyTxConfirmViewInteraction(
+                    interaction,
+                )),
+            );
+        };
+
+        // Nothing is saved without a name
+        confirm(&mut grin_gui, LocalViewInteraction::SaveSenderContact);
+        assert!(grin_gui.address_book.contacts.is_empty());
+
+        for interaction in [
+            LocalViewInteraction::ContactName(" Alice ".to_owned()),
+            LocalViewInteraction::ContactNotes("coffee".to_owned()),
+            LocalViewInteraction::SaveSenderContact,
+        ] {
+            confirm(&mut grin_gui, interaction);
+        }
+        assert!(grin_gui.error.is_none());
+        let contact = grin_gui
+            .address_book
+            .find_by_address(MOCK_SLATEPACK_ADDRESS)
+            .unwrap();
+        assert_eq!(contact.name, "Alice");
+        assert_eq!(contact.notes, "coffee");
+        assert_eq!(contact.last_used, None);
+        assert_eq!(AddressBook::load().unwrap(), grin_gui.address_book);
+    }
+}
@@ -3,28 +3,39 @@ use {
         BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
         SMALLER_FONT_SIZE,
     },
-    crate::gui::{GrinGui, Interaction, Message},
+    crate::gui::{element::qr_code, GrinGui, Interaction, Message},
     crate::localization::localized_string,
+    crate::log_error,
     crate::Result,
     grin_gui_core::config::Config,
     grin_gui_core::theme::ColorPalette,
     grin_gui_core::theme::{
         Column, Container, Element, PickList, Row, Scrollable, Text, TextInput,
     },
+    grin_gui_core::wallet::write_slatepack_file,
+    grin_gui_widgets::qr_code::QrData,
     iced::widget::{button, pick_list, scrollable, text_input, Button, Checkbox, Space},
     iced::{alignment, Alignment, Command, Length},
     iced_aw::Card,
+    native_dialog::FileDialog,
+    std::path::PathBuf,
 };
 
 pub struct StateContainer {
     // Encrypted slate to send to recipient
     pub encrypted_slate: Option<String>,
+    // Suggested name when saving the slatepack to a file
+    pub slatepack_file_name: String,
+    // QR code of the slatepack, if it fits in one
+    pub slatepack_qr: Option<QrData>,
 }
 
 impl Default for StateContainer {
     fn default() -> Self {
         Self {
             encrypted_slate: Default::default(),
+            slatepack_file_name: Default::default(),
+            slatepack_qr: None,
         }
     }
 }
@@ -32,18 +43,50 @@ impl Default for StateContainer {
 #[derive(Debug, Clone)]
 pub enum LocalViewInteraction {
     Submit,
+    SaveSlatepack,
+    SaveSlatepackFile(PathBuf),
 }
 
 pub fn handle_message(
     grin_gui: &mut GrinGui,
     message: LocalViewInteraction,
 ) -> Result<Command<Message>> {
-    let state = &mut grin_gui.wallet_state.setup_state.setup_wallet_state;
+    let state = &mut grin_gui.wallet_state.operation_state.apply_tx_success_state;
     match message {
         LocalViewInteraction::Submit => {
             grin_gui.wallet_state.operation_state.mode =
                 crate::gui::element::wallet::operation::Mode::Home;
         }
+        LocalViewInteraction::SaveSlatepack => match FileDialog::new()
+            .set_filename(&state.slatepack_file_name)
+            .add_filter("Slatepack", &["slatepack"])
+            .show_save_single_file()
+        {
+            Ok(Some(path)) => {
+                return Ok(Command::perform(async {}, move |_| {
+                    Message::Interaction(Interaction::WalletOperationApplyTxSuccessViewInteraction(
+                        LocalViewInteraction::SaveSlatepackFile(path),
+                    ))
+                }));
+            }
+            Ok(None) => {}
+            Err(e) => {
+                log::debug!(
+                    "apply_tx_success.rs::LocalViewInteraction::SaveSlatepack {}",
+                    e
+                );
+            }
+        },
+        LocalViewInteraction::SaveSlatepackFile(path) => {
+            grin_gui.error.take();
+            if let Some(slatepack) = state.encrypted_slate.as_ref() {
+                if let Err(e) = write_slatepack_file(&path, slatepack) {
+                    let e = anyhow::Error::from(e).context("Failed to save slatepack");
+                    log_error(&e);
+                    grin_gui.error = Some(e);
+                }
+            }
+        }
     }
     Ok(Command::none())
 }
@@ -87,28 +130,45 @@ pub fn data_container<'a>(
         None => "Transaction was posted to chain".to_owned(),
     };
 
+    let mut card_foot = Column::new()
+        .spacing(10)
+        .padding(5)
+        .width(Length::Fill)
+        .align_items(Alignment::Center)
+        .push(
+            Button::new(
+                Text::new(localized_string("copy-to-clipboard"))
+                    .size(SMALLER_FONT_SIZE)
+                    .horizontal_alignment(alignment::Horizontal::Center),
+            )
+            .style(grin_gui_core::theme::ButtonStyle::NormalText)
+            .on_press(Message::Interaction(Interaction::WriteToClipboard(
+                state.encrypted_slate.clone().unwrap_or("None".to_owned()),
+            ))),
+        );
+
+    // Nothing to save once the transaction was posted
+    if state.encrypted_slate.is_some() {
+        card_foot = card_foot.push(
+            Button::new(
+                Text::new(localized_string("save-slatepack-file"))
+                    .size(SMALLER_FONT_SIZE)
+                    .horizontal_alignment(alignment::Horizontal::Center),
+            )
+            .style(grin_gui_core::theme::ButtonStyle::NormalText)
+            .on_press(Message::Interaction(
+                Interaction::WalletOperationApplyTxSuccessViewInteraction(
+                    LocalViewInteraction::SaveSlatepack,
+                ),
+            )),
+        );
+    }
+
     let encrypted_slate_card = Card::new(
         Text::new(localized_string("tx-create-success-title")).size(DEFAULT_HEADER_FONT_SIZE),
         Text::new(slate_card_content).size(DEFAULT_FONT_SIZE),
     )
-    .foot(
-        Column::new()
-            .spacing(10)
-            .padding(5)
-            .width(Length::Fill)
-            .align_items(Alignment::Center)
-            .push(
-                Button::new(
-                    Text::new(localized_string("copy-to-clipboard"))
-                        .size(SMALLER_FONT_SIZE)
-                        .horizontal_alignment(alignment::Horizontal::Center),
-                )
-                .style(grin_gui_core::theme::ButtonStyle::NormalText)
-                .on_press(Message::Interaction(Interaction::WriteToClipboard(
-                    state.encrypted_slate.clone().unwrap_or("None".to_owned()),
-                ))),
-            ),
-    )
+    .foot(card_foot)
     .max_width(400)
     .style(grin_gui_core::theme::CardStyle::Normal);
 
@@ -140,13 +200,24 @@ pub fn data_container<'a>(
     let unit_spacing = 15;
     let button_row = Row::new().push(cancel_container);
 
+    let mut slatepack_row = Row::new()
+        .spacing(DEFAULT_PADDING)
+        .push(encrypted_slate_card);
+    if let Some(qr) = state.slatepack_qr.as_ref() {
+        slatepack_row = slatepack_row.push(qr_code::qr_code_column(
+            qr,
+            state.encrypted_slate.as_deref().unwrap_or_default(),
+            &state.slatepack_file_name,
+        ));
+    }
+
     let column = Column::new()
         .push(description_container)
         .push(Space::new(
             Length::Units(0),
             Length::Units(unit_spacing + 5),
         ))
-        .push(encrypted_slate_card)
+        .push(slatepack_row)
         .push(Space::new(
             Length::Units(0),
             Length::Units(unit_spacing + 10),
@@ -0,0 +1,333 @@
+use crate::log_error;
+use grin_gui_core::{config::Config, error::GrinWalletInterfaceError};
+
+use {
+    super::super::super::{
+        BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+        SMALLER_FONT_SIZE,
+    },
+    super::invoice_success::InvoiceStep,
+    crate::gui::{GrinGui, Interaction, Message},
+    crate::localization::localized_string,
+    crate::Result,
+    grin_gui_core::node::amount_from_hr_string,
+    grin_gui_core::theme::{
+        Button, Column, Container, ContainerStyle, Element, Row, Scrollable, Text, TextInput,
+    },
+    grin_gui_core::wallet::{slatepack_file_name, IssueInvoiceTxArgs, Slate},
+    grin_gui_widgets::qr_code::QrData,
+    iced::widget::Space,
+    iced::{alignment, Command, Length},
+    std::sync::{Arc, RwLock},
+};
+
+#[derive(Default)]
+pub struct StateContainer {
+    pub payer_address_value: String,
+    pub amount_value: String,
+    // whether amount has errored
+    amount_error: bool,
+    // slatepack address error
+    slatepack_address_error: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+    Back,
+    PayerAddress(String),
+    Amount(String),
+    CreateInvoice,
+
+    InvoiceCreatedOk(Box<(Slate, String)>),
+    InvoiceCreateError(Arc<RwLock<Option<anyhow::Error>>>),
+    SlatepackAddressError,
+}
+
+pub fn handle_message(
+    grin_gui: &mut GrinGui,
+    message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+    let state = &mut grin_gui.wallet_state.operation_state.create_invoice_state;
+
+    match message {
+        LocalViewInteraction::Back => {
+            log::debug!("Interaction::WalletOperationCreateInvoiceViewInteraction(Back)");
+            grin_gui.wallet_state.operation_state.mode =
+                crate::gui::element::wallet::operation::Mode::Home;
+        }
+        LocalViewInteraction::PayerAddress(s) => {
+            state.payer_address_value = s;
+        }
+        LocalViewInteraction::Amount(s) => {
+            state.amount_value = s;
+        }
+        LocalViewInteraction::CreateInvoice => {
+            log::debug!("Interaction::WalletOperationCreateInvoiceViewInteraction(CreateInvoice)");
+            grin_gui.error.take();
+            state.amount_error = false;
+            state.slatepack_address_error = false;
+
+            let amount = match amount_from_hr_string(&state.amount_value) {
+                Ok(0) | Err(_) => {
+                    state.amount_error = true;
+                    return Ok(Command::none());
+                }
+                Ok(a) => a,
+            };
+
+            let dest_acct_name = grin_gui
+                .config
+                .current_wallet_index
+                .and_then(|i| grin_gui.config.wallets[i].active_account.clone());
+
+            let args = IssueInvoiceTxArgs {
+                dest_acct_name,
+                amount,
+                ..Default::default()
+            };
+            let address = state.payer_address_value.clone();
+            let fut = grin_gui
+                .wallet_interface
+                .run(move |w| w.issue_invoice(args, address));
+
+            return Ok(Command::perform(fut, |r| match r {
+                Ok(ret) => {
+                    Message::Interaction(Interaction::WalletOperationCreateInvoiceViewInteraction(
+                        LocalViewInteraction::InvoiceCreatedOk(Box::new(ret)),
+                    ))
+                }
+                Err(GrinWalletInterfaceError::InvalidSlatepackAddress) => {
+                    Message::Interaction(Interaction::WalletOperationCreateInvoiceViewInteraction(
+                        LocalViewInteraction::SlatepackAddressError,
+                    ))
+                }
+                Err(e) => {
+                    Message::Interaction(Interaction::WalletOperationCreateInvoiceViewInteraction(
+                        LocalViewInteraction::InvoiceCreateError(Arc::new(RwLock::new(Some(
+                            anyhow::Error::from(e),
+                        )))),
+                    ))
+                }
+            }));
+        }
+        LocalViewInteraction::InvoiceCreatedOk(ret) => {
+            let (slate, slatepack) = *ret;
+            super::remember_slatepack(grin_gui, &slate, &slate.state, &slatepack);
+            let success_state = &mut grin_gui.wallet_state.operation_state.invoice_success_state;
+            success_state.step = InvoiceStep::Issued;
+            success_state.slatepack_qr = QrData::new(&slatepack).ok();
+            success_state.encrypted_slate = Some(slatepack);
+            success_state.slatepack_file_name = slatepack_file_name(&slate, &slate.state);
+            grin_gui.wallet_state.operation_state.mode =
+                crate::gui::element::wallet::operation::Mode::InvoiceSuccess;
+        }
+        LocalViewInteraction::InvoiceCreateError(err) => {
+            grin_gui.error = err.write().unwrap().take();
+            if let Some(e) = grin_gui.error.as_ref() {
+                log_error(e);
+            }
+        }
+        LocalViewInteraction::SlatepackAddressError => state.slatepack_address_error = true,
+    }
+
+    Ok(Command::none())
+}
+
+pub fn data_container<'a>(
+    _config: &'a Config,
+    state: &'a StateContainer,
+) -> Container<'a, Message> {
+    let unit_spacing = 15;
+
+    // Title row
+    let title = Text::new(localized_string("create-invoice"))
+        .size(DEFAULT_HEADER_FONT_SIZE)
+        .horizontal_alignment(alignment::Horizontal::Center);
+
+    let title_container = Container::new(title)
+        .style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+        .padding(iced::Padding::from([
+            2, // top
+            0, // right
+            2, // bottom
+            5, // left
+        ]));
+
+    let header_row = Row::new().push(title_container);
+
+    let header_container = Container::new(header_row).padding(iced::Padding::from([
+        0,               // top
+        0,               // right
+        DEFAULT_PADDING, // bottom
+        0,               // left
+    ]));
+
+    let payer_address = Text::new(localized_string("invoice-payer-address"))
+        .size(DEFAULT_FONT_SIZE)
+        .horizontal_alignment(alignment::Horizontal::Left);
+
+    let payer_address_container =
+        Container::new(payer_address).style(ContainerStyle::NormalBackground);
+
+    let address_instruction = Text::new(localized_string("invoice-payer-address-instruction"))
+        .size(SMALLER_FONT_SIZE)
+        .horizontal_alignment(alignment::Horizontal::Left);
+
+    let address_instruction_container =
+        Container::new(address_instruction).style(ContainerStyle::NormalBackground);
+
+    let payer_address_input: Element<Interaction> =
+        TextInput::new("", &state.payer_address_value, |s| {
+            Interaction::WalletOperationCreateInvoiceViewInteraction(
+                LocalViewInteraction::PayerAddress(s),
+            )
+        })
+        .size(DEFAULT_FONT_SIZE)
+        .padding(6)
+        .width(Length::Units(400))
+        .style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+        .into();
+
+    let address_error = Text::new(localized_string("create-tx-address-error"))
+        .size(DEFAULT_FONT_SIZE)
+        .horizontal_alignment(alignment::Horizontal::Left)
+        .style(grin_gui_core::theme::text::TextStyle::Warning);
+
+    let address_error_container =
+        Container::new(address_error).style(ContainerStyle::NormalBackground);
+
+    let amount = Text::new(localized_string("invoice-amount"))
+        .size(DEFAULT_FONT_SIZE)
+        .horizontal_alignment(alignment::Horizontal::Left);
+
+    let amount_container = Container::new(amount).style(ContainerStyle::NormalBackground);
+
+    let amount_input: Element<Interaction> = TextInput::new("", &state.amount_value, |s| {
+        Interaction::WalletOperationCreateInvoiceViewInteraction(LocalViewInteraction::Amount(s))
+    })
+    .size(DEFAULT_FONT_SIZE)
+    .padding(6)
+    .width(Length::Units(100))
+    .style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+    .into();
+
+    let amount_error = Text::new(localized_string("create-tx-amount-error"))
+        .size(DEFAULT_FONT_SIZE)
+        .horizontal_alignment(alignment::Horizontal::Left)
+        .style(grin_gui_core::theme::text::TextStyle::Warning);
+
+    let amount_error_container =
+        Container::new(amount_error).style(ContainerStyle::NormalBackground);
+
+    let button_height = Length::Units(BUTTON_HEIGHT);
+    let button_width = Length::Units(BUTTON_WIDTH);
+
+    let submit_button_label_container = Container::new(
+        Text::new(localized_string("invoice-create-submit")).size(DEFAULT_FONT_SIZE),
+    )
+    .width(button_width)
+    .height(button_height)
+    .center_x()
+    .center_y()
+    .align_x(alignment::Horizontal::Center);
+
+    let submit_button: Element<Interaction> = Button::new(submit_button_label_container)
+        .style(grin_gui_core::theme::ButtonStyle::Primary)
+        .on_press(Interaction::WalletOperationCreateInvoiceViewInteraction(
+            LocalViewInteraction::CreateInvoice,
+        ))
+        .into();
+
+    let cancel_button_label_container =
+        Container::new(Text::new(localized_string("cancel")).size(DEFAULT_FONT_SIZE))
+            .width(button_width)
+            .height(button_height)
+            .center_x()
+            .center_y()
+            .align_x(alignment::Horizontal::Center);
+
+    let cancel_button: Element<Interaction> = Button::new(cancel_button_label_container)
+        .style(grin_gui_core::theme::ButtonStyle::Primary)
+        .on_press(Interaction::WalletOperationCreateInvoiceViewInteraction(
+            LocalViewInteraction::Back,
+        ))
+        .into();
+
+    let submit_container = Container::new(submit_button.map(Message::Interaction)).padding(1);
+    let submit_container = Container::new(submit_container)
+        .style(grin_gui_core::theme::ContainerStyle::Segmented)
+        .padding(1);
+
+    let cancel_container = Container::new(cancel_button.map(Message::Interaction)).padding(1);
+    let cancel_container = Container::new(cancel_container)
+        .style(grin_gui_core::theme::ContainerStyle::Segmented)
+        .padding(1);
+
+    let button_row = Row::new()
+        .push(submit_container)
+        .push(Space::new(Length::Units(unit_spacing), Length::Units(0)))
+        .push(cancel_container);
+
+    let mut column = Column::new()
+        .push(payer_address_container)
+        .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
+        .push(address_instruction_container)
+        .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
+        .push(payer_address_input.map(Message::Interaction))
+        .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+
+    if state.slatepack_address_error {
+        column = column
+            .push(address_error_container)
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+    }
+
+    column = column
+        .push(amount_container)
+        .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
+        .push(amount_input.map(Message::Interaction))
+        .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+
+    if state.amount_error {
+        column = column
+            .push(amount_error_container)
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+    }
+
+    column = column.push(button_row).push(Space::new(
+        Length::Units(0),
+        Length::Units(unit_spacing + 10),
+    ));
+
+    let form_container = Container::new(column)
+        .width(Length::Fill)
+        .padding(iced::Padding::from([
+            0, // top
+            0, // right
+            0, // bottom
+            5, // left
+        ]));
+
+    // form container should be scrollable in tiny windows
+    let scrollable = Scrollable::new(form_container)
+        .height(Length::Fill)
+        .style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+    let content = Container::new(scrollable)
+        .width(Length::Fill)
+        .height(Length::Shrink)
+        .style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+    let wrapper_column = Column::new()
+        .height(Length::Fill)
+        .push(header_container)
+        .push(content);
+
+    // Returns the final container.
+    Container::new(wrapper_column).padding(iced::Padding::from([
+        DEFAULT_PADDING, // top
+        DEFAULT_PADDING, // right
+        DEFAULT_PADDING, // bottom
+        DEFAULT_PADDING, // left
+    ]))
+}
@@ -1,12 +1,13 @@
 use super::tx_list::{self, ExpandType};
 use crate::log_error;
 use async_std::prelude::FutureExt;
+use chrono::Utc;
 use grin_gui_core::{
-    config::Config,
+    config::{AddressBook, Config, Contact, SendDefaults},
     error::GrinWalletInterfaceError,
     wallet::{TxLogEntry, TxLogEntryType},
 };
-use grin_gui_widgets::widget::header;
+use grin_gui_widgets::{qr_code::QrData, widget::header};
 use iced_aw::Card;
 use iced_native::Widget;
 use std::path::PathBuf;
@@ -22,11 +23,15 @@ use {
     crate::localization::localized_string,
     crate::Result,
     anyhow::Context,
+    grin_gui_core::fs::PersistentData,
     grin_gui_core::theme::{
         Button, Column, Container, Element, Header, PickList, Row, Scrollable, TableRow, Text,
         TextInput,
     },
-    grin_gui_core::wallet::{InitTxArgs, Slate, StatusMessage, WalletInfo, WalletInterface},
+    grin_gui_core::wallet::{
+        max_send_amount, output_commit_hex, slatepack_file_name, InitTxArgs, OutputCommitMapping,
+        OutputStatus, Slate, SlatepackAddress, StatusMessage, TxEstimate, WalletInfo,
+    },
     grin_gui_core::{
         node::{amount_from_hr_string, amount_to_hr_string},
         theme::{ButtonStyle, ColorPalette, ContainerStyle},
@@ -45,8 +50,33 @@ pub struct StateContainer {
     amount_error: bool,
     // slatepack address error
     slatepack_address_error: bool,
+    // whether the user picks the outputs funding the send
+    pub coin_control: bool,
+    // unspent outputs available to coin control
+    pub spendable_outputs: Vec<OutputCommitMapping>,
+    // chain height the outputs were loaded at, to count their confirmations
+    pub outputs_height: u64,
+    // commitments (hex) of the outputs picked to fund the send
+    pub selected_outputs: Vec<String>,
+    // estimate under review, outputs are only locked once it's confirmed
+    pub estimate: Option<TxEstimate>,
+    // spendable balance once the reviewed send is made
+    pub spendable_after: Option<u64>,
+    // whether the advanced send parameters are shown
+    pub show_advanced: bool,
+    pub minimum_confirmations_value: String,
+    pub num_change_outputs_value: String,
+    // blank for no TTL
+    pub ttl_blocks_value: String,
+    pub late_lock: bool,
+    pub use_all: bool,
+    // whether an advanced parameter doesn't parse
+    advanced_error: bool,
 }
 
+/// Most outputs a send may spend
+const MAX_OUTPUTS: u32 = 500;
+
 impl Default for StateContainer {
     fn default() -> Self {
         Self {
@@ -54,7 +84,95 @@ impl Default for StateContainer {
             amount_value: Default::default(),
             amount_error: false,
             slatepack_address_error: false,
+            coin_control: false,
+            spendable_outputs: vec![],
+            outputs_height: 0,
+            selected_outputs: vec![],
+            estimate: None,
+            spendable_after: None,
+            show_advanced: false,
+            minimum_confirmations_value: Default::default(),
+            num_change_outputs_value: Default::default(),
+            ttl_blocks_value: Default::default(),
+            late_lock: false,
+            use_all: false,
+            advanced_error: false,
         }
+        .with_send_defaults(&SendDefaults::default())
+    }
+}
+
+impl StateContainer {
+    /// Outputs coin control offers, those the wallet will spend with the minimum
+    /// confirmations entered
+    pub fn pickable_outputs(&self) -> Vec<&OutputCommitMapping> {
+        let minimum_confirmations = self.minimum_confirmations_value.trim().parse().ok();
+        self.spendable_outputs
+            .iter()
+            .filter(|o| {
+                minimum_confirmations
+                    .map_or(true, |m| o.output.eligible_to_spend(self.outputs_height, m))
+            })
+            .collect()
+    }
+
+    /// Drops picks that aren't offered anymore
+    fn retain_pickable_selection(&mut self) {
+        let pickable: Vec<String> = self
+            .pickable_outputs()
+            .into_iter()
+            .map(output_commit_hex)
+            .collect();
+        self.selected_outputs.retain(|c| pickable.contains(c));
+    }
+
+    /// Total value of the outputs picked with coin control
+    pub fn selected_value(&self) -> u64 {
+        self.pickable_outputs()
+            .into_iter()
+            .filter(|o| self.selected_outputs.contains(&output_commit_hex(o)))
+            .map(|o| o.output.value)
+            .sum()
+    }
+
+    /// Resets the advanced parameters to a wallet's defaults
+    pub fn load_send_defaults(&mut self, defaults: &SendDefaults) {
+        self.minimum_confirmations_value = defaults.minimum_confirmations.to_string();
+        self.num_change_outputs_value = defaults.num_change_outputs.to_string();
+        self.ttl_blocks_value = defaults
+            .ttl_blocks
+            .map(|t| t.to_string())
+            .unwrap_or_default();
+        self.late_lock = defaults.late_lock;
+        self.use_all = defaults.use_all;
+        self.advanced_error = false;
+        self.estimate = None;
+        self.retain_pickable_selection();
+    }
+
+    fn with_send_defaults(mut self, defaults: &SendDefaults) -> Self {
+        self.load_send_defaults(defaults);
+        self
+    }
+
+    /// Advanced parameters as entered, `None` if any doesn't parse
+    pub fn send_parameters(&self) -> Option<SendDefaults> {
+        let ttl_blocks = match self.ttl_blocks_value.trim() {
+            "" => None,
+            t => Some(t.parse::<u64>().ok().filter(|t| *t > 0)?),
+        };
+        Some(SendDefaults {
+            minimum_confirmations: self.minimum_confirmations_value.trim().parse().ok()?,
+            num_change_outputs: self
+                .num_change_outputs_value
+                .trim()
+                .parse()
+                .ok()
+                .filter(|n| *n > 0)?,
+            late_lock: self.late_lock,
+            ttl_blocks,
+            use_all: self.use_all,
+        })
     }
 }
 
@@ -65,10 +183,26 @@ pub enum Action {}
 pub enum LocalViewInteraction {
     Back,
     RecipientAddress(String),
+    SelectContact(Contact),
     Amount(String),
     CreateTransaction(),
+    TxEstimateOk(TxEstimate),
+    EditTransaction,
+    ConfirmTransaction,
+    ToggleCoinControl(bool),
+    SelectOutput(String, bool),
+    OutputsUpdateSuccess(Vec<OutputCommitMapping>, u64),
+    ToggleAdvanced(bool),
+    MinimumConfirmations(String),
+    NumChangeOutputs(String),
+    TtlBlocks(String),
+    LateLock(bool),
+    UseAll(bool),
+    SaveSendDefaults,
+    SendMax,
+    SendMaxOutputs(Vec<OutputCommitMapping>, u64),
 
-    TxCreatedOk(String),
+    TxCreatedOk(Box<(Slate, String)>),
     TxCreateError(Arc<RwLock<Option<anyhow::Error>>>),
     SlatepackAddressError,
 }
@@ -87,45 +221,214 @@ pub fn handle_message<'a>(
         }
         LocalViewInteraction::RecipientAddress(s) => {
             state.recipient_address_value = s;
+            state.estimate = None;
+        }
+        LocalViewInteraction::SelectContact(contact) => {
+            state.recipient_address_value = contact.address;
+            state.slatepack_address_error = false;
+            state.estimate = None;
         }
         LocalViewInteraction::Amount(s) => {
             state.amount_value = s;
+            state.estimate = None;
         }
-        LocalViewInteraction::CreateTransaction() => {
-            grin_gui.error.take();
+        LocalViewInteraction::ToggleCoinControl(enabled) => {
+            state.coin_control = enabled;
+            state.estimate = None;
+            state.selected_outputs.clear();
+            if enabled {
+                // Late locking picks inputs again at finalize time, ignoring the choice
+                state.late_lock = false;
+                let fut = grin_gui.wallet_interface.run(|w| {
+                    let (_, outputs) = w.get_outputs(false)?;
+                    let (_, info) = w.get_wallet_info()?;
+                    Ok((outputs, info.last_confirmed_height))
+                });
+                return Ok(Command::perform(fut, |r| match r {
+                    Ok((outputs, height)) => {
+                        Message::Interaction(Interaction::WalletOperationCreateTxViewInteraction(
+                            LocalViewInteraction::OutputsUpdateSuccess(outputs, height),
+                        ))
+                    }
+                    Err(e) => {
+                        Message::Interaction(Interaction::WalletOperationCreateTxViewInteraction(
+                            LocalViewInteraction::TxCreateError(Arc::new(RwLock::new(Some(
+                                anyhow::Error::from(e),
+                            )))),
+                        ))
+                    }
+                }));
+            }
+        }
+        LocalViewInteraction::OutputsUpdateSuccess(outputs, height) => {
+            state.spendable_outputs = outputs
+                .into_iter()
+                .filter(|o| o.output.status == OutputStatus::Unspent)
+                .collect();
+            state.outputs_height = height;
+            state.retain_pickable_selection();
+        }
+        LocalViewInteraction::SelectOutput(commit, selected) => {
+            state.estimate = None;
+            state.selected_outputs.retain(|c| *c != commit);
+            if selected {
+                state.selected_outputs.push(commit);
+            }
+        }
+        LocalViewInteraction::ToggleAdvanced(show) => state.show_advanced = show,
+        LocalViewInteraction::MinimumConfirmations(s) => {
+            state.minimum_confirmations_value = s;
+            state.estimate = None;
+            state.retain_pickable_selection();
+        }
+        LocalViewInteraction::NumChangeOutputs(s) => {
+            state.num_change_outputs_value = s;
+            state.estimate = None;
+        }
+        LocalViewInteraction::TtlBlocks(s) => {
+            state.ttl_blocks_value = s;
+            state.estimate = None;
+        }
+        LocalViewInteraction::LateLock(late_lock) => {
+            if !state.coin_control {
+                state.late_lock = late_lock;
+                state.estimate = None;
+            }
+        }
+        LocalViewInteraction::UseAll(use_all) => {
+            state.use_all = use_all;
+            state.estimate = None;
+        }
+        LocalViewInteraction::SaveSendDefaults => {
+            state.advanced_error = false;
+            match state.send_parameters() {
+                Some(defaults) => {
+                    if let Some(i) = grin_gui.config.current_wallet_index {
+                        grin_gui.config.wallets[i].send_defaults = defaults;
+                        let _ = grin_gui.config.save();
+                    }
+                }
+                None => state.advanced_error = true,
+            }
+        }
+        LocalViewInteraction::SendMax => {
+            let fut = grin_gui.wallet_interface.run(|w| {
+                let (_, outputs) = w.get_outputs(false)?;
+                let (_, info) = w.get_wallet_info()?;
+                Ok((outputs, info.last_confirmed_height))
+            });
+            return Ok(Command::perform(fut, |r| match r {
+                Ok((outputs, height)) => {
+                    Message::Interaction(Interaction::WalletOperationCreateTxViewInteraction(
+                        LocalViewInteraction::SendMaxOutputs(outputs, height),
+                    ))
+                }
+                Err(e) => {
+                    Message::Interaction(Interaction::WalletOperationCreateTxViewInteraction(
+                        LocalViewInteraction::TxCreateError(Arc::new(RwLock::new(Some(
+                            anyhow::Error::from(e),
+                        )))),
+                    ))
+                }
+            }));
+        }
+        LocalViewInteraction::SendMaxOutputs(outputs, height) => {
             state.amount_error = false;
-            state.slatepack_address_error = false;
+            state.advanced_error = false;
+            let params = match state.send_parameters() {
+                Some(p) => p,
+                None => {
+                    state.advanced_error = true;
+                    return Ok(Command::none());
+                }
+            };
+            let outputs: Vec<OutputCommitMapping> = if state.coin_control {
+                outputs
+                    .into_iter()
+                    .filter(|o| state.selected_outputs.contains(&output_commit_hex(o)))
+                    .collect()
+            } else {
+                outputs
+            };
+            let max = max_send_amount(
+                &outputs,
+                height,
+                params.minimum_confirmations,
+                MAX_OUTPUTS as usize,
+            );
+            state.estimate = None;
+            if max == 0 {
+                state.amount_error = true;
+            } else {
+                state.amount_value = amount_to_hr_string(max, false);
+            }
+        }
+        LocalViewInteraction::CreateTransaction() => {
+            log::debug!("Interaction::WalletOperationCreateTxViewInteraction(CreateTransaction)");
 
-            log::debug!("Interaction::WalletOperationCreateTxViewInteraction");
+            let (args, selected_outputs) = match send_args(grin_gui) {
+                Some(a) => a,
+                None => return Ok(Command::none()),
+            };
+            let state = &mut grin_gui.wallet_state.operation_state.create_tx_state;
+            if SlatepackAddress::try_from(state.recipient_address_value.as_str()).is_err() {
+                state.slatepack_address_error = true;
+                return Ok(Command::none());
+            }
 
-            let w = grin_gui.wallet_interface.clone();
+            let fut = grin_gui
+                .wallet_interface
+                .run(move |w| w.estimate_tx(args, selected_outputs));
 
-            let amount = match amount_from_hr_string(&state.amount_value) {
-                Ok(0) | Err(_) => {
-                    state.amount_error = true;
-                    return Ok(Command::none());
+            return Ok(Command::perform(fut, |r| match r {
+                Ok(estimate) => {
+                    Message::Interaction(Interaction::WalletOperationCreateTxViewInteraction(
+                        LocalViewInteraction::TxEstimateOk(estimate),
+                    ))
                 }
-                Ok(a) => a,
-            };
+                Err(e) => {
+                    Message::Interaction(Interaction::WalletOperationCreateTxViewInteraction(
+                        LocalViewInteraction::TxCreateError(Arc::new(RwLock::new(Some(
+                            anyhow::Error::from(e),
+                        )))),
+                    ))
+                }
+            }));
+        }
+        LocalViewInteraction::TxEstimateOk(estimate) => {
+            state.spendable_after = grin_gui
+                .wallet_state
+                .operation_state
+                .home_state
+                .wallet_info
+                .as_ref()
+                .map(|i| {
+                    i.amount_currently_spendable
+                        .saturating_sub(estimate.total_inputs)
+                });
+            state.estimate = Some(estimate);
+        }
+        LocalViewInteraction::EditTransaction => {
+            state.estimate = None;
+        }
+        LocalViewInteraction::ConfirmTransaction => {
+            log::debug!("Interaction::WalletOperationCreateTxViewInteraction(ConfirmTransaction)");
 
-            // Todo: Amount parsing + validation, just testing the flow for now
-            let args = InitTxArgs {
-                src_acct_name: None,
-                amount,
-                minimum_confirmations: 2,
-                max_outputs: 500,
-                num_change_outputs: 1,
-                selection_strategy_is_use_all: false,
-                late_lock: Some(false),
-                ..Default::default()
+            let (args, selected_outputs) = match send_args(grin_gui) {
+                Some(a) => a,
+                None => return Ok(Command::none()),
             };
-            let fut =
-                move || WalletInterface::create_tx(w, args, state.recipient_address_value.clone());
+            let state = &mut grin_gui.wallet_state.operation_state.create_tx_state;
+            state.estimate = None;
+            let address = state.recipient_address_value.clone();
+            let fut = grin_gui
+                .wallet_interface
+                .run(move |w| w.create_tx(args, address, selected_outputs));
 
-            return Ok(Command::perform(fut(), |r| match r {
+            return Ok(Command::perform(fut, |r| match r {
                 Ok(ret) => {
                     Message::Interaction(Interaction::WalletOperationCreateTxViewInteraction(
-                        LocalViewInteraction::TxCreatedOk(ret),
+                        LocalViewInteraction::TxCreatedOk(Box::new(ret)),
                     ))
                 }
                 Err(e) => match e {
@@ -142,13 +445,33 @@ pub fn handle_message<'a>(
                 },
             }));
         }
-        LocalViewInteraction::TxCreatedOk(slate) => {
-            log::debug!("{:?}", slate);
-            grin_gui
+        LocalViewInteraction::TxCreatedOk(ret) => {
+            let (slate, slatepack) = *ret;
+            log::debug!("{:?}", slatepack);
+
+            // Keep recently paid contacts at the top of the picker
+            if grin_gui
+                .address_book
+                .touch(&state.recipient_address_value, Utc::now())
+            {
+                if let Err(e) = grin_gui
+                    .address_book
+                    .save()
+                    .context("Failed to save contact")
+                {
+                    log_error(&e);
+                    grin_gui.error = Some(e);
+                }
+            }
+
+            super::remember_slatepack(grin_gui, &slate, &slate.state, &slatepack);
+            let success_state = &mut grin_gui
                 .wallet_state
                 .operation_state
-                .create_tx_success_state
-                .encrypted_slate = slate.to_string();
+                .create_tx_success_state;
+            success_state.slatepack_qr = QrData::new(&slatepack).ok();
+            success_state.encrypted_slate = slatepack;
+            success_state.slatepack_file_name = slatepack_file_name(&slate, &slate.state);
             grin_gui.wallet_state.operation_state.mode =
                 crate::gui::element::wallet::operation::Mode::CreateTxSuccess;
         }
@@ -164,7 +487,82 @@ pub fn handle_message<'a>(
     Ok(Command::none())
 }
 
-pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+/// Validates the form and builds the arguments for estimating or creating the send,
+/// flagging any invalid field in the state
+fn send_args(grin_gui: &mut GrinGui) -> Option<(InitTxArgs, Option<Vec<String>>)> {
+    grin_gui.error.take();
+    let src_acct_name = grin_gui
+        .config
+        .current_wallet_index
+        .and_then(|i| grin_gui.config.wallets[i].active_account.clone());
+
+    let state = &mut grin_gui.wallet_state.operation_state.create_tx_state;
+    state.amount_error = false;
+    state.slatepack_address_error = false;
+    state.advanced_error = false;
+
+    let params = match state.send_parameters() {
+        Some(p) => p,
+        None => {
+            state.advanced_error = true;
+            state.show_advanced = true;
+            return None;
+        }
+    };
+
+    let amount = match amount_from_hr_string(&state.amount_value) {
+        Ok(0) | Err(_) => {
+            state.amount_error = true;
+            return None;
+        }
+        Ok(a) => a,
+    };
+
+    let selected_outputs = if state.coin_control {
+        if params.late_lock {
+            grin_gui.error = Some(GrinWalletInterfaceError::LateLockWithCoinControl.into());
+            return None;
+        }
+        if state.selected_value() < amount {
+            state.amount_error = true;
+            return None;
+        }
+        Some(state.selected_outputs.clone())
+    } else {
+        None
+    };
+
+    Some((
+        init_tx_args(src_acct_name, amount, &params),
+        selected_outputs,
+    ))
+}
+
+/// Arguments for a payment of `amount` built with the given send parameters, shared by
+/// sends and invoice payments
+pub fn init_tx_args(
+    src_acct_name: Option<String>,
+    amount: u64,
+    params: &SendDefaults,
+) -> InitTxArgs {
+    InitTxArgs {
+        src_acct_name,
+        amount,
+        minimum_confirmations: params.minimum_confirmations,
+        max_outputs: MAX_OUTPUTS,
+        num_change_outputs: params.num_change_outputs,
+        selection_strategy_is_use_all: params.use_all,
+        late_lock: Some(params.late_lock),
+        ttl_blocks: params.ttl_blocks,
+        ..Default::default()
+    }
+}
+
+pub fn data_container<'a>(
+    config: &'a Config,
+    state: &'a StateContainer,
+    address_book: &'a AddressBook,
+) -> Container<'a, Message> {
     // Title row
     let title = Text::new(localized_string("create-tx"))
         .size(DEFAULT_HEADER_FONT_SIZE)
@@ -208,6 +606,25 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 
     let recipient_address_input: Element<Interaction> = recipient_address_input.into();
 
+    let selected_contact = address_book
+        .find_by_address(&state.recipient_address_value)
+        .cloned();
+    let contact_pick_list = PickList::new(address_book.by_last_used(), selected_contact, |c| {
+        Message::Interaction(Interaction::WalletOperationCreateTxViewInteraction(
+            LocalViewInteraction::SelectContact(c),
+        ))
+    })
+    .placeholder(localized_string("select-contact"))
+    .text_size(DEFAULT_FONT_SIZE)
+    .width(Length::Units(200))
+    .style(grin_gui_core::theme::PickListStyle::Primary);
+
+    let recipient_address_row = Row::new()
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .push(recipient_address_input.map(Message::Interaction))
+        .push(contact_pick_list);
+
     let address_error = Text::new(localized_string("create-tx-address-error"))
         .size(DEFAULT_FONT_SIZE)
         .horizontal_alignment(alignment::Horizontal::Left)
@@ -236,6 +653,23 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 
     let amount_input: Element<Interaction> = amount_input.into();
 
+    let send_max_button: Element<Interaction> = Button::new(
+        Text::new(localized_string("send-max"))
+            .size(DEFAULT_FONT_SIZE)
+            .horizontal_alignment(alignment::Horizontal::Center),
+    )
+    .style(grin_gui_core::theme::ButtonStyle::Primary)
+    .on_press(Interaction::WalletOperationCreateTxViewInteraction(
+        LocalViewInteraction::SendMax,
+    ))
+    .into();
+
+    let amount_row = Row::new()
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .push(amount_input.map(Message::Interaction))
+        .push(send_max_button.map(Message::Interaction));
+
     let amount_error = Text::new(localized_string("create-tx-amount-error"))
         .size(DEFAULT_FONT_SIZE)
         .horizontal_alignment(alignment::Horizontal::Left)
@@ -255,24 +689,45 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
     let button_height = Length::Units(BUTTON_HEIGHT);
     let button_width = Length::Units(BUTTON_WIDTH);
 
+    let submit_label = if state.estimate.is_some() {
+        localized_string("tx-confirm-submit")
+    } else {
+        localized_string("tx-create-submit")
+    };
     let submit_button_label_container =
-        Container::new(Text::new(localized_string("tx-create-submit")).size(DEFAULT_FONT_SIZE))
+        Container::new(Text::new(submit_label).size(DEFAULT_FONT_SIZE))
             .width(button_width)
             .height(button_height)
             .center_x()
             .center_y()
             .align_x(alignment::Horizontal::Center);
 
+    // Once reviewed the send is confirmed, or the form reopened for editing
+    let submit_message = if state.estimate.is_some() {
+        LocalViewInteraction::ConfirmTransaction
+    } else {
+        LocalViewInteraction::CreateTransaction()
+    };
+    let cancel_message = if state.estimate.is_some() {
+        LocalViewInteraction::EditTransaction
+    } else {
+        LocalViewInteraction::Back
+    };
+
     let mut submit_button = Button::new(submit_button_label_container)
         .style(grin_gui_core::theme::ButtonStyle::Primary);
-    let submit_button =
-        submit_button.on_press(Interaction::WalletOperationCreateTxViewInteraction(
-            LocalViewInteraction::CreateTransaction(),
-        ));
+    let submit_button = submit_button.on_press(
+        Interaction::WalletOperationCreateTxViewInteraction(submit_message),
+    );
     let submit_button: Element<Interaction> = submit_button.into();
 
+    let cancel_label = if state.estimate.is_some() {
+        localized_string("tx-edit")
+    } else {
+        localized_string("cancel")
+    };
     let cancel_button_label_container =
-        Container::new(Text::new(localized_string("cancel")).size(DEFAULT_FONT_SIZE))
+        Container::new(Text::new(cancel_label).size(DEFAULT_FONT_SIZE))
             .width(button_width)
             .height(button_height)
             .center_x()
@@ -282,7 +737,7 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
     let cancel_button: Element<Interaction> = Button::new(cancel_button_label_container)
         .style(grin_gui_core::theme::ButtonStyle::Primary)
         .on_press(Interaction::WalletOperationCreateTxViewInteraction(
-            LocalViewInteraction::Back,
+            cancel_message,
         ))
         .into();
 
@@ -307,7 +762,7 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
         .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
         .push(address_instruction_container)
         .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
-        .push(recipient_address_input.map(Message::Interaction))
+        .push(recipient_address_row)
         .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
 
     if state.slatepack_address_error {
@@ -319,7 +774,7 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
     column = column
         .push(amount_container)
         .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
-        .push(amount_input.map(Message::Interaction))
+        .push(amount_row)
         .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
 
     if state.amount_error {
@@ -328,6 +783,102 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
             .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
     }
 
+    let coin_control_checkbox: Element<Interaction> =
+        Checkbox::new(localized_string("coin-control"), state.coin_control, |b| {
+            Interaction::WalletOperationCreateTxViewInteraction(
+                LocalViewInteraction::ToggleCoinControl(b),
+            )
+        })
+        .style(grin_gui_core::theme::CheckboxStyle::Normal)
+        .text_size(DEFAULT_FONT_SIZE)
+        .spacing(5)
+        .into();
+
+    column = column
+        .push(coin_control_checkbox.map(Message::Interaction))
+        .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+
+    if state.coin_control {
+        let instruction = Text::new(localized_string("coin-control-instruction"))
+            .size(SMALLER_FONT_SIZE)
+            .horizontal_alignment(alignment::Horizontal::Left);
+        column = column
+            .push(Container::new(instruction).style(ContainerStyle::NormalBackground))
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+
+        let mut outputs_column = Column::new().spacing(5);
+        let pickable_outputs = state.pickable_outputs();
+        if pickable_outputs.is_empty() {
+            outputs_column = outputs_column.push(
+                Text::new(localized_string("coin-control-no-outputs")).size(DEFAULT_FONT_SIZE),
+            );
+        }
+        for output in pickable_outputs {
+            let commit = output_commit_hex(output);
+            let label = format!(
+                "{}  {}…  ({} {})",
+                amount_to_hr_string(output.output.value, false),
+                &commit[..16],
+                localized_string("output-height"),
+                output.output.height,
+            );
+            let checkbox: Element<Interaction> =
+                Checkbox::new(label, state.selected_outputs.contains(&commit), move |b| {
+                    Interaction::WalletOperationCreateTxViewInteraction(
+                        LocalViewInteraction::SelectOutput(commit.clone(), b),
+                    )
+                })
+                .style(grin_gui_core::theme::CheckboxStyle::Normal)
+                .text_size(SMALLER_FONT_SIZE)
+                .spacing(5)
+                .into();
+            outputs_column = outputs_column.push(checkbox.map(Message::Interaction));
+        }
+
+        let selected_total = Text::new(format!(
+            "{}: {}",
+            localized_string("coin-control-selected"),
+            amount_to_hr_string(state.selected_value(), false)
+        ))
+        .size(DEFAULT_FONT_SIZE);
+
+        column = column
+            .push(Container::new(outputs_column).style(ContainerStyle::NormalBackground))
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
+            .push(Container::new(selected_total).style(ContainerStyle::NormalBackground))
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+    }
+
+    let advanced_checkbox: Element<Interaction> = Checkbox::new(
+        localized_string("send-advanced"),
+        state.show_advanced,
+        |b| {
+            Interaction::WalletOperationCreateTxViewInteraction(
+                LocalViewInteraction::ToggleAdvanced(b),
+            )
+        },
+    )
+    .style(grin_gui_core::theme::CheckboxStyle::Normal)
+    .text_size(DEFAULT_FONT_SIZE)
+    .spacing(5)
+    .into();
+
+    column = column
+        .push(advanced_checkbox.map(Message::Interaction))
+        .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+
+    if state.show_advanced {
+        column = column
+            .push(advanced_container(state))
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+    }
+
+    if let Some(estimate) = state.estimate.as_ref() {
+        column = column
+            .push(review_container(estimate, state.spendable_after))
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+    }
+
     column = column
         .push(button_row)
         .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
@@ -368,3 +919,513 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
         DEFAULT_PADDING, // left
     ]))
 }
+
+/// Send parameters that usually keep the wallet's defaults
+fn advanced_container<'a>(state: &'a StateContainer) -> Element<'a, Message> {
+    let number_row = |label: String,
+                      value: &'a str,
+                      on_change: fn(String) -> LocalViewInteraction|
+     -> Element<'a, Message> {
+        let input: Element<Interaction> = TextInput::new("", value, move |s| {
+            Interaction::WalletOperationCreateTxViewInteraction(on_change(s))
+        })
+        .size(DEFAULT_FONT_SIZE)
+        .padding(6)
+        .width(Length::Units(100))
+        .style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+        .into();
+        Row::new()
+            .align_items(Alignment::Center)
+            .push(
+                Text::new(label)
+                    .size(DEFAULT_FONT_SIZE)
+                    .width(Length::Units(200)),
+            )
+            .push(input.map(Message::Interaction))
+            .into()
+    };
+    let checkbox = |label: String,
+                    checked: bool,
+                    on_toggle: fn(bool) -> LocalViewInteraction|
+     -> Element<'a, Message> {
+        let checkbox: Element<Interaction> = Checkbox::new(label, checked, move |b| {
+            Interaction::WalletOperationCreateTxViewInteraction(on_toggle(b))
+        })
+        .style(grin_gui_core::theme::CheckboxStyle::Normal)
+        .text_size(DEFAULT_FONT_SIZE)
+        .spacing(5)
+        .into();
+        checkbox.map(Message::Interaction)
+    };
+
+    let save_button: Element<Interaction> =
+        Button::new(Text::new(localized_string("send-save-defaults")).size(DEFAULT_FONT_SIZE))
+            .style(grin_gui_core::theme::ButtonStyle::Primary)
+            .on_press(Interaction::WalletOperationCreateTxViewInteraction(
+                LocalViewInteraction::SaveSendDefaults,
+            ))
+            .into();
+
+    let mut column = Column::new()
+        .spacing(5)
+        .push(number_row(
+            localized_string("send-minimum-confirmations"),
+            state.minimum_confirmations_value.as_str(),
+            LocalViewInteraction::MinimumConfirmations,
+        ))
+        .push(number_row(
+            localized_string("send-change-outputs"),
+            state.num_change_outputs_value.as_str(),
+            LocalViewInteraction::NumChangeOutputs,
+        ))
+        .push(number_row(
+            localized_string("send-ttl-blocks"),
+            state.ttl_blocks_value.as_str(),
+            LocalViewInteraction::TtlBlocks,
+        ))
+        .push(if state.coin_control {
+            // Checkboxes can't be disabled, so coin control shows why the option is gone
+            Text::new(localized_string("send-late-lock-coin-control"))
+                .size(DEFAULT_FONT_SIZE)
+                .into()
+        } else {
+            checkbox(
+                localized_string("send-late-lock"),
+                state.late_lock,
+                LocalViewInteraction::LateLock,
+            )
+        })
+        .push(checkbox(
+            localized_string("send-use-all"),
+            state.use_all,
+            LocalViewInteraction::UseAll,
+        ));
+
+    if state.advanced_error {
+        column = column.push(
+            Text::new(localized_string("send-advanced-error"))
+                .size(DEFAULT_FONT_SIZE)
+                .style(grin_gui_core::theme::text::TextStyle::Warning),
+        );
+    }
+
+    column = column.push(save_button.map(Message::Interaction));
+
+    Container::new(column)
+        .style(ContainerStyle::NormalBackground)
+        .into()
+}
+
+/// Figures of the send under review
+fn review_container<'a>(
+    estimate: &TxEstimate,
+    spendable_after: Option<u64>,
+) -> Element<'a, Message> {
+    let mut rows = vec![
+        (
+            localized_string("tx-review-amount"),
+            amount_to_hr_string(estimate.amount, false),
+        ),
+        (
+            localized_string("tx-review-fee"),
+            amount_to_hr_string(estimate.fee, false),
+        ),
+        (
+            localized_string("tx-review-total-debited"),
+            amount_to_hr_string(estimate.total_debited(), false),
+        ),
+        (
+            localized_string("tx-review-inputs"),
+            estimate.num_inputs.to_string(),
+        ),
+        (
+            localized_string("tx-review-change-outputs"),
+            format!(
+                "{} ({})",
+                estimate.num_change_outputs,
+                amount_to_hr_string(estimate.change(), false)
+            ),
+        ),
+    ];
+    if let Some(spendable) = spendable_after {
+        rows.push((
+            localized_string("tx-review-spendable-after"),
+            amount_to_hr_string(spendable, false),
+        ));
+    }
+
+    let title = Text::new(localized_string("tx-review"))
+        .size(DEFAULT_FONT_SIZE)
+        .horizontal_alignment(alignment::Horizontal::Left);
+    let mut column = Column::new()
+        .spacing(5)
+        .push(Container::new(title).style(ContainerStyle::NormalBackground));
+    for (label, value) in rows {
+        let row = Row::new()
+            .push(
+                Text::new(label)
+                    .size(SMALLER_FONT_SIZE)
+                    .width(Length::Units(200)),
+            )
+            .push(Text::new(value).size(SMALLER_FONT_SIZE));
+        column = column.push(row);
+    }
+
+    Container::new(column)
+        .style(ContainerStyle::NormalBackground)
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gui::element::wallet::operation::{self, action_menu};
+    use crate::gui::test::{mock_gui, open_wallet, run, TestDir};
+    use futures::executor::block_on;
+    use grin_gui_core::wallet::{mock::MOCK_SLATEPACK_ADDRESS, MockWallet};
+
+    fn create(grin_gui: &mut GrinGui, interactions: Vec<LocalViewInteraction>) {
+        for interaction in interactions {
+            run(
+                grin_gui,
+                Message::Interaction(Interaction::WalletOperationCreateTxViewInteraction(
+                    interaction,
+                )),
+            );
+        }
+    }
+
+    #[test]
+    fn test_send_parameters() {
+        let mut state = StateContainer::default();
+        state.load_send_defaults(&SendDefaults {
+            ttl_blocks: Some(30),
+            ..Default::default()
+        });
+        assert_eq!(state.ttl_blocks_value, "30");
+        assert_eq!(
+            state.send_parameters(),
+            Some(SendDefaults {
+                ttl_blocks: Some(30),
+                ..Default::default()
+            })
+        );
+
+        // Blank means no TTL, but none of the counts may be zero or garbage
+        state.ttl_blocks_value = " ".to_owned();
+        assert_eq!(state.send_parameters().unwrap().ttl_blocks, None);
+        for (field, value) in [(0, "0"), (0, "two"), (1, "0"), (2, "-1")] {
+            let mut state = StateContainer::default();
+            state.load_send_defaults(&SendDefaults::default());
+            match field {
+                0 => state.num_change_outputs_value = value.to_owned(),
+                1 => state.ttl_blocks_value = value.to_owned(),
+                _ => state.minimum_confirmations_value = value.to_owned(),
+            }
+            assert_eq!(state.send_parameters(), None, "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_pickable_outputs() {
+        let mut state = StateContainer::default();
+        state.spendable_outputs = vec![
+            MockWallet::output(1, 30, OutputStatus::Unspent, None),
+            MockWallet::output(2, 10, OutputStatus::Unspent, None),
+        ];
+        state.spendable_outputs[1].output.height = 2;
+        state.outputs_height = 2;
+        state.selected_outputs = state
+            .spendable_outputs
+            .iter()
+            .map(output_commit_hex)
+            .collect();
+
+        // Only outputs with the confirmations asked for are offered, and stay picked
+        state.load_send_defaults(&SendDefaults::default());
+        assert_eq!(state.pickable_outputs().len(), 1);
+        assert_eq!(state.selected_outputs.len(), 1);
+        assert_eq!(state.selected_value(), 30);
+
+        state.minimum_confirmations_value = "1".to_owned();
+        assert_eq!(state.pickable_outputs().len(), 2);
+        state.minimum_confirmations_value = "3".to_owned();
+        assert!(state.pickable_outputs().is_empty());
+        assert_eq!(state.selected_value(), 0);
+        // Nothing to go by until it parses
+        state.minimum_confirmations_value = "three".to_owned();
+        assert_eq!(state.pickable_outputs().len(), 2);
+    }
+
+    #[test]
+    fn test_review_estimate_before_locking() {
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(
+            &dir,
+            MockWallet::new("password")
+                .with_received(3_000_000_000)
+                .with_received(5_000_000_000),
+        );
+        open_wallet(&mut grin_gui, "password");
+
+        create(
+            &mut grin_gui,
+            vec![
+                LocalViewInteraction::RecipientAddress(MOCK_SLATEPACK_ADDRESS.to_owned()),
+                LocalViewInteraction::Amount("4".to_owned()),
+                LocalViewInteraction::CreateTransaction(),
+            ],
+        );
+        assert!(grin_gui.error.is_none());
+        let estimate = grin_gui
+            .wallet_state
+            .operation_state
+            .create_tx_state
+            .estimate
+            .clone()
+            .unwrap();
+        assert_eq!(estimate.amount, 4_000_000_000);
+        assert_eq!(estimate.num_inputs, 2);
+        assert_eq!(estimate.num_change_outputs, 1);
+        assert_eq!(estimate.total_inputs, 8_000_000_000);
+        assert_eq!(
+            estimate.change(),
+            8_000_000_000 - 4_000_000_000 - estimate.fee
+        );
+
+        // Nothing is locked until the send is confirmed
+        let (_, info) = block_on(grin_gui.wallet_interface.run(|w| w.get_wallet_info())).unwrap();
+        assert_eq!(info.amount_locked, 0);
+
+        create(
+            &mut grin_gui,
+            vec![LocalViewInteraction::ConfirmTransaction],
+        );
+        assert!(grin_gui.error.is_none());
+        assert_eq!(
+            grin_gui.wallet_state.operation_state.mode,
+            operation::Mode::CreateTxSuccess
+        );
+        let (_, info) = block_on(grin_gui.wallet_interface.run(|w| w.get_wallet_info())).unwrap();
+        assert_eq!(info.amount_locked, 4_000_000_000);
+    }
+
+    #[test]
+    fn test_send_to_contact() {
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(
+            &dir,
+            MockWallet::new("password").with_received(5_000_000_000),
+        );
+        open_wallet(&mut grin_gui, "password");
+        let contact = Contact::new(
+            "Alice".to_owned(),
+            MOCK_SLATEPACK_ADDRESS.to_owned(),
+            String::new(),
+        );
+        grin_gui.address_book.upsert(contact.clone());
+
+        // Picking the contact fills in the recipient, and sending to it is remembered
+        create(
+            &mut grin_gui,
+            vec![
+                LocalViewInteraction::SelectContact(contact),
+                LocalViewInteraction::Amount("1".to_owned()),
+                LocalViewInteraction::CreateTransaction(),
+                LocalViewInteraction::ConfirmTransaction,
+            ],
+        );
+        assert!(grin_gui.error.is_none());
+        assert_eq!(
+            grin_gui
+                .wallet_state
+                .operation_state
+                .create_tx_state
+                .recipient_address_value,
+            MOCK_SLATEPACK_ADDRESS
+        );
+        assert!(grin_gui.address_book.contacts[0].last_used.is_some());
+        assert_eq!(AddressBook::load().unwrap(), grin_gui.address_book);
+    }
+
+    #[test]
+    fn test_create_tx_with_selected_outputs() {
+        let dir = TestDir::new();
+        let mut mock = MockWallet::new("password")
+            .with_received(3_000_000_000)
+            .with_received(5_000_000_000);
+        // Both outputs have the 2 confirmations sends ask for by default
+        mock.info.last_confirmed_height = 2;
+        let mut grin_gui = mock_gui(&dir, mock);
+        open_wallet(&mut grin_gui, "password");
+
+        // Late locking would pick the inputs again, so it's off while picking them
+        create(
+            &mut grin_gui,
+            vec![
+                LocalViewInteraction::LateLock(true),
+                LocalViewInteraction::ToggleCoinControl(true),
+                LocalViewInteraction::LateLock(true),
+            ],
+        );
+        let state = &grin_gui.wallet_state.operation_state.create_tx_state;
+        assert!(!state.late_lock);
+        assert_eq!(state.pickable_outputs().len(), 2);
+        let second = output_commit_hex(&state.spendable_outputs[1]);
+
+        // Nothing ticked, so the send doesn't reach the wallet
+        create(
+            &mut grin_gui,
+            vec![
+                LocalViewInteraction::RecipientAddress(MOCK_SLATEPACK_ADDRESS.to_owned()),
+                LocalViewInteraction::Amount("4".to_owned()),
+                LocalViewInteraction::CreateTransaction(),
+            ],
+        );
+        assert!(grin_gui
+            .wallet_state
+            .operation_state
+            .create_tx_state
+            .estimate
+            .is_none());
+
+        // The estimate spends exactly the chosen output and leaves the wallet untouched
+        create(
+            &mut grin_gui,
+            vec![
+                LocalViewInteraction::SelectOutput(second.clone(), true),
+                LocalViewInteraction::CreateTransaction(),
+            ],
+        );
+        assert!(grin_gui.error.is_none());
+        let estimate = grin_gui
+            .wallet_state
+            .operation_state
+            .create_tx_state
+            .estimate
+            .clone()
+            .unwrap();
+        assert_eq!(estimate.num_inputs, 1);
+        assert_eq!(estimate.total_inputs, 5_000_000_000);
+        let (_, info) = block_on(grin_gui.wallet_interface.run(|w| w.get_wallet_info())).unwrap();
+        assert_eq!(info.amount_locked, 0);
+
+        create(
+            &mut grin_gui,
+            vec![LocalViewInteraction::ConfirmTransaction],
+        );
+        assert!(grin_gui.error.is_none());
+        let (_, outputs) =
+            block_on(grin_gui.wallet_interface.run(|w| w.get_outputs(true))).unwrap();
+        let locked: Vec<String> = outputs
+            .iter()
+            .filter(|o| o.output.status == OutputStatus::Locked)
+            .map(output_commit_hex)
+            .collect();
+        assert_eq!(locked, vec![second]);
+    }
+
+    #[test]
+    fn test_send_max_and_saved_defaults() {
+        let mut mock = MockWallet::new("password")
+            .with_received(3_000_000_000)
+            .with_received(5_000_000_000);
+        mock.info.last_confirmed_height = 10;
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, mock);
+        open_wallet(&mut grin_gui, "password");
+        let index = grin_gui.config.current_wallet_index.unwrap();
+        let defaults = &mut grin_gui.config.wallets[index].send_defaults;
+        defaults.num_change_outputs = 2;
+
+        // Opening the send screen picks up the wallet's defaults
+        run(
+            &mut grin_gui,
+            Message::Interaction(Interaction::WalletOperationHomeActionMenuViewInteraction(
+                action_menu::LocalViewInteraction::SelectAction(action_menu::Action::CreateTx),
+            )),
+        );
+        assert_eq!(
+            grin_gui
+                .wallet_state
+                .operation_state
+                .create_tx_state
+                .num_change_outputs_value,
+            "2"
+        );
+
+        // Using all outputs spends both, and the change is split as configured
+        create(
+            &mut grin_gui,
+            vec![
+                LocalViewInteraction::RecipientAddress(MOCK_SLATEPACK_ADDRESS.to_owned()),
+                LocalViewInteraction::Amount("1".to_owned()),
+                LocalViewInteraction::UseAll(true),
+                LocalViewInteraction::CreateTransaction(),
+            ],
+        );
+        let estimate = grin_gui
+            .wallet_state
+            .operation_state
+            .create_tx_state
+            .estimate
+            .clone()
+            .unwrap();
+        assert_eq!(estimate.num_inputs, 2);
+        assert_eq!(estimate.num_change_outputs, 2);
+
+        // Sending the max leaves nothing over for change
+        create(
+            &mut grin_gui,
+            vec![
+                LocalViewInteraction::UseAll(false),
+                LocalViewInteraction::SendMax,
+                LocalViewInteraction::CreateTransaction(),
+            ],
+        );
+        let state = &grin_gui.wallet_state.operation_state.create_tx_state;
+        let estimate = state.estimate.clone().unwrap();
+        assert_eq!(estimate.total_inputs, 8_000_000_000);
+        assert_eq!(estimate.change(), 0);
+        assert_eq!(estimate.num_change_outputs, 0);
+        assert_eq!(
+            state.amount_value,
+            amount_to_hr_string(8_000_000_000 - estimate.fee, false)
+        );
+
+        // Outputs short of the minimum confirmations can't be sent
+        create(
+            &mut grin_gui,
+            vec![
+                LocalViewInteraction::MinimumConfirmations("11".to_owned()),
+                LocalViewInteraction::SaveSendDefaults,
+                LocalViewInteraction::Amount("1".to_owned()),
+                LocalViewInteraction::SendMax,
+            ],
+        );
+        assert_eq!(
+            grin_gui
+                .wallet_state
+                .operation_state
+                .create_tx_state
+                .amount_value,
+            "1"
+        );
+
+        // Parameters that don't parse are neither saved nor sent
+        create(
+            &mut grin_gui,
+            vec![
+                LocalViewInteraction::NumChangeOutputs("0".to_owned()),
+                LocalViewInteraction::SaveSendDefaults,
+                LocalViewInteraction::CreateTransaction(),
+            ],
+        );
+        let state = &grin_gui.wallet_state.operation_state.create_tx_state;
+        assert!(state.estimate.is_none());
+        assert!(state.show_advanced);
+        let defaults = &grin_gui.config.wallets[index].send_defaults;
+        assert_eq!(defaults.minimum_confirmations, 11);
+        assert_eq!(defaults.num_change_outputs, 2);
+    }
+}
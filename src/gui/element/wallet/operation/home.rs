@@ -7,10 +7,10 @@ use super::{
 use async_std::{prelude::FutureExt, task::current};
 use chrono::{DateTime, DurationRound, TimeZone, Utc};
 use grin_gui_core::{
-    config::{Config, Currency},
+    config::{Config, Currency, SentSlatepacks, TxNotes},
     wallet::{RetrieveTxQueryArgs, TxLogEntry, TxLogEntryType},
 };
-use grin_gui_widgets::widget::header;
+use grin_gui_widgets::{qr_code::QrData, widget::header};
 use iced::Point;
 use iced_aw::Card;
 use iced_native::Widget;
@@ -21,26 +21,30 @@ use plotters::{
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::io::Read;
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+    collections::{HashMap, VecDeque},
+    path::PathBuf,
+};
 
 use {
     super::super::super::{
         DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING, DEFAULT_SUB_HEADER_FONT_SIZE,
         SMALLER_FONT_SIZE,
     },
-    crate::gui::{GrinGui, Interaction, Message},
+    crate::gui::{element::qr_code, GrinGui, Interaction, Message},
     crate::localization::localized_string,
     crate::log_error,
     crate::Result,
     anyhow::Context,
     grin_gui_core::theme::{
-        Button, Column, Container, Element, Header, PickList, Row, Scrollable, TableRow, Text,
-        TextInput,
+        Button, Column, Container, Element, Header, PickList, ProgressBar, Row, Scrollable,
+        TableRow, Text, TextInput,
     },
-    grin_gui_core::wallet::{StatusMessage, WalletInfo, WalletInterface},
-    grin_gui_core::{node::amount_to_hr_string, theme::ColorPalette},
+    grin_gui_core::wallet::{write_payment_proof, AcctPathMapping, StatusMessage, WalletInfo},
+    grin_gui_core::{node::amount_to_hr_string, theme::ColorPalette, wallet::WalletWorker},
     iced::widget::{button, pick_list, scrollable, text_input, Checkbox, Space},
     iced::{alignment, Alignment, Command, Length},
+    native_dialog::FileDialog,
     std::sync::{Arc, RwLock},
 };
 
@@ -49,7 +53,7 @@ pub struct StateContainer {
     pub action_menu_state: action_menu::StateContainer,
     pub tx_list_display_state: tx_list_display::StateContainer,
 
-    wallet_info: Option<WalletInfo>,
+    pub wallet_info: Option<WalletInfo>,
     wallet_status: String,
     last_summary_update: chrono::DateTime<chrono::Local>,
     tx_header_state: HeaderState,
@@ -57,8 +61,30 @@ pub struct StateContainer {
     cursor_index: Option<usize>,
     caption_index: Option<usize>,
     price_history: HashMap<DateTime<Utc>, f64>,
+
+    // Updater messages as they arrive, newest last
+    pub activity_log: VecDeque<(chrono::DateTime<chrono::Local>, StatusMessage)>,
+    // Percentage of a running scan, cleared once it completes
+    pub scan_progress: Option<u8>,
+    show_activity_log: bool,
+
+    // Account labels in the open wallet, empty until first fetched
+    pub accounts: Vec<String>,
+    show_create_account: bool,
+    new_account_value: String,
+
+    // Wallet's slatepack address and its QR code, shown on request
+    pub slatepack_address: String,
+    address_qr: Option<QrData>,
+    show_address: bool,
 }
 
+/// Number of updater messages kept in the activity log
+const ACTIVITY_LOG_SIZE: usize = 100;
+
+/// Account grin-wallet creates with every wallet
+const DEFAULT_ACCOUNT: &str = "default";
+
 #[derive(Debug, Clone)]
 pub enum LocalViewInteraction {
     Back,
@@ -67,12 +93,25 @@ pub enum LocalViewInteraction {
     WalletInfoUpdateSuccess(bool, WalletInfo),
     WalletInfoUpdateFailure(Arc<RwLock<Option<anyhow::Error>>>),
     WalletSlatepackAddressUpdateSuccess(String),
+    ToggleActivityLog,
+    ToggleAddress,
+    AccountsUpdateSuccess(Vec<AcctPathMapping>),
+    SelectAccount(String),
+    ActiveAccountChanged(String),
+    ToggleCreateAccount,
+    NewAccountLabel(String),
+    CreateAccount,
+    AccountError(Arc<RwLock<Option<anyhow::Error>>>),
     WalletCloseError(Arc<RwLock<Option<anyhow::Error>>>),
     WalletCloseSuccess,
     CancelTx(u32),
     TxDetails(TxLogEntryWrap),
     TxCancelledOk(u32),
     TxCancelError(Arc<RwLock<Option<anyhow::Error>>>),
+    ExportPaymentProof(u32),
+    SavePaymentProof(u32, PathBuf),
+    PaymentProofSaved(PathBuf),
+    PaymentProofError(Arc<RwLock<Option<anyhow::Error>>>),
 
     // chart stuff
     MouseIndex(usize, usize),
@@ -124,24 +163,39 @@ pub fn handle_tick<'a>(
     grin_gui: &mut GrinGui,
     time: chrono::DateTime<chrono::Local>,
 ) -> Result<Command<Message>> {
+    if !grin_gui.wallet_interface.wallet_is_open() {
+        return Ok(Command::none());
+    }
+
+    // A scan holds the wallet for as long as it runs, anything queued behind it would
+    // only time out
+    if grin_gui
+        .wallet_state
+        .operation_state
+        .scan_state
+        .is_scanning()
     {
-        let w = grin_gui.wallet_interface.read().unwrap();
-        if !w.wallet_is_open() {
-            return Ok(Command::none());
-        }
+        return Ok(Command::none());
     }
-    let messages = WalletInterface::get_wallet_updater_status(grin_gui.wallet_interface.clone())?;
-    let state = &mut grin_gui.wallet_state.operation_state.home_state;
-    let last_message = messages.get(0);
-    if let Some(m) = last_message {
-        state.wallet_status = match m {
-            StatusMessage::UpdatingOutputs(s) => format!("{}", s),
-            StatusMessage::UpdatingTransactions(s) => format!("{}", s),
-            StatusMessage::FullScanWarn(s) => format!("{}", s),
-            StatusMessage::Scanning(s, m) => format!("Scanning - {}% complete", m),
-            StatusMessage::ScanningComplete(s) => format!("{}", s),
-            StatusMessage::UpdateWarning(s) => format!("{}", s),
+
+    // Updater messages arrive through the wallet subscription, this only prods
+    // backends that have to fetch them
+    let w = grin_gui.wallet_interface.clone();
+    let status_fut = w.run(|w| w.poll_updater_status());
+    let status_cmd = Command::perform(status_fut, |status_res| {
+        // Not worth an error modal every tick, the next one will try again
+        if let Err(e) = status_res {
+            log::debug!("Failed to poll wallet updater status: {}", e);
         }
+        Message::None(())
+    });
+
+    let mut commands = vec![status_cmd];
+
+    let state = &mut grin_gui.wallet_state.operation_state.home_state;
+
+    if state.accounts.is_empty() {
+        commands.push(fetch_accounts(&w));
     }
 
     // calls to API should be limited to once per minute
@@ -161,11 +215,9 @@ pub fn handle_tick<'a>(
         query_args.exclude_cancelled = Some(true);
         query_args.include_outstanding_only = Some(true);
 
-        let w = grin_gui.wallet_interface.clone();
+        let fut = w.run(|w| w.get_wallet_info()); //.join(get_txs(w, Some(query_args)));
 
-        let fut = move || WalletInterface::get_wallet_info(w.clone()); //.join(WalletInterface::get_txs(w, Some(query_args)));
-
-        return Ok(Command::perform(fut(), |wallet_info_res| {
+        let info_cmd = Command::perform(fut, |wallet_info_res| {
             if wallet_info_res.is_err() {
                 let e = wallet_info_res
                     .context("Failed to retrieve wallet info status")
@@ -180,15 +232,15 @@ pub fn handle_tick<'a>(
                 //LocalViewInteraction::WalletInfoUpdateSuccess(node_success, wallet_info, txs),
                 LocalViewInteraction::WalletInfoUpdateSuccess(node_success, wallet_info),
             ))
-        }));
+        });
+        commands.push(info_cmd);
+        return Ok(Command::batch(commands));
     }
     // If slatepack address is not filled out, go get it
     let apply_tx_state = &mut grin_gui.wallet_state.operation_state.apply_tx_state;
     if apply_tx_state.address_value.is_empty() {
-        let w = grin_gui.wallet_interface.clone();
-
-        let fut = move || WalletInterface::get_slatepack_address(w.clone());
-        return Ok(Command::perform(fut(), |get_slatepack_address_res| {
+        let fut = w.run(|w| w.get_slatepack_address());
+        let address_cmd = Command::perform(fut, |get_slatepack_address_res| {
             if get_slatepack_address_res.is_err() {
                 let e = get_slatepack_address_res
                     .context("Failed to retrieve wallet slatepack address")
@@ -202,10 +254,23 @@ pub fn handle_tick<'a>(
                     get_slatepack_address_res.unwrap(),
                 ),
             ))
-        }));
+        });
+        commands.push(address_cmd);
     }
 
-    Ok(Command::none())
+    Ok(Command::batch(commands))
+}
+
+fn fetch_accounts(w: &WalletWorker) -> Command<Message> {
+    let fut = w.run(|w| w.accounts());
+    Command::perform(fut, |r| match r.context("Failed to retrieve wallet accounts") {
+        Ok(accounts) => Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+            LocalViewInteraction::AccountsUpdateSuccess(accounts),
+        )),
+        Err(e) => Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+            LocalViewInteraction::AccountError(Arc::new(RwLock::new(Some(e)))),
+        )),
+    })
 }
 
 pub fn handle_message<'a>(
@@ -226,8 +291,7 @@ pub fn handle_message<'a>(
             state.caption_index = None;
         }
         LocalViewInteraction::Back => {
-            let wallet_interface = grin_gui.wallet_interface.clone();
-            let fut = WalletInterface::close_wallet(wallet_interface);
+            let fut = grin_gui.wallet_interface.run(|w| w.close_wallet());
 
             return Ok(Command::perform(fut, |r| {
                 match r.context("Failed to close wallet") {
@@ -250,7 +314,19 @@ pub fn handle_message<'a>(
                 "Update Wallet Info Summary: {}, {:?}",
                 node_success, wallet_info
             );
+            let tip_moved = state.wallet_info.as_ref().map(|i| i.last_confirmed_height)
+                != Some(wallet_info.last_confirmed_height);
             state.wallet_info = Some(wallet_info);
+
+            // A new block may confirm listed txs, reload them to recount
+            let mode = state.tx_list_display_state.mode.clone();
+            if tip_moved && mode != tx_list_display::Mode::NotInit {
+                return Ok(Command::perform(async {}, |_| {
+                    Message::Interaction(Interaction::WalletOperationHomeTxListDisplayInteraction(
+                        tx_list_display::LocalViewInteraction::SelectMode(mode),
+                    ))
+                }));
+            }
         }
         LocalViewInteraction::WalletInfoUpdateFailure(err) => {
             grin_gui.error = err.write().unwrap().take();
@@ -259,6 +335,9 @@ pub fn handle_message<'a>(
             }
         }
         LocalViewInteraction::WalletCloseSuccess => {
+            // Next wallet opened has its own accounts
+            state.accounts.clear();
+            state.show_create_account = false;
             grin_gui.wallet_state.operation_state.mode =
                 crate::gui::element::wallet::operation::Mode::Open;
         }
@@ -268,7 +347,97 @@ pub fn handle_message<'a>(
                 log_error(e);
             }
         }
+        LocalViewInteraction::ToggleActivityLog => {
+            state.show_activity_log = !state.show_activity_log;
+        }
+        LocalViewInteraction::ToggleAddress => {
+            state.show_address = !state.show_address;
+        }
+        LocalViewInteraction::AccountsUpdateSuccess(accounts) => {
+            state.accounts = accounts.into_iter().map(|a| a.label).collect();
+        }
+        LocalViewInteraction::SelectAccount(label) => {
+            let account = label.clone();
+            let fut = grin_gui
+                .wallet_interface
+                .run(move |w| w.set_active_account(account));
+
+            return Ok(Command::perform(fut, move |r| {
+                match r.context("Failed to select account") {
+                    Ok(()) => {
+                        Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+                            LocalViewInteraction::ActiveAccountChanged(label),
+                        ))
+                    }
+                    Err(e) => {
+                        Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+                            LocalViewInteraction::AccountError(Arc::new(RwLock::new(Some(e)))),
+                        ))
+                    }
+                }
+            }));
+        }
+        LocalViewInteraction::ActiveAccountChanged(label) => {
+            if let Some(index) = grin_gui.config.current_wallet_index {
+                grin_gui.config.wallets[index].active_account = Some(label);
+                let _ = grin_gui.config.save();
+            }
+
+            // Force summary refresh on next tick, and reload transactions for the account
+            state.wallet_info = None;
+            state.last_summary_update = Default::default();
+            let mode = state.tx_list_display_state.mode.clone();
+            let fut = move || async {};
+            return Ok(Command::perform(fut(), |_| {
+                Message::Interaction(Interaction::WalletOperationHomeTxListDisplayInteraction(
+                    super::home::tx_list_display::LocalViewInteraction::SelectMode(mode),
+                ))
+            }));
+        }
+        LocalViewInteraction::ToggleCreateAccount => {
+            state.show_create_account = !state.show_create_account;
+            state.new_account_value.clear();
+        }
+        LocalViewInteraction::NewAccountLabel(label) => {
+            state.new_account_value = label;
+        }
+        LocalViewInteraction::CreateAccount => {
+            let label = state.new_account_value.trim().to_owned();
+            if label.is_empty() {
+                return Ok(Command::none());
+            }
+            state.show_create_account = false;
+            state.new_account_value.clear();
+
+            let fut = grin_gui.wallet_interface.run(move |w| {
+                w.create_account(label)?;
+                w.accounts()
+            });
+
+            return Ok(Command::perform(fut, |r| {
+                match r.context("Failed to create account") {
+                    Ok(accounts) => {
+                        Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+                            LocalViewInteraction::AccountsUpdateSuccess(accounts),
+                        ))
+                    }
+                    Err(e) => {
+                        Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+                            LocalViewInteraction::AccountError(Arc::new(RwLock::new(Some(e)))),
+                        ))
+                    }
+                }
+            }));
+        }
+        LocalViewInteraction::AccountError(err) => {
+            grin_gui.error = err.write().unwrap().take();
+            if let Some(e) = grin_gui.error.as_ref() {
+                log_error(e);
+            }
+        }
         LocalViewInteraction::WalletSlatepackAddressUpdateSuccess(address) => {
+            state.address_qr = QrData::new(&address).ok();
+            state.slatepack_address = address.clone();
             grin_gui
                 .wallet_state
                 .operation_state
@@ -277,7 +446,7 @@ pub fn handle_message<'a>(
         }
         LocalViewInteraction::TxDetails(tx_log_entry_wrap) => {
             log::debug!("Interaction::WalletOperationHomeViewInteraction::TxDetails");
-            log::debug!("TBD {}", tx_log_entry_wrap.tx.id);
+            return Ok(super::tx_detail::open(grin_gui, tx_log_entry_wrap));
         }
         LocalViewInteraction::CancelTx(id) => {
             debug!("Cancel Tx: {}", id);
@@ -285,11 +454,9 @@ pub fn handle_message<'a>(
 
             log::debug!("Interaction::WalletOperationHomeViewInteraction::CancelTx");
 
-            let w = grin_gui.wallet_interface.clone();
-
-            let fut = move || WalletInterface::cancel_tx(w, id);
+            let fut = grin_gui.wallet_interface.run(move |w| w.cancel_tx(id));
 
-            return Ok(Command::perform(fut(), |r| {
+            return Ok(Command::perform(fut, |r| {
                 match r.context("Failed to Cancel Transaction") {
                     Ok(ret) => {
                         Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
@@ -305,6 +472,20 @@ pub fn handle_message<'a>(
             }));
         }
         LocalViewInteraction::TxCancelledOk(id) => {
+            let slate_id = grin_gui
+                .wallet_state
+                .operation_state
+                .home_state
+                .tx_list_display_state
+                .wallet_txs
+                .txs
+                .iter()
+                .find(|t| t.tx.id == id)
+                .and_then(|t| t.tx.tx_slate_id);
+            if let Some(slate_id) = slate_id {
+                super::forget_slatepacks(grin_gui, &[slate_id.to_string()]);
+            }
+
             // Trigger event to reload transaction list
             let mode = grin_gui
                 .wallet_state
@@ -326,11 +507,229 @@ pub fn handle_message<'a>(
                 log_error(e);
             }
         }
+        LocalViewInteraction::ExportPaymentProof(id) => {
+            let file_name = format!("payment_proof_{}.json", id);
+            match FileDialog::new()
+                .set_filename(&file_name)
+                .show_save_single_file()
+            {
+                Ok(Some(path)) => {
+                    return Ok(Command::perform(async {}, move |_| {
+                        Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+                            LocalViewInteraction::SavePaymentProof(id, path),
+                        ))
+                    }));
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    log::debug!("home.rs::LocalViewInteraction::ExportPaymentProof {}", e);
+                }
+            }
+        }
+        LocalViewInteraction::SavePaymentProof(id, path) => {
+            grin_gui.error.take();
+            log::debug!("Interaction::WalletOperationHomeViewInteraction::SavePaymentProof");
+
+            let fut = grin_gui.wallet_interface.run(move |w| {
+                let proof = w.retrieve_payment_proof(id)?;
+                write_payment_proof(&path, &proof)?;
+                Ok(path)
+            });
+
+            return Ok(Command::perform(fut, |r| {
+                match r.context("Failed to export payment proof") {
+                    Ok(path) => {
+                        Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+                            LocalViewInteraction::PaymentProofSaved(path),
+                        ))
+                    }
+                    Err(e) => {
+                        Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+                            LocalViewInteraction::PaymentProofError(Arc::new(RwLock::new(Some(e)))),
+                        ))
+                    }
+                }
+            }));
+        }
+        LocalViewInteraction::PaymentProofSaved(path) => {
+            log::debug!("Payment proof written to {}", path.display());
+        }
+        LocalViewInteraction::PaymentProofError(err) => {
+            grin_gui.error = err.write().unwrap().take();
+            if let Some(e) = grin_gui.error.as_ref() {
+                log_error(e);
+            }
+        }
     }
     Ok(Command::none())
 }
 
-pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+/// Handles a message streamed from the wallet updater, see `wallet::subscriber`
+pub fn handle_status_message(grin_gui: &mut GrinGui, message: StatusMessage) {
+    let state = &mut grin_gui.wallet_state.operation_state.home_state;
+
+    state.wallet_status = format_status_message(&message);
+    match &message {
+        StatusMessage::Scanning(_, pct) => state.scan_progress = Some(*pct),
+        StatusMessage::ScanningComplete(_) => state.scan_progress = None,
+        _ => {}
+    }
+
+    state.activity_log.push_back((chrono::Local::now(), message.clone()));
+    while state.activity_log.len() > ACTIVITY_LOG_SIZE {
+        state.activity_log.pop_front();
+    }
+
+    // A scan started from the scan view reports its progress there too
+    let scan_state = &mut grin_gui.wallet_state.operation_state.scan_state;
+    if let StatusMessage::Scanning(_, pct) = message {
+        if scan_state.is_scanning() {
+            scan_state.progress = Some(pct);
+        }
+    }
+}
+
+/// Account picker, or the new account form while it's open
+fn account_row<'a>(config: &'a Config, state: &'a StateContainer) -> Row<'a, Message> {
+    if state.show_create_account {
+        let label_input = TextInput::new(
+            &localized_string("account-label"),
+            &state.new_account_value,
+            |s| {
+                Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+                    LocalViewInteraction::NewAccountLabel(s),
+                ))
+            },
+        )
+        .on_submit(Message::Interaction(
+            Interaction::WalletOperationHomeViewInteraction(LocalViewInteraction::CreateAccount),
+        ))
+        .size(SMALLER_FONT_SIZE)
+        .padding(2)
+        .width(Length::Units(120))
+        .style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+
+        let create_button: Element<Interaction> = Button::new(
+            Text::new(localized_string("create-account")).size(SMALLER_FONT_SIZE),
+        )
+        .style(grin_gui_core::theme::ButtonStyle::Bordered)
+        .on_press(Interaction::WalletOperationHomeViewInteraction(
+            LocalViewInteraction::CreateAccount,
+        ))
+        .padding(2)
+        .into();
+
+        let cancel_button: Element<Interaction> =
+            Button::new(Text::new(localized_string("cancel")).size(SMALLER_FONT_SIZE))
+                .style(grin_gui_core::theme::ButtonStyle::Bordered)
+                .on_press(Interaction::WalletOperationHomeViewInteraction(
+                    LocalViewInteraction::ToggleCreateAccount,
+                ))
+                .padding(2)
+                .into();
+
+        return Row::new()
+            .push(label_input)
+            .push(create_button.map(Message::Interaction))
+            .push(cancel_button.map(Message::Interaction))
+            .spacing(2)
+            .align_items(Alignment::Center);
+    }
+
+    let active_account = config
+        .current_wallet_index
+        .and_then(|i| config.wallets[i].active_account.clone())
+        .unwrap_or_else(|| DEFAULT_ACCOUNT.to_owned());
+
+    let account_label = Text::new(format!("{}:", localized_string("account")))
+        .size(SMALLER_FONT_SIZE);
+
+    let account_pick_list = PickList::new(&state.accounts[..], Some(active_account), |a| {
+        Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+            LocalViewInteraction::SelectAccount(a),
+        ))
+    })
+    .text_size(SMALLER_FONT_SIZE)
+    .width(Length::Units(120))
+    .style(grin_gui_core::theme::PickListStyle::Primary);
+
+    let new_account_button: Element<Interaction> =
+        Button::new(Text::new(localized_string("new-account")).size(SMALLER_FONT_SIZE))
+            .style(grin_gui_core::theme::ButtonStyle::Bordered)
+            .on_press(Interaction::WalletOperationHomeViewInteraction(
+                LocalViewInteraction::ToggleCreateAccount,
+            ))
+            .padding(2)
+            .into();
+
+    Row::new()
+        .push(account_label)
+        .push(account_pick_list)
+        .push(new_account_button.map(Message::Interaction))
+        .spacing(4)
+        .align_items(Alignment::Center)
+}
+
+/// Slatepack address with copy button and, if it encodes, its QR code
+fn address_row<'a>(state: &'a StateContainer) -> Row<'a, Message> {
+    let address = Text::new(&state.slatepack_address).size(DEFAULT_FONT_SIZE);
+    let address_container =
+        Container::new(address).style(grin_gui_core::theme::ContainerStyle::BrightBackground);
+
+    let copy_address_button: Element<Interaction> = Button::new(
+        Text::new(localized_string("copy-to-clipboard"))
+            .size(SMALLER_FONT_SIZE)
+            .horizontal_alignment(alignment::Horizontal::Center),
+    )
+    .style(grin_gui_core::theme::ButtonStyle::NormalText)
+    .on_press(Interaction::WriteToClipboard(state.slatepack_address.clone()))
+    .into();
+
+    let address_column = Column::new()
+        .spacing(5)
+        .push(address_container)
+        .push(copy_address_button.map(Message::Interaction));
+
+    let mut row = Row::new()
+        .spacing(DEFAULT_PADDING)
+        .padding(iced::Padding::from([
+            0, // top
+            0, // right
+            0, // bottom
+            5, // left
+        ]))
+        .push(address_column);
+
+    if let Some(qr) = state.address_qr.as_ref() {
+        row = row.push(qr_code::qr_code_column(
+            qr,
+            &state.slatepack_address,
+            "slatepack_address",
+        ));
+    }
+
+    row
+}
+
+fn format_status_message(message: &StatusMessage) -> String {
+    match message {
+        StatusMessage::UpdatingOutputs(s) => format!("{}", s),
+        StatusMessage::UpdatingTransactions(s) => format!("{}", s),
+        StatusMessage::FullScanWarn(s) => format!("{}", s),
+        StatusMessage::Scanning(_, pct) => {
+            format!("{} - {}%", localized_string("scan-progress"), pct)
+        }
+        StatusMessage::ScanningComplete(s) => format!("{}", s),
+        StatusMessage::UpdateWarning(s) => format!("{}", s),
+    }
+}
+
+pub fn data_container<'a>(
+    config: &'a Config,
+    state: &'a StateContainer,
+    tx_notes: &'a TxNotes,
+    sent_slatepacks: &'a SentSlatepacks,
+) -> Container<'a, Message> {
     // Buttons to perform operations go here, but empty container for now
     let operations_menu = action_menu::data_container(config, &state.action_menu_state);
 
@@ -420,12 +819,40 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
         .padding(2)
         .into();
 
+    let address_label = if state.show_address {
+        localized_string("hide-address")
+    } else {
+        localized_string("show-address")
+    };
+    let address_label_container = Container::new(Text::new(address_label).size(SMALLER_FONT_SIZE))
+        .height(Length::Units(14))
+        .center_y()
+        .center_x();
+
+    let address_button: Element<Interaction> = Button::new(address_label_container)
+        .style(grin_gui_core::theme::ButtonStyle::Bordered)
+        .on_press(Interaction::WalletOperationHomeViewInteraction(
+            LocalViewInteraction::ToggleAddress,
+        ))
+        .padding(2)
+        .into();
+
     let subtitle_row = Row::new()
         .push(subtitle_container)
         .push(Space::with_width(Length::Units(2)))
-        .push(close_wallet_button.map(Message::Interaction));
+        .push(close_wallet_button.map(Message::Interaction))
+        .push(Space::with_width(Length::Units(2)))
+        .push(address_button.map(Message::Interaction));
 
-    let title_container = Container::new(Column::new().push(title_container).push(subtitle_row))
+    let account_row = account_row(config, state);
+
+    let title_container = Container::new(
+        Column::new()
+            .push(title_container)
+            .push(subtitle_row)
+            .push(Space::with_height(Length::Units(3)))
+            .push(account_row),
+    )
         .padding(iced::Padding::from([
             0, // top
             0, // right
@@ -606,8 +1033,41 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
         .horizontal_alignment(alignment::Horizontal::Right)
         .vertical_alignment(alignment::Vertical::Center);
 
-    let status_container_contents = Row::new()
-        .push(Space::new(Length::Fill, Length::Fill))
+    let activity_log_label = if state.show_activity_log {
+        localized_string("hide-activity-log")
+    } else {
+        localized_string("activity-log")
+    };
+    let activity_log_button: Element<Interaction> = Button::new(
+        Text::new(activity_log_label)
+            .size(SMALLER_FONT_SIZE)
+            .vertical_alignment(alignment::Vertical::Center),
+    )
+    .style(grin_gui_core::theme::ButtonStyle::NormalText)
+    .on_press(Interaction::WalletOperationHomeViewInteraction(
+        LocalViewInteraction::ToggleActivityLog,
+    ))
+    .into();
+
+    let mut status_container_contents = Row::new()
+        .push(activity_log_button.map(Message::Interaction))
+        .push(Space::new(Length::Fill, Length::Fill));
+
+    if let Some(pct) = state.scan_progress {
+        let scan_progress_bar = ProgressBar::new(0.0..=100.0, pct as f32)
+            .width(Length::Units(150))
+            .height(Length::Units(10))
+            .style(grin_gui_core::theme::ProgressBarStyle::Normal);
+        status_container_contents = status_container_contents
+            .push(
+                Container::new(scan_progress_bar)
+                    .height(Length::Fill)
+                    .center_y(),
+            )
+            .push(Space::new(Length::Units(DEFAULT_PADDING), Length::Units(0)));
+    }
+
+    let status_container_contents = status_container_contents
         .push(status_container_label_text)
         .push(status_container_separator_text)
         .push(status_container_status_text)
@@ -625,15 +1085,55 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
         .spacing(25);
 
     // Buttons to perform operations go here, but empty container for now
-    let tx_list_display = tx_list_display::data_container(config, &state.tx_list_display_state);
+    let tx_list_display = tx_list_display::data_container(
+        config,
+        &state.tx_list_display_state,
+        tx_notes,
+        sent_slatepacks,
+        state.wallet_info.as_ref().map(|i| i.last_confirmed_height),
+    );
 
     // Overall Home screen layout column
-    let column = Column::new()
+    let mut column = Column::new()
         .push(header_container)
-        .push(first_row_container)
+        .push(first_row_container);
+
+    if state.show_address {
+        column = column
+            .push(Space::with_height(Length::Units(DEFAULT_PADDING)))
+            .push(address_row(state));
+    }
+
+    let mut column = column
         .push(Space::with_height(Length::Units(DEFAULT_PADDING * 3)))
-        .push(tx_list_display)
-        .push(status_row);
+        .push(tx_list_display);
+
+    if state.show_activity_log {
+        let mut activity_log_column = Column::new().spacing(2);
+        // Newest at the top
+        for (time, message) in state.activity_log.iter().rev() {
+            let line = format!(
+                "{}  {}",
+                time.format("%H:%M:%S"),
+                format_status_message(message)
+            );
+            activity_log_column =
+                activity_log_column.push(Text::new(line).size(SMALLER_FONT_SIZE));
+        }
+
+        let activity_log_scrollable = Scrollable::new(activity_log_column)
+            .style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+        let activity_log_container = Container::new(activity_log_scrollable)
+            .width(Length::Fill)
+            .height(Length::Units(120))
+            .padding(5)
+            .style(grin_gui_core::theme::ContainerStyle::BrightBackground);
+
+        column = column.push(activity_log_container);
+    }
+
+    let column = column.push(status_row);
 
     Container::new(column).padding(iced::Padding::from([
         DEFAULT_PADDING, // top
@@ -642,3 +1142,179 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
         DEFAULT_PADDING, // left
     ]))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gui::test::{mock_gui, open_wallet, run, TestDir};
+    use futures::executor::block_on;
+    use grin_gui_core::wallet::{
+        mock::MOCK_SLATEPACK_ADDRESS, subscriber::UIMessage, InitTxArgs, MockWallet,
+    };
+    use iced_futures::futures::channel::mpsc;
+
+    fn home(grin_gui: &mut GrinGui, interaction: LocalViewInteraction) {
+        run(
+            grin_gui,
+            Message::Interaction(Interaction::WalletOperationHomeViewInteraction(interaction)),
+        );
+    }
+
+    #[test]
+    fn test_cancel_tx() {
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, MockWallet::new("password").with_received(5));
+        open_wallet(&mut grin_gui, "password");
+        block_on(grin_gui.wallet_interface.run(|w| {
+            w.create_tx(
+                InitTxArgs {
+                    amount: 2,
+                    ..Default::default()
+                },
+                MOCK_SLATEPACK_ADDRESS.to_owned(),
+                None,
+            )
+        }))
+        .unwrap();
+
+        home(&mut grin_gui, LocalViewInteraction::CancelTx(1));
+
+        assert!(grin_gui.error.is_none());
+        let (_, info) = block_on(grin_gui.wallet_interface.run(|w| w.get_wallet_info())).unwrap();
+        assert_eq!(info.amount_locked, 0);
+        assert_eq!(info.amount_currently_spendable, 5);
+    }
+
+    #[test]
+    fn test_create_and_select_account() {
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, MockWallet::new("password"));
+        open_wallet(&mut grin_gui, "password");
+        let index = grin_gui.config.current_wallet_index.unwrap();
+
+        home(
+            &mut grin_gui,
+            LocalViewInteraction::SelectAccount("savings".to_owned()),
+        );
+        assert!(grin_gui.error.take().is_some());
+        assert_eq!(grin_gui.config.wallets[index].active_account, None);
+
+        for interaction in [
+            LocalViewInteraction::ToggleCreateAccount,
+            LocalViewInteraction::NewAccountLabel("savings".to_owned()),
+            LocalViewInteraction::CreateAccount,
+            LocalViewInteraction::SelectAccount("savings".to_owned()),
+        ] {
+            home(&mut grin_gui, interaction);
+        }
+        assert!(grin_gui.error.is_none());
+        assert_eq!(
+            grin_gui.wallet_state.operation_state.home_state.accounts,
+            vec!["default".to_owned(), "savings".to_owned()]
+        );
+        assert_eq!(
+            grin_gui.config.wallets[index].active_account,
+            Some("savings".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_updater_status_streams_to_home() {
+        let mut mock = MockWallet::new("password");
+        mock.updater_messages = vec![
+            StatusMessage::UpdatingOutputs("Updating outputs".to_owned()),
+            StatusMessage::Scanning("Scanning".to_owned(), 40),
+        ];
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, mock);
+        open_wallet(&mut grin_gui, "password");
+
+        // Subscription hands over its sender first, as it would on startup
+        let (sender, mut receiver) = mpsc::channel(100);
+        run(
+            &mut grin_gui,
+            Message::SendWalletMessage((0, UIMessage::None, Some(sender))),
+        );
+        block_on(grin_gui.wallet_interface.run(|w| w.poll_updater_status())).unwrap();
+        while let Ok(Some(msg)) = receiver.try_next() {
+            run(&mut grin_gui, Message::SendWalletMessage((0, msg, None)));
+        }
+
+        let home_state = &grin_gui.wallet_state.operation_state.home_state;
+        assert_eq!(home_state.activity_log.len(), 2);
+        assert_eq!(home_state.scan_progress, Some(40));
+
+        run(
+            &mut grin_gui,
+            Message::SendWalletMessage((
+                0,
+                UIMessage::UpdaterStatus(StatusMessage::ScanningComplete(
+                    "Scanning complete".to_owned(),
+                )),
+                None,
+            )),
+        );
+        let home_state = &grin_gui.wallet_state.operation_state.home_state;
+        assert_eq!(home_state.activity_log.len(), 3);
+        assert_eq!(home_state.scan_progress, None);
+    }
+
+    #[test]
+    fn test_tx_list_reloads_on_new_tip() {
+        let mut mock = MockWallet::new("password")
+            .with_received(5_000_000_000)
+            .with_received(1_000_000_000);
+        mock.info.last_confirmed_height = 5;
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, mock);
+        open_wallet(&mut grin_gui, "password");
+        let (_, info) = block_on(grin_gui.wallet_interface.run(|w| w.get_wallet_info())).unwrap();
+        let list_txs = |grin_gui: &mut GrinGui| {
+            grin_gui
+                .wallet_state
+                .operation_state
+                .home_state
+                .tx_list_display_state
+                .wallet_txs
+                .txs
+                .len()
+        };
+
+        // Nothing listed yet, so a new tip has nothing to reload
+        home(
+            &mut grin_gui,
+            LocalViewInteraction::WalletInfoUpdateSuccess(true, info.clone()),
+        );
+        assert_eq!(list_txs(&mut grin_gui), 0);
+
+        run(
+            &mut grin_gui,
+            Message::Interaction(Interaction::WalletOperationHomeTxListDisplayInteraction(
+                tx_list_display::LocalViewInteraction::SelectMode(tx_list_display::Mode::Recent),
+            )),
+        );
+        assert_eq!(list_txs(&mut grin_gui), 2);
+
+        // The list is reloaded once the tip moves, not on every update
+        grin_gui
+            .wallet_state
+            .operation_state
+            .home_state
+            .tx_list_display_state
+            .wallet_txs
+            .txs
+            .clear();
+        home(
+            &mut grin_gui,
+            LocalViewInteraction::WalletInfoUpdateSuccess(true, info.clone()),
+        );
+        assert_eq!(list_txs(&mut grin_gui), 0);
+        let mut next = info;
+        next.last_confirmed_height = 6;
+        home(
+            &mut grin_gui,
+            LocalViewInteraction::WalletInfoUpdateSuccess(true, next),
+        );
+        assert_eq!(list_txs(&mut grin_gui), 2);
+    }
+}
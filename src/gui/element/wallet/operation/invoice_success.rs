@@ -0,0 +1,268 @@
+use {
+    super::super::super::{
+        BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+        SMALLER_FONT_SIZE,
+    },
+    crate::gui::{element::qr_code, GrinGui, Interaction, Message},
+    crate::localization::localized_string,
+    crate::log_error,
+    crate::Result,
+    grin_gui_core::config::Config,
+    grin_gui_core::theme::{Column, Container, Element, Row, Scrollable, Text},
+    grin_gui_core::wallet::write_slatepack_file,
+    grin_gui_widgets::qr_code::QrData,
+    iced::widget::{Button, Space},
+    iced::{alignment, Alignment, Command, Length},
+    iced_aw::Card,
+    native_dialog::FileDialog,
+    std::path::PathBuf,
+};
+
+/// Stage of the invoice flow that just completed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InvoiceStep {
+    /// Issuer created the invoice (I1), to be sent to the payer
+    #[default]
+    Issued,
+    /// Payer paid the invoice (I2), to be sent back to the issuer
+    Paid,
+    /// Issuer finalized the paid invoice (I3) and posted it
+    Finalized,
+}
+
+impl InvoiceStep {
+    fn title_key(&self) -> &'static str {
+        match self {
+            InvoiceStep::Issued => "invoice-issued",
+            InvoiceStep::Paid => "invoice-paid",
+            InvoiceStep::Finalized => "invoice-finalized",
+        }
+    }
+
+    fn description_key(&self) -> &'static str {
+        match self {
+            InvoiceStep::Issued => "invoice-issued-desc",
+            InvoiceStep::Paid => "invoice-paid-desc",
+            InvoiceStep::Finalized => "invoice-finalized-desc",
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct StateContainer {
+    pub step: InvoiceStep,
+    // Encrypted slate to pass on to the other party, none once finalized
+    pub encrypted_slate: Option<String>,
+    // Suggested name when saving the slatepack to a file
+    pub slatepack_file_name: String,
+    // QR code of the slatepack, if it fits in one
+    pub slatepack_qr: Option<QrData>,
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+    Submit,
+    SaveSlatepack,
+    SaveSlatepackFile(PathBuf),
+}
+
+pub fn handle_message(
+    grin_gui: &mut GrinGui,
+    message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+    let state = &mut grin_gui.wallet_state.operation_state.invoice_success_state;
+    match message {
+        LocalViewInteraction::Submit => {
+            grin_gui.wallet_state.operation_state.mode =
+                crate::gui::element::wallet::operation::Mode::Home;
+        }
+        LocalViewInteraction::SaveSlatepack => match FileDialog::new()
+            .set_filename(&state.slatepack_file_name)
+            .add_filter("Slatepack", &["slatepack"])
+            .show_save_single_file()
+        {
+            Ok(Some(path)) => {
+                return Ok(Command::perform(async {}, move |_| {
+                    Message::Interaction(Interaction::WalletOperationInvoiceSuccessViewInteraction(
+                        LocalViewInteraction::SaveSlatepackFile(path),
+                    ))
+                }));
+            }
+            Ok(None) => {}
+            Err(e) => {
+                log::debug!(
+                    "invoice_success.rs::LocalViewInteraction::SaveSlatepack {}",
+                    e
+                );
+            }
+        },
+        LocalViewInteraction::SaveSlatepackFile(path) => {
+            grin_gui.error.take();
+            if let Some(slatepack) = state.encrypted_slate.as_ref() {
+                if let Err(e) = write_slatepack_file(&path, slatepack) {
+                    let e = anyhow::Error::from(e).context("Failed to save slatepack");
+                    log_error(&e);
+                    grin_gui.error = Some(e);
+                }
+            }
+        }
+    }
+    Ok(Command::none())
+}
+
+pub fn data_container<'a>(
+    _config: &'a Config,
+    state: &'a StateContainer,
+) -> Container<'a, Message> {
+    // Title row
+    let title = Text::new(localized_string(state.step.title_key()))
+        .size(DEFAULT_HEADER_FONT_SIZE)
+        .horizontal_alignment(alignment::Horizontal::Center);
+
+    let title_container = Container::new(title)
+        .style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+        .padding(iced::Padding::from([
+            2, // top
+            0, // right
+            2, // bottom
+            5, // left
+        ]));
+
+    let header_row = Row::new().push(title_container);
+
+    let header_container = Container::new(header_row).padding(iced::Padding::from([
+        0,               // top
+        0,               // right
+        DEFAULT_PADDING, // bottom
+        0,               // left
+    ]));
+
+    let description = Text::new(localized_string(state.step.description_key()))
+        .size(DEFAULT_FONT_SIZE)
+        .horizontal_alignment(alignment::Horizontal::Center);
+    let description_container =
+        Container::new(description).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+    let unit_spacing = 15;
+
+    let mut column = Column::new().push(description_container).push(Space::new(
+        Length::Units(0),
+        Length::Units(unit_spacing + 5),
+    ));
+
+    if let Some(encrypted_slate) = state.encrypted_slate.as_ref() {
+        let encrypted_slate_card = Card::new(
+            Text::new(localized_string("tx-create-success-title")).size(DEFAULT_HEADER_FONT_SIZE),
+            Text::new(encrypted_slate).size(DEFAULT_FONT_SIZE),
+        )
+        .foot(
+            Column::new()
+                .spacing(10)
+                .padding(5)
+                .width(Length::Fill)
+                .align_items(Alignment::Center)
+                .push(
+                    Button::new(
+                        Text::new(localized_string("copy-to-clipboard"))
+                            .size(SMALLER_FONT_SIZE)
+                            .horizontal_alignment(alignment::Horizontal::Center),
+                    )
+                    .style(grin_gui_core::theme::ButtonStyle::NormalText)
+                    .on_press(Message::Interaction(
+                        Interaction::WriteToClipboard(encrypted_slate.clone()),
+                    )),
+                )
+                .push(
+                    Button::new(
+                        Text::new(localized_string("save-slatepack-file"))
+                            .size(SMALLER_FONT_SIZE)
+                            .horizontal_alignment(alignment::Horizontal::Center),
+                    )
+                    .style(grin_gui_core::theme::ButtonStyle::NormalText)
+                    .on_press(Message::Interaction(
+                        Interaction::WalletOperationInvoiceSuccessViewInteraction(
+                            LocalViewInteraction::SaveSlatepack,
+                        ),
+                    )),
+                ),
+        )
+        .max_width(400)
+        .style(grin_gui_core::theme::CardStyle::Normal);
+
+        let mut slatepack_row = Row::new()
+            .spacing(DEFAULT_PADDING)
+            .push(encrypted_slate_card);
+        if let Some(qr) = state.slatepack_qr.as_ref() {
+            slatepack_row = slatepack_row.push(qr_code::qr_code_column(
+                qr,
+                encrypted_slate,
+                &state.slatepack_file_name,
+            ));
+        }
+
+        column = column.push(slatepack_row).push(Space::new(
+            Length::Units(0),
+            Length::Units(unit_spacing + 10),
+        ));
+    }
+
+    let button_height = Length::Units(BUTTON_HEIGHT);
+    let button_width = Length::Units(BUTTON_WIDTH);
+
+    let ok_button_label_container =
+        Container::new(Text::new(localized_string("ok-caps")).size(DEFAULT_FONT_SIZE))
+            .width(button_width)
+            .height(button_height)
+            .center_x()
+            .center_y()
+            .align_x(alignment::Horizontal::Center);
+
+    let ok_button: Element<Interaction> = Button::new(ok_button_label_container)
+        .style(grin_gui_core::theme::ButtonStyle::Primary)
+        .on_press(Interaction::WalletOperationInvoiceSuccessViewInteraction(
+            LocalViewInteraction::Submit,
+        ))
+        .into();
+
+    let ok_container = Container::new(ok_button.map(Message::Interaction)).padding(1);
+    let ok_container = Container::new(ok_container)
+        .style(grin_gui_core::theme::ContainerStyle::Segmented)
+        .padding(1);
+
+    column = column.push(Row::new().push(ok_container)).push(Space::new(
+        Length::Units(0),
+        Length::Units(unit_spacing + 10),
+    ));
+
+    let form_container = Container::new(column)
+        .width(Length::Fill)
+        .padding(iced::Padding::from([
+            0, // top
+            0, // right
+            0, // bottom
+            5, // left
+        ]));
+
+    // form container should be scrollable in tiny windows
+    let scrollable = Scrollable::new(form_container)
+        .height(Length::Fill)
+        .style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+    let content = Container::new(scrollable)
+        .width(Length::Fill)
+        .height(Length::Shrink)
+        .style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+    let wrapper_column = Column::new()
+        .height(Length::Fill)
+        .push(header_container)
+        .push(content);
+
+    // Returns the final container.
+    Container::new(wrapper_column).padding(iced::Padding::from([
+        DEFAULT_PADDING, // top
+        DEFAULT_PADDING, // right
+        DEFAULT_PADDING, // bottom
+        DEFAULT_PADDING, // left
+    ]))
+}
@@ -3,21 +3,31 @@ pub mod apply_tx;
 pub mod apply_tx_confirm;
 pub mod apply_tx_success;
 pub mod chart;
+pub mod create_invoice;
 pub mod create_tx;
 pub mod create_tx_success;
 pub mod home;
+pub mod invoice_success;
 pub mod open;
+pub mod outputs;
+pub mod scan;
+pub mod tx_detail;
 pub mod tx_list;
 pub mod tx_list_display;
+pub mod verify_proof;
 
 use {
     crate::gui::{GrinGui, Message},
+    crate::log_error,
     crate::Result,
-    grin_gui_core::config::Config,
+    anyhow::Context,
+    grin_gui_core::config::{AddressBook, Config, SentSlatepack, SentSlatepacks, TxNotes},
+    grin_gui_core::fs::PersistentData,
     grin_gui_core::theme::ColorPalette,
     grin_gui_core::theme::{
         Button, Column, Container, Element, PickList, Row, Scrollable, Text, TextInput,
     },
+    grin_gui_core::wallet::{slatepack_file_name, Slate, SlateState},
     iced::{Command, Length},
 };
 
@@ -30,6 +40,12 @@ pub struct StateContainer {
     pub apply_tx_state: apply_tx::StateContainer,
     pub apply_tx_confirm_state: apply_tx_confirm::StateContainer,
     pub apply_tx_success_state: apply_tx_success::StateContainer,
+    pub outputs_state: outputs::StateContainer,
+    pub create_invoice_state: create_invoice::StateContainer,
+    pub invoice_success_state: invoice_success::StateContainer,
+    pub verify_proof_state: verify_proof::StateContainer,
+    pub scan_state: scan::StateContainer,
+    pub tx_detail_state: tx_detail::StateContainer,
     // When changed to true, this should stay false until a wallet is opened with a password
     has_wallet_open_check_failed_one_time: bool,
 }
@@ -42,7 +58,13 @@ pub enum Mode {
     CreateTxSuccess,
     ApplyTx,
     ApplyTxConfirm,
-    ApplyTxSuccess
+    ApplyTxSuccess,
+    Outputs,
+    CreateInvoice,
+    InvoiceSuccess,
+    VerifyProof,
+    Scan,
+    TxDetail,
 }
 
 impl Default for StateContainer {
@@ -56,6 +78,12 @@ impl Default for StateContainer {
             apply_tx_state: Default::default(),
             apply_tx_confirm_state: Default::default(),
             apply_tx_success_state: Default::default(),
+            outputs_state: Default::default(),
+            create_invoice_state: Default::default(),
+            invoice_success_state: Default::default(),
+            verify_proof_state: Default::default(),
+            scan_state: Default::default(),
+            tx_detail_state: Default::default(),
             has_wallet_open_check_failed_one_time: false,
         }
     }
@@ -76,6 +104,52 @@ impl StateContainer {
     }
 }
 
+/// Keeps a slatepack just handed out for `slate` at `state`, so it can be shown again
+/// while the transaction is outstanding
+pub fn remember_slatepack(
+    grin_gui: &mut GrinGui,
+    slate: &Slate,
+    state: &SlateState,
+    slatepack: &str,
+) {
+    grin_gui.sent_slatepacks.insert(
+        slate.id.to_string(),
+        state.to_string(),
+        SentSlatepack {
+            file_name: slatepack_file_name(slate, state),
+            slatepack: slatepack.to_owned(),
+        },
+    );
+    if let Err(e) = grin_gui
+        .sent_slatepacks
+        .save()
+        .context("Failed to save slatepack")
+    {
+        log_error(&e);
+        grin_gui.error = Some(e);
+    }
+}
+
+/// Drops the slatepacks kept for transactions that no longer wait on the other party,
+/// finalized or cancelled, so they don't pile up
+pub fn forget_slatepacks(grin_gui: &mut GrinGui, slate_ids: &[String]) {
+    let mut removed = false;
+    for slate_id in slate_ids {
+        removed |= grin_gui.sent_slatepacks.remove(slate_id);
+    }
+    if !removed {
+        return;
+    }
+    if let Err(e) = grin_gui
+        .sent_slatepacks
+        .save()
+        .context("Failed to save slatepacks")
+    {
+        log_error(&e);
+        grin_gui.error = Some(e);
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum LocalViewInteraction {}
 
@@ -86,21 +160,45 @@ pub fn handle_message(
     Ok(Command::none())
 }
 
-pub fn data_container<'a>(state: &'a StateContainer, config: &'a Config) -> Container<'a, Message> {
+pub fn data_container<'a>(
+    state: &'a StateContainer,
+    config: &'a Config,
+    address_book: &'a AddressBook,
+    tx_notes: &'a TxNotes,
+    sent_slatepacks: &'a SentSlatepacks,
+) -> Container<'a, Message> {
     let content = match state.mode {
         Mode::Open => open::data_container(&state.open_state, config),
-        Mode::Home => home::data_container(config, &state.home_state),
-        Mode::CreateTx => create_tx::data_container(config, &state.create_tx_state),
+        Mode::Home => home::data_container(config, &state.home_state, tx_notes, sent_slatepacks),
+        Mode::CreateTx => create_tx::data_container(config, &state.create_tx_state, address_book),
         Mode::CreateTxSuccess => {
             create_tx_success::data_container(config, &state.create_tx_success_state)
         }
-        Mode::ApplyTx => apply_tx::data_container(config, &state.apply_tx_state),
+        Mode::ApplyTx => apply_tx::data_container(config, &state.apply_tx_state, address_book),
         Mode::ApplyTxConfirm => {
-            apply_tx_confirm::data_container(config, &state.apply_tx_confirm_state)
+            apply_tx_confirm::data_container(config, &state.apply_tx_confirm_state, address_book)
         }
         Mode::ApplyTxSuccess => {
             apply_tx_success::data_container(config, &state.apply_tx_success_state)
         }
+        Mode::Outputs => outputs::data_container(config, &state.outputs_state),
+        Mode::CreateInvoice => create_invoice::data_container(config, &state.create_invoice_state),
+        Mode::InvoiceSuccess => {
+            invoice_success::data_container(config, &state.invoice_success_state)
+        }
+        Mode::VerifyProof => verify_proof::data_container(config, &state.verify_proof_state),
+        Mode::Scan => scan::data_container(config, &state.scan_state),
+        Mode::TxDetail => tx_detail::data_container(
+            config,
+            &state.tx_detail_state,
+            address_book,
+            sent_slatepacks,
+            state
+                .home_state
+                .wallet_info
+                .as_ref()
+                .map(|i| i.last_confirmed_height),
+        ),
     };
 
     let column = Column::new().push(content);
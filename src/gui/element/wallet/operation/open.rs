@@ -10,13 +10,16 @@ use {
     crate::localization::localized_string,
     crate::Result,
     anyhow::Context,
-    grin_gui_core::config::Config,
+    grin_gui_core::config::{Config, WalletBackendType},
+    grin_gui_core::fs::PersistentData,
     grin_gui_core::theme::ColorPalette,
     grin_gui_core::theme::{
         Button, Column, Container, Element, PickList, Row, Scrollable, Text, TextInput,
     },
     grin_gui_core::{
-        node::ChainTypes::Mainnet, node::ChainTypes::Testnet, wallet::WalletInterface,
+        node::ChainTypes::Mainnet,
+        node::ChainTypes::Testnet,
+        wallet::{read_api_secret, RemoteOwnerApi, WalletBackend},
     },
     iced::widget::{button, pick_list, scrollable, text_input, Space},
     iced::{alignment, Alignment, Command, Length},
@@ -64,7 +67,8 @@ pub enum LocalViewInteraction {
     PasswordInputEnterPressed,
     OpenWallet,
     CancelOpenWallet,
-    WalletOpenedOkay,
+    /// Carries whether the account last used with the wallet could be selected again
+    WalletOpenedOkay(bool),
     WalletOpenError(Arc<RwLock<Option<anyhow::Error>>>),
 }
 
@@ -99,12 +103,53 @@ pub fn handle_message<'a>(
 
             let password = state.password_state.input_value.clone();
             let wallet_interface = grin_gui.wallet_interface.clone();
-            let running_chain_type = grin_gui.node_interface.read().unwrap().chain_type.unwrap();
             let wallet_index = grin_gui.config.current_wallet_index.unwrap();
             let current_wallet = &grin_gui.config.wallets[wallet_index];
             let wallet_chain_type = current_wallet.chain_type;
+            let active_account = current_wallet.active_account.clone();
+
+            // Remote wallets manage their own node connection
+            if let WalletBackendType::RemoteOwnerApi {
+                owner_api_url,
+                owner_api_secret_path,
+                foreign_api_url,
+                foreign_api_secret_path,
+            } = &current_wallet.backend
+            {
+                let remote_api = RemoteOwnerApi::new(
+                    owner_api_url,
+                    foreign_api_url.as_deref(),
+                    read_api_secret(owner_api_secret_path.as_ref()),
+                    read_api_secret(foreign_api_secret_path.as_ref()),
+                );
+                let fut = wallet_interface.run(move |w| {
+                    w.open_remote_wallet(password.clone(), remote_api, wallet_chain_type)?;
+                    Ok(restore_active_account(w, active_account))
+                });
+
+                return Ok(Command::perform(fut, |r| {
+                    match r.context("Failed to Open Remote Wallet") {
+                        Ok(restored) => {
+                            Message::Interaction(Interaction::WalletOperationOpenViewInteraction(
+                                LocalViewInteraction::WalletOpenedOkay(restored),
+                            ))
+                        }
+                        Err(e) => {
+                            Message::Interaction(Interaction::WalletOperationOpenViewInteraction(
+                                LocalViewInteraction::WalletOpenError(Arc::new(RwLock::new(Some(
+                                    e,
+                                )))),
+                            ))
+                        }
+                    }
+                }));
+            }
 
+            // Set when the wallet should check outputs against the embedded node
+            let mut embedded_node_secret_path = None;
             if current_wallet.use_embedded_node {
+                let running_chain_type =
+                    grin_gui.node_interface.read().unwrap().chain_type.unwrap();
                 // restart embedded server is chain types differ
                 if running_chain_type != wallet_chain_type {
                     let mut node = grin_gui.node_interface.write().unwrap();
@@ -114,28 +159,25 @@ pub fn handle_message<'a>(
                 let node_interface = grin_gui.node_interface.read().unwrap();
                 if let Some(c) = &node_interface.config {
                     if let Some(m) = &c.members {
-                        WalletInterface::set_use_embedded_node(wallet_interface.clone(), true);
-                        let mut w = wallet_interface.write().unwrap();
-                        w.check_node_foreign_api_secret_path =
-                            m.server.foreign_api_secret_path.clone();
+                        embedded_node_secret_path = Some(m.server.foreign_api_secret_path.clone());
                     }
                 }
             }
             let tld = current_wallet.tld.clone().unwrap();
-            let fut = move || {
-                WalletInterface::open_wallet(
-                    wallet_interface,
-                    password.clone(),
-                    tld,
-                    current_wallet.chain_type,
-                )
-            };
+            let fut = wallet_interface.run(move |w| {
+                if let Some(secret_path) = embedded_node_secret_path {
+                    w.set_use_embedded_node(true);
+                    w.set_check_node_foreign_api_secret_path(secret_path);
+                }
+                w.open_wallet(password.clone(), tld, wallet_chain_type)?;
+                Ok(restore_active_account(w, active_account))
+            });
 
-            return Ok(Command::perform(fut(), |r| {
+            return Ok(Command::perform(fut, |r| {
                 match r.context("Failed to Open Wallet") {
-                    Ok(()) => {
+                    Ok(restored) => {
                         Message::Interaction(Interaction::WalletOperationOpenViewInteraction(
-                            LocalViewInteraction::WalletOpenedOkay,
+                            LocalViewInteraction::WalletOpenedOkay(restored),
                         ))
                     }
                     Err(e) => {
@@ -146,7 +188,15 @@ pub fn handle_message<'a>(
                 }
             }));
         }
-        LocalViewInteraction::WalletOpenedOkay => {
+        LocalViewInteraction::WalletOpenedOkay(account_restored) => {
+            // The wallet stayed on `default`, so later calls mustn't name the stale account
+            if !account_restored {
+                if let Some(index) = grin_gui.config.current_wallet_index {
+                    grin_gui.config.wallets[index].active_account = None;
+                    let _ = grin_gui.config.save();
+                }
+            }
+
             grin_gui
                 .wallet_state
                 .operation_state
@@ -168,6 +218,22 @@ pub fn handle_message<'a>(
     Ok(Command::none())
 }
 
+/// Switches a freshly opened wallet to the account last used with it. A stale account
+/// (e.g. the wallet was restored elsewhere) leaves the wallet on `default`, and `false`
+/// is returned so it can be forgotten.
+fn restore_active_account(w: &mut dyn WalletBackend, account: Option<String>) -> bool {
+    match account {
+        Some(label) => match w.set_active_account(label.clone()) {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("Unable to select account {}: {}", label, e);
+                false
+            }
+        },
+        None => true,
+    }
+}
+
 pub fn data_container<'a>(
     state: &'a StateContainer,
     config: &Config,
@@ -304,3 +370,40 @@ pub fn data_container<'a>(
         .width(Length::Fill)
         .height(Length::Fill)
 }
+
+#[cfg(test)]
+mod test {
+    use crate::gui::element::wallet::operation;
+    use crate::gui::test::{mock_gui, open_wallet, TestDir};
+    use grin_gui_core::wallet::MockWallet;
+
+    #[test]
+    fn test_open_wallet() {
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, MockWallet::new("password"));
+
+        open_wallet(&mut grin_gui, "wrong");
+        assert!(!grin_gui.wallet_interface.wallet_is_open());
+        assert!(grin_gui.error.is_some());
+
+        open_wallet(&mut grin_gui, "password");
+        assert!(grin_gui.wallet_interface.wallet_is_open());
+        assert!(grin_gui.error.is_none());
+        assert_eq!(
+            grin_gui.wallet_state.operation_state.mode,
+            operation::Mode::Home
+        );
+    }
+
+    #[test]
+    fn test_stale_active_account_is_forgotten() {
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, MockWallet::new("password"));
+        let index = grin_gui.config.current_wallet_index.unwrap();
+        grin_gui.config.wallets[index].active_account = Some("missing".to_owned());
+        open_wallet(&mut grin_gui, "password");
+
+        assert!(grin_gui.error.is_none());
+        assert_eq!(grin_gui.config.wallets[index].active_account, None);
+    }
+}
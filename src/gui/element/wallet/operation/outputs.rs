@@ -0,0 +1,579 @@
+use super::tx_list::{row_title, SortDirection};
+use crate::log_error;
+use grin_gui_core::config::Config;
+use grin_gui_widgets::widget::header;
+
+use {
+    super::super::super::{
+        BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+        SMALLER_FONT_SIZE,
+    },
+    crate::gui::{GrinGui, Interaction, Message},
+    crate::localization::localized_string,
+    crate::Result,
+    anyhow::Context,
+    grin_gui_core::theme::{
+        Button, Column, Container, Element, Header, PickList, Row, Scrollable, TableRow, Text,
+        TextInput,
+    },
+    grin_gui_core::wallet::{output_commit_hex, OutputCommitMapping, OutputStatus},
+    grin_gui_core::node::amount_to_hr_string,
+    iced::widget::Space,
+    iced::{alignment, Alignment, Command, Length},
+    std::sync::{Arc, RwLock},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColumnKey {
+    Commitment,
+    Value,
+    Height,
+    LockHeight,
+    Status,
+    IsCoinbase,
+    TxLogId,
+}
+
+impl ColumnKey {
+    fn title(self) -> String {
+        use ColumnKey::*;
+
+        match self {
+            Commitment => localized_string("output-commitment"),
+            Value => localized_string("output-value"),
+            Height => localized_string("output-height"),
+            LockHeight => localized_string("output-lock-height"),
+            Status => localized_string("output-status"),
+            IsCoinbase => localized_string("output-coinbase"),
+            TxLogId => localized_string("output-tx-id"),
+        }
+    }
+
+    fn as_string(self) -> String {
+        use ColumnKey::*;
+
+        let s = match self {
+            Commitment => "output-commitment",
+            Value => "output-value",
+            Height => "output-height",
+            LockHeight => "output-lock-height",
+            Status => "output-status",
+            IsCoinbase => "output-coinbase",
+            TxLogId => "output-tx-id",
+        };
+
+        s.to_string()
+    }
+}
+
+pub struct ColumnState {
+    key: ColumnKey,
+    width: Length,
+}
+
+/// Which outputs to show, by status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusFilter {
+    All,
+    Unconfirmed,
+    Unspent,
+    Locked,
+    Spent,
+}
+
+impl StatusFilter {
+    pub const ALL: [StatusFilter; 5] = [
+        StatusFilter::All,
+        StatusFilter::Unconfirmed,
+        StatusFilter::Unspent,
+        StatusFilter::Locked,
+        StatusFilter::Spent,
+    ];
+
+    fn matches(self, status: &OutputStatus) -> bool {
+        match self {
+            StatusFilter::All => true,
+            StatusFilter::Unconfirmed => *status == OutputStatus::Unconfirmed,
+            StatusFilter::Unspent => *status == OutputStatus::Unspent,
+            StatusFilter::Locked => *status == OutputStatus::Locked,
+            StatusFilter::Spent => *status == OutputStatus::Spent,
+        }
+    }
+}
+
+impl std::fmt::Display for StatusFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            StatusFilter::All => localized_string("output-status-all"),
+            StatusFilter::Unconfirmed => localized_string("output-unconfirmed"),
+            StatusFilter::Unspent => localized_string("output-unspent"),
+            StatusFilter::Locked => localized_string("output-locked"),
+            StatusFilter::Spent => localized_string("output-spent"),
+        };
+        write!(f, "{}", s)
+    }
+}
+
+pub fn display_status(status: &OutputStatus) -> String {
+    match status {
+        OutputStatus::Unconfirmed => localized_string("output-unconfirmed"),
+        OutputStatus::Unspent => localized_string("output-unspent"),
+        OutputStatus::Locked => localized_string("output-locked"),
+        OutputStatus::Spent => localized_string("output-spent"),
+        #[allow(unreachable_patterns)]
+        s => s.to_string(),
+    }
+}
+
+pub struct StateContainer {
+    pub outputs: Vec<OutputCommitMapping>,
+    pub status_filter: StatusFilter,
+    pub query: String,
+    pub sort_column: Option<ColumnKey>,
+    pub sort_direction: Option<SortDirection>,
+    header_state: header::State,
+    columns: Vec<ColumnState>,
+}
+
+impl Default for StateContainer {
+    fn default() -> Self {
+        Self {
+            outputs: vec![],
+            status_filter: StatusFilter::All,
+            query: Default::default(),
+            sort_column: None,
+            sort_direction: None,
+            header_state: Default::default(),
+            columns: vec![
+                ColumnState {
+                    key: ColumnKey::Commitment,
+                    width: Length::Units(200),
+                },
+                ColumnState {
+                    key: ColumnKey::Value,
+                    width: Length::Units(110),
+                },
+                ColumnState {
+                    key: ColumnKey::Height,
+                    width: Length::Units(85),
+                },
+                ColumnState {
+                    key: ColumnKey::LockHeight,
+                    width: Length::Units(85),
+                },
+                ColumnState {
+                    key: ColumnKey::Status,
+                    width: Length::Units(105),
+                },
+                ColumnState {
+                    key: ColumnKey::IsCoinbase,
+                    width: Length::Units(85),
+                },
+                ColumnState {
+                    key: ColumnKey::TxLogId,
+                    width: Length::Units(85),
+                },
+            ],
+        }
+    }
+}
+
+impl StateContainer {
+    /// Outputs passing the status filter and query, in the selected sort order
+    pub fn visible_outputs(&self) -> Vec<&OutputCommitMapping> {
+        let query = self.query.trim().to_lowercase();
+        let mut outputs: Vec<&OutputCommitMapping> = self
+            .outputs
+            .iter()
+            .filter(|o| self.status_filter.matches(&o.output.status))
+            .filter(|o| {
+                query.is_empty()
+                    || output_commit_hex(o).contains(&query)
+                    || o.output.tx_log_entry.map(|id| id.to_string()) == Some(query.clone())
+            })
+            .collect();
+
+        if let Some(key) = self.sort_column {
+            outputs.sort_by(|a_map, b_map| {
+                let (a, b) = (&a_map.output, &b_map.output);
+                match key {
+                    ColumnKey::Commitment => {
+                        output_commit_hex(a_map).cmp(&output_commit_hex(b_map))
+                    }
+                    ColumnKey::Value => a.value.cmp(&b.value),
+                    ColumnKey::Height => a.height.cmp(&b.height),
+                    ColumnKey::LockHeight => a.lock_height.cmp(&b.lock_height),
+                    ColumnKey::Status => a.status.to_string().cmp(&b.status.to_string()),
+                    ColumnKey::IsCoinbase => a.is_coinbase.cmp(&b.is_coinbase),
+                    ColumnKey::TxLogId => a.tx_log_entry.cmp(&b.tx_log_entry),
+                }
+            });
+            if self.sort_direction == Some(SortDirection::Desc) {
+                outputs.reverse();
+            }
+        }
+
+        outputs
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+    Back,
+    Refresh,
+    OutputsUpdateSuccess(bool, Vec<OutputCommitMapping>),
+    OutputsUpdateFailure(Arc<RwLock<Option<anyhow::Error>>>),
+    SortColumn(ColumnKey),
+    SelectStatusFilter(StatusFilter),
+    Query(String),
+}
+
+pub fn handle_message<'a>(
+    grin_gui: &mut GrinGui,
+    message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+    let state = &mut grin_gui.wallet_state.operation_state.outputs_state;
+
+    match message {
+        LocalViewInteraction::Back => {
+            log::debug!("Interaction::WalletOperationOutputsViewInteraction(Back)");
+            grin_gui.wallet_state.operation_state.mode =
+                crate::gui::element::wallet::operation::Mode::Home;
+        }
+        LocalViewInteraction::Refresh => {
+            // Spent outputs are fetched as well, the status filter decides what's shown
+            let fut = grin_gui.wallet_interface.run(|w| w.get_outputs(true));
+            return Ok(Command::perform(fut, |r| {
+                match r.context("Failed to retrieve wallet outputs") {
+                    Ok((node_success, outputs)) => {
+                        Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+                            LocalViewInteraction::OutputsUpdateSuccess(node_success, outputs),
+                        ))
+                    }
+                    Err(e) => {
+                        Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+                            LocalViewInteraction::OutputsUpdateFailure(Arc::new(RwLock::new(
+                                Some(e),
+                            ))),
+                        ))
+                    }
+                }
+            }));
+        }
+        LocalViewInteraction::OutputsUpdateSuccess(node_success, outputs) => {
+            debug!("Update Outputs: {}, {} outputs", node_success, outputs.len());
+            state.outputs = outputs;
+        }
+        LocalViewInteraction::OutputsUpdateFailure(err) => {
+            grin_gui.error = err.write().unwrap().take();
+            if let Some(e) = grin_gui.error.as_ref() {
+                log_error(e);
+            }
+        }
+        LocalViewInteraction::SortColumn(key) => {
+            state.sort_direction = if state.sort_column == Some(key) {
+                state.sort_direction.map(SortDirection::toggle)
+            } else {
+                Some(SortDirection::Asc)
+            };
+            state.sort_column = Some(key);
+        }
+        LocalViewInteraction::SelectStatusFilter(filter) => {
+            state.status_filter = filter;
+        }
+        LocalViewInteraction::Query(query) => {
+            state.query = query;
+        }
+    }
+
+    Ok(Command::none())
+}
+
+fn titles_row_header<'a>(state: &'a StateContainer) -> Header<'a, Message> {
+    let mut row_titles = vec![];
+
+    for column in state.columns.iter() {
+        let column_key = column.key;
+
+        let row_title = row_title(
+            column_key,
+            state.sort_column,
+            state.sort_direction,
+            &column_key.title(),
+        );
+
+        let mut row_header = Button::new(
+            Text::new(row_title)
+                .size(DEFAULT_FONT_SIZE)
+                .width(Length::Fill),
+        )
+        .width(Length::Fill)
+        .on_press(Interaction::WalletOperationOutputsViewInteraction(
+            LocalViewInteraction::SortColumn(column_key),
+        ));
+
+        if state.sort_column == Some(column_key) {
+            row_header = row_header.style(grin_gui_core::theme::ButtonStyle::SelectedColumn);
+        } else {
+            row_header = row_header.style(grin_gui_core::theme::ButtonStyle::ColumnHeader);
+        }
+
+        let row_header: Element<Interaction> = row_header.into();
+
+        let row_container = Container::new(row_header.map(Message::Interaction))
+            .width(column.width)
+            .style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+        row_titles.push((column_key.as_string(), row_container));
+    }
+
+    Header::new(state.header_state.clone(), row_titles, None, None)
+        .spacing(1)
+        .height(Length::Units(25))
+}
+
+fn data_row_container<'a>(
+    output: &OutputCommitMapping,
+    columns: &[ColumnState],
+    is_odd: Option<bool>,
+) -> Container<'a, Message> {
+    let default_height = Length::Units(26);
+
+    let mut row = Row::new().spacing(1);
+
+    for column in columns.iter() {
+        let o = &output.output;
+        let value = match column.key {
+            ColumnKey::Commitment => output_commit_hex(output),
+            ColumnKey::Value => amount_to_hr_string(o.value, true),
+            ColumnKey::Height => o.height.to_string(),
+            ColumnKey::LockHeight => o.lock_height.to_string(),
+            ColumnKey::Status => display_status(&o.status),
+            ColumnKey::IsCoinbase => {
+                if o.is_coinbase {
+                    localized_string("yes")
+                } else {
+                    localized_string("no")
+                }
+            }
+            ColumnKey::TxLogId => o
+                .tx_log_entry
+                .map(|id| id.to_string())
+                .unwrap_or_else(|| "-".to_owned()),
+        };
+
+        let cell = Container::new(Text::new(value).size(DEFAULT_FONT_SIZE))
+            .padding(5)
+            .height(default_height)
+            .width(column.width)
+            .center_y()
+            .style(grin_gui_core::theme::ContainerStyle::HoverableForeground);
+
+        row = row.push(cell);
+    }
+
+    let mut table_row = TableRow::new(row)
+        .width(Length::Fill)
+        .inner_row_height(26);
+
+    if is_odd == Some(true) {
+        table_row = table_row.style(grin_gui_core::theme::TableRowStyle::TableRowAlternate)
+    } else {
+        table_row = table_row.style(grin_gui_core::theme::TableRowStyle::Default)
+    }
+
+    Container::new(table_row)
+}
+
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+    let button_height = Length::Units(BUTTON_HEIGHT);
+    let button_width = Length::Units(BUTTON_WIDTH);
+
+    // Title row
+    let title = Text::new(localized_string("outputs")).size(DEFAULT_HEADER_FONT_SIZE);
+    let title_container = Container::new(title)
+        .style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+        .padding(iced::Padding::from([
+            2, // top
+            0, // right
+            2, // bottom
+            5, // left
+        ]));
+
+    let status_pick_list = PickList::new(
+        &StatusFilter::ALL[..],
+        Some(state.status_filter),
+        |f| {
+            Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+                LocalViewInteraction::SelectStatusFilter(f),
+            ))
+        },
+    )
+    .text_size(SMALLER_FONT_SIZE)
+    .width(Length::Units(120))
+    .style(grin_gui_core::theme::PickListStyle::Primary);
+
+    let query_input = TextInput::new(&localized_string("output-filter"), &state.query, |s| {
+        Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+            LocalViewInteraction::Query(s),
+        ))
+    })
+    .size(SMALLER_FONT_SIZE)
+    .padding(6)
+    .width(Length::Units(200))
+    .style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+
+    let refresh_container =
+        Container::new(Text::new(localized_string("refresh")).size(DEFAULT_FONT_SIZE))
+            .width(button_width)
+            .align_y(alignment::Vertical::Center)
+            .align_x(alignment::Horizontal::Center);
+
+    let refresh_button: Element<Interaction> = Button::new(refresh_container)
+        .width(button_width)
+        .style(grin_gui_core::theme::ButtonStyle::Primary)
+        .on_press(Interaction::WalletOperationOutputsViewInteraction(
+            LocalViewInteraction::Refresh,
+        ))
+        .into();
+
+    let refresh_container_wrap =
+        Container::new(refresh_button.map(Message::Interaction)).padding(1);
+    let refresh_container_wrap = Container::new(refresh_container_wrap)
+        .style(grin_gui_core::theme::ContainerStyle::Segmented)
+        .padding(1);
+
+    let header_row = Row::new()
+        .push(title_container)
+        .push(Space::with_width(Length::Fill))
+        .push(query_input)
+        .push(Space::with_width(Length::Units(DEFAULT_PADDING)))
+        .push(status_pick_list)
+        .push(Space::with_width(Length::Units(DEFAULT_PADDING)))
+        .push(refresh_container_wrap)
+        .align_items(Alignment::Center);
+
+    let header_container = Container::new(header_row).padding(iced::Padding::from([
+        0,               // top
+        0,               // right
+        DEFAULT_PADDING, // bottom
+        0,               // left
+    ]));
+
+    let table_header_container =
+        Container::new(titles_row_header(state)).padding(iced::Padding::from([
+            0,               // top
+            DEFAULT_PADDING, // right - should roughly match width of content scroll bar to align table headers
+            0,               // bottom
+            0,               // left
+        ]));
+
+    let mut content = Column::new().spacing(1);
+    for (idx, output) in state.visible_outputs().into_iter().enumerate() {
+        let is_odd = if config.alternating_row_colors {
+            Some(idx % 2 != 0)
+        } else {
+            None
+        };
+        content = content.push(data_row_container(output, &state.columns, is_odd));
+    }
+
+    let outputs_scrollable = Scrollable::new(content)
+        .height(Length::Fill)
+        .style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+    let table_column = Column::new()
+        .push(table_header_container)
+        .push(outputs_scrollable);
+
+    let table_container = Container::new(table_column)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .style(grin_gui_core::theme::ContainerStyle::PanelBordered)
+        .padding(DEFAULT_PADDING);
+
+    let back_button_label_container =
+        Container::new(Text::new(localized_string("back")).size(DEFAULT_FONT_SIZE))
+            .width(button_width)
+            .height(button_height)
+            .center_x()
+            .center_y()
+            .align_x(alignment::Horizontal::Center);
+
+    let back_button: Element<Interaction> = Button::new(back_button_label_container)
+        .style(grin_gui_core::theme::ButtonStyle::Primary)
+        .on_press(Interaction::WalletOperationOutputsViewInteraction(
+            LocalViewInteraction::Back,
+        ))
+        .into();
+
+    let back_container = Container::new(back_button.map(Message::Interaction)).padding(1);
+    let back_container = Container::new(back_container)
+        .style(grin_gui_core::theme::ContainerStyle::Segmented)
+        .padding(1);
+
+    let wrapper_column = Column::new()
+        .height(Length::Fill)
+        .push(header_container)
+        .push(table_container)
+        .push(Space::with_height(Length::Units(DEFAULT_PADDING)))
+        .push(back_container);
+
+    // Returns the final container.
+    Container::new(wrapper_column).padding(iced::Padding::from([
+        DEFAULT_PADDING, // top
+        DEFAULT_PADDING, // right
+        DEFAULT_PADDING, // bottom
+        DEFAULT_PADDING, // left
+    ]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use grin_gui_core::wallet::MockWallet;
+
+    fn state() -> StateContainer {
+        let mut state = StateContainer::default();
+        state.outputs = vec![
+            MockWallet::output(1, 30, OutputStatus::Unspent, Some(0)),
+            MockWallet::output(2, 10, OutputStatus::Locked, Some(7)),
+            MockWallet::output(3, 20, OutputStatus::Unspent, None),
+        ];
+        state
+    }
+
+    fn values(state: &StateContainer) -> Vec<u64> {
+        state
+            .visible_outputs()
+            .iter()
+            .map(|o| o.output.value)
+            .collect()
+    }
+
+    #[test]
+    fn test_outputs_sort_by_value() {
+        let mut state = state();
+        state.sort_column = Some(ColumnKey::Value);
+        state.sort_direction = Some(SortDirection::Asc);
+        assert_eq!(values(&state), vec![10, 20, 30]);
+
+        state.sort_direction = Some(SortDirection::Desc);
+        assert_eq!(values(&state), vec![30, 20, 10]);
+    }
+
+    #[test]
+    fn test_outputs_filter_by_status_and_query() {
+        let mut state = state();
+        state.status_filter = StatusFilter::Unspent;
+        assert_eq!(values(&state), vec![30, 20]);
+
+        state.status_filter = StatusFilter::All;
+        state.query = "7".to_owned();
+        assert_eq!(values(&state), vec![10]);
+
+        // Commitments of mock output 3 are all 0x03 bytes
+        state.query = "0303".to_owned();
+        assert_eq!(values(&state), vec![20]);
+    }
+}
@@ -0,0 +1,562 @@
+use crate::log_error;
+use grin_gui_core::{config::Config, error::GrinWalletInterfaceError};
+
+use {
+    super::super::super::{
+        BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+        SMALLER_FONT_SIZE,
+    },
+    crate::gui::{GrinGui, Interaction, Message},
+    crate::localization::localized_string,
+    crate::Result,
+    grin_gui_core::theme::{
+        Button, Column, Container, ContainerStyle, Element, ProgressBar, Row, Scrollable, Text,
+        TextInput,
+    },
+    grin_gui_core::wallet::{CancelToken, ScanSummary},
+    iced::widget::Space,
+    iced::{alignment, Command, Length},
+    std::sync::{Arc, RwLock},
+    std::time::Duration,
+};
+
+/// A scan from genesis can take hours on a large wallet
+const SCAN_JOB_TIMEOUT: Duration = Duration::from_secs(6 * 60 * 60);
+
+#[derive(Default)]
+pub struct StateContainer {
+    // Blank scans from genesis
+    pub start_height_value: String,
+    start_height_error: bool,
+    // Set while a scan is running
+    cancel_token: Option<CancelToken>,
+    // Cancel was asked for, but the scan hasn't returned yet
+    cancelling: bool,
+    // Percentage reported by the updater for the running scan
+    pub progress: Option<u8>,
+    pub summary: Option<ScanSummary>,
+    pub cancelled: bool,
+}
+
+impl StateContainer {
+    pub fn is_scanning(&self) -> bool {
+        self.cancel_token.is_some()
+    }
+
+    pub fn is_cancelling(&self) -> bool {
+        self.cancelling
+    }
+
+    /// Whether the running scan has got past the point it can be stopped
+    pub fn is_past_cancelling(&self) -> bool {
+        self.cancel_token.as_ref().map_or(false, |t| t.is_started())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+    Back,
+    StartHeight(String),
+    StartScan,
+    CancelScan,
+    ScanComplete(ScanSummary),
+    ScanCancelled,
+    ScanError(Arc<RwLock<Option<anyhow::Error>>>),
+}
+
+pub fn handle_message(
+    grin_gui: &mut GrinGui,
+    message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+    let state = &mut grin_gui.wallet_state.operation_state.scan_state;
+
+    match message {
+        LocalViewInteraction::Back => {
+            log::debug!("Interaction::WalletOperationScanViewInteraction(Back)");
+            state.summary = None;
+            state.cancelled = false;
+            grin_gui.wallet_state.operation_state.mode =
+                crate::gui::element::wallet::operation::Mode::Home;
+        }
+        LocalViewInteraction::StartHeight(s) => {
+            state.start_height_value = s;
+        }
+        LocalViewInteraction::StartScan => {
+            log::debug!("Interaction::WalletOperationScanViewInteraction(StartScan)");
+            // Only one scan at a time, even while a cancelled one winds down
+            if state.is_scanning() {
+                return Ok(Command::none());
+            }
+            grin_gui.error.take();
+            state.start_height_error = false;
+            state.summary = None;
+            state.cancelled = false;
+
+            let start_height = match state.start_height_value.trim() {
+                "" => None,
+                s => match s.parse::<u64>() {
+                    Ok(h) => Some(h),
+                    Err(_) => {
+                        state.start_height_error = true;
+                        return Ok(Command::none());
+                    }
+                },
+            };
+
+            let cancel_token = CancelToken::new();
+            state.cancel_token = Some(cancel_token.clone());
+            state.progress = Some(0);
+
+            let job_token = cancel_token.clone();
+            let fut = grin_gui.wallet_interface.run_cancellable(
+                move |w| {
+                    let (_, before) = w.get_outputs(true)?;
+                    // The scan itself can't be interrupted, so cancelling ends here
+                    if !job_token.start() {
+                        return Err(GrinWalletInterfaceError::WalletWorkerCancelled);
+                    }
+                    w.scan(start_height, false)?;
+                    let (_, after) = w.get_outputs(true)?;
+                    Ok(ScanSummary::between(&before, &after))
+                },
+                SCAN_JOB_TIMEOUT,
+                cancel_token,
+            );
+
+            return Ok(Command::perform(fut, |r| match r {
+                Ok(summary) => {
+                    Message::Interaction(Interaction::WalletOperationScanViewInteraction(
+                        LocalViewInteraction::ScanComplete(summary),
+                    ))
+                }
+                Err(GrinWalletInterfaceError::WalletWorkerCancelled) => {
+                    Message::Interaction(Interaction::WalletOperationScanViewInteraction(
+                        LocalViewInteraction::ScanCancelled,
+                    ))
+                }
+                Err(e) => Message::Interaction(Interaction::WalletOperationScanViewInteraction(
+                    LocalViewInteraction::ScanError(Arc::new(RwLock::new(Some(
+                        anyhow::Error::from(e),
+                    )))),
+                )),
+            }));
+        }
+        LocalViewInteraction::CancelScan => {
+            log::debug!("Interaction::WalletOperationScanViewInteraction(CancelScan)");
+            // Refused once the scan is under way
+            if let Some(token) = state.cancel_token.as_ref() {
+                state.cancelling = token.cancel();
+            }
+        }
+        // A scan already under way when cancelled still finishes, so still gets a summary
+        LocalViewInteraction::ScanComplete(summary) => {
+            state.cancel_token = None;
+            state.cancelling = false;
+            state.progress = None;
+            state.summary = Some(summary);
+        }
+        LocalViewInteraction::ScanCancelled => {
+            state.cancel_token = None;
+            state.cancelling = false;
+            state.progress = None;
+            state.cancelled = true;
+        }
+        LocalViewInteraction::ScanError(err) => {
+            state.cancel_token = None;
+            state.cancelling = false;
+            state.progress = None;
+            grin_gui.error = err.write().unwrap().take();
+            if let Some(e) = grin_gui.error.as_ref() {
+                log_error(e);
+            }
+        }
+    }
+
+    Ok(Command::none())
+}
+
+fn summary_row<'a>(label: String, value: usize) -> Row<'a, Message> {
+    let label = Container::new(Text::new(label).size(DEFAULT_FONT_SIZE))
+        .width(Length::Units(160))
+        .style(ContainerStyle::NormalBackground);
+    let value = Container::new(Text::new(value.to_string()).size(DEFAULT_FONT_SIZE))
+        .style(ContainerStyle::NormalBackground);
+
+    Row::new().push(label).push(value)
+}
+
+pub fn data_container<'a>(
+    _config: &'a Config,
+    state: &'a StateContainer,
+) -> Container<'a, Message> {
+    let unit_spacing = 15;
+
+    // Title row
+    let title = Text::new(localized_string("scan-wallet"))
+        .size(DEFAULT_HEADER_FONT_SIZE)
+        .horizontal_alignment(alignment::Horizontal::Center);
+
+    let title_container = Container::new(title)
+        .style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+        .padding(iced::Padding::from([
+            2, // top
+            0, // right
+            2, // bottom
+            5, // left
+        ]));
+
+    let header_row = Row::new().push(title_container);
+
+    let header_container = Container::new(header_row).padding(iced::Padding::from([
+        0,               // top
+        0,               // right
+        DEFAULT_PADDING, // bottom
+        0,               // left
+    ]));
+
+    let description = Text::new(localized_string("scan-wallet-desc"))
+        .size(DEFAULT_FONT_SIZE)
+        .horizontal_alignment(alignment::Horizontal::Left);
+
+    let description_container = Container::new(description).style(ContainerStyle::NormalBackground);
+
+    let start_height = Text::new(localized_string("scan-start-height"))
+        .size(DEFAULT_FONT_SIZE)
+        .horizontal_alignment(alignment::Horizontal::Left);
+
+    let start_height_container =
+        Container::new(start_height).style(ContainerStyle::NormalBackground);
+
+    let start_height_instruction = Text::new(localized_string("scan-start-height-instruction"))
+        .size(SMALLER_FONT_SIZE)
+        .horizontal_alignment(alignment::Horizontal::Left);
+
+    let start_height_instruction_container =
+        Container::new(start_height_instruction).style(ContainerStyle::NormalBackground);
+
+    let start_height_input: Element<Interaction> =
+        TextInput::new("", &state.start_height_value, |s| {
+            Interaction::WalletOperationScanViewInteraction(LocalViewInteraction::StartHeight(s))
+        })
+        .size(DEFAULT_FONT_SIZE)
+        .padding(6)
+        .width(Length::Units(100))
+        .style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+        .into();
+
+    let mut column = Column::new()
+        .push(description_container)
+        .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
+        .push(start_height_container)
+        .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
+        .push(start_height_instruction_container)
+        .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
+        .push(start_height_input.map(Message::Interaction))
+        .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+
+    if state.start_height_error {
+        let start_height_error = Text::new(localized_string("scan-start-height-error"))
+            .size(DEFAULT_FONT_SIZE)
+            .horizontal_alignment(alignment::Horizontal::Left)
+            .style(grin_gui_core::theme::text::TextStyle::Warning);
+
+        column = column
+            .push(Container::new(start_height_error).style(ContainerStyle::NormalBackground))
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+    }
+
+    if let Some(pct) = state.progress {
+        let progress_text = Text::new(format!("{} - {}%", localized_string("scan-progress"), pct))
+            .size(DEFAULT_FONT_SIZE);
+        let progress_bar = ProgressBar::new(0.0..=100.0, pct as f32)
+            .height(Length::Units(8))
+            .width(Length::Units(400))
+            .style(grin_gui_core::theme::ProgressBarStyle::Normal);
+
+        column = column
+            .push(Container::new(progress_text).style(ContainerStyle::NormalBackground))
+            .push(Space::new(Length::Units(0), Length::Units(5)))
+            .push(progress_bar)
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+    }
+
+    if state.cancelled {
+        let cancelled = Text::new(localized_string("scan-cancelled"))
+            .size(DEFAULT_FONT_SIZE)
+            .style(grin_gui_core::theme::text::TextStyle::Warning);
+
+        column = column
+            .push(Container::new(cancelled).style(ContainerStyle::NormalBackground))
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+    }
+
+    if let Some(summary) = state.summary.as_ref() {
+        let summary_title = Text::new(localized_string("scan-summary"))
+            .size(DEFAULT_FONT_SIZE)
+            .horizontal_alignment(alignment::Horizontal::Left);
+
+        column = column
+            .push(Container::new(summary_title).style(ContainerStyle::NormalBackground))
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
+            .push(summary_row(
+                localized_string("scan-outputs-before"),
+                summary.outputs_before,
+            ))
+            .push(summary_row(
+                localized_string("scan-outputs-after"),
+                summary.outputs_after,
+            ))
+            .push(summary_row(
+                localized_string("scan-outputs-restored"),
+                summary.restored,
+            ))
+            .push(summary_row(
+                localized_string("scan-outputs-deleted"),
+                summary.deleted,
+            ))
+            .push(summary_row(
+                localized_string("scan-outputs-unlocked"),
+                summary.unlocked,
+            ))
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+    }
+
+    let button_height = Length::Units(BUTTON_HEIGHT);
+    let button_width = Length::Units(BUTTON_WIDTH);
+
+    // While scanning, the only way out is to cancel, then wait for the scan to return.
+    // Once the scan itself is under way it can only be waited for.
+    let (submit_key, submit_interaction) = if state.is_cancelling() {
+        ("scan-cancelling", None)
+    } else if state.is_past_cancelling() {
+        let note = Text::new(localized_string("scan-cannot-cancel")).size(DEFAULT_FONT_SIZE);
+        column = column
+            .push(Container::new(note).style(ContainerStyle::NormalBackground))
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+        ("scan-cancel", None)
+    } else if state.is_scanning() {
+        ("scan-cancel", Some(LocalViewInteraction::CancelScan))
+    } else {
+        ("scan-start", Some(LocalViewInteraction::StartScan))
+    };
+
+    let submit_button_label_container =
+        Container::new(Text::new(localized_string(submit_key)).size(DEFAULT_FONT_SIZE))
+            .width(button_width)
+            .height(button_height)
+            .center_x()
+            .center_y()
+            .align_x(alignment::Horizontal::Center);
+
+    let mut submit_button = Button::new(submit_button_label_container)
+        .style(grin_gui_core::theme::ButtonStyle::Primary);
+    if let Some(interaction) = submit_interaction {
+        submit_button =
+            submit_button.on_press(Interaction::WalletOperationScanViewInteraction(interaction));
+    }
+    let submit_button: Element<Interaction> = submit_button.into();
+
+    let submit_container = Container::new(submit_button.map(Message::Interaction)).padding(1);
+    let submit_container = Container::new(submit_container)
+        .style(grin_gui_core::theme::ContainerStyle::Segmented)
+        .padding(1);
+
+    let mut button_row = Row::new().push(submit_container);
+
+    if !state.is_scanning() {
+        let back_button_label_container =
+            Container::new(Text::new(localized_string("back")).size(DEFAULT_FONT_SIZE))
+                .width(button_width)
+                .height(button_height)
+                .center_x()
+                .center_y()
+                .align_x(alignment::Horizontal::Center);
+
+        let back_button: Element<Interaction> = Button::new(back_button_label_container)
+            .style(grin_gui_core::theme::ButtonStyle::Primary)
+            .on_press(Interaction::WalletOperationScanViewInteraction(
+                LocalViewInteraction::Back,
+            ))
+            .into();
+
+        let back_container = Container::new(back_button.map(Message::Interaction)).padding(1);
+        let back_container = Container::new(back_container)
+            .style(grin_gui_core::theme::ContainerStyle::Segmented)
+            .padding(1);
+
+        button_row = button_row
+            .push(Space::new(Length::Units(unit_spacing), Length::Units(0)))
+            .push(back_container);
+    }
+
+    column = column.push(button_row).push(Space::new(
+        Length::Units(0),
+        Length::Units(unit_spacing + 10),
+    ));
+
+    let form_container = Container::new(column)
+        .width(Length::Fill)
+        .padding(iced::Padding::from([
+            0, // top
+            0, // right
+            0, // bottom
+            5, // left
+        ]));
+
+    // form container should be scrollable in tiny windows
+    let scrollable = Scrollable::new(form_container)
+        .height(Length::Fill)
+        .style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+    let content = Container::new(scrollable)
+        .width(Length::Fill)
+        .height(Length::Shrink)
+        .style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+    let wrapper_column = Column::new()
+        .height(Length::Fill)
+        .push(header_container)
+        .push(content);
+
+    // Returns the final container.
+    Container::new(wrapper_column).padding(iced::Padding::from([
+        DEFAULT_PADDING, // top
+        DEFAULT_PADDING, // right
+        DEFAULT_PADDING, // bottom
+        DEFAULT_PADDING, // left
+    ]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gui::test::{mock_gui, open_wallet, run, TestDir};
+    use crate::gui::update::handle_message;
+    use futures::executor::block_on;
+    use grin_gui_core::wallet::{MockWallet, OutputStatus};
+    use iced_native::command::Action;
+
+    fn scan_message(interaction: LocalViewInteraction) -> Message {
+        Message::Interaction(Interaction::WalletOperationScanViewInteraction(interaction))
+    }
+
+    #[test]
+    fn test_scan_reports_summary() {
+        let mut mock = MockWallet::new("password").with_received(5_000_000_000);
+        // Held by a send that never completed
+        mock.outputs[0].output.status = OutputStatus::Locked;
+        mock.info.amount_locked = 5_000_000_000;
+        mock.info.amount_currently_spendable = 0;
+        mock.scan_restores = vec![MockWallet::output(
+            7,
+            1_000_000_000,
+            OutputStatus::Unspent,
+            None,
+        )];
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, mock);
+        open_wallet(&mut grin_gui, "password");
+
+        run(
+            &mut grin_gui,
+            scan_message(LocalViewInteraction::StartHeight("abc".to_owned())),
+        );
+        run(&mut grin_gui, scan_message(LocalViewInteraction::StartScan));
+        assert!(!grin_gui
+            .wallet_state
+            .operation_state
+            .scan_state
+            .is_scanning());
+        assert!(grin_gui
+            .wallet_state
+            .operation_state
+            .scan_state
+            .summary
+            .is_none());
+
+        for interaction in [
+            LocalViewInteraction::StartHeight("".to_owned()),
+            LocalViewInteraction::StartScan,
+        ] {
+            run(&mut grin_gui, scan_message(interaction));
+        }
+        assert!(grin_gui.error.is_none());
+        let scan_state = &grin_gui.wallet_state.operation_state.scan_state;
+        assert!(!scan_state.is_scanning());
+        assert_eq!(
+            scan_state.summary,
+            Some(ScanSummary {
+                outputs_before: 1,
+                outputs_after: 2,
+                restored: 1,
+                deleted: 0,
+                unlocked: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_cancel_scan_waits_for_the_worker() {
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, MockWallet::new("password"));
+        open_wallet(&mut grin_gui, "password");
+
+        // Hold the worker busy so the scan is still queued when cancelled, and keep hold
+        // of the scan future instead of resolving it straight away
+        let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+        let busy = grin_gui.wallet_interface.run(move |_| {
+            let _ = release_rx.recv();
+            Ok(())
+        });
+        let command =
+            handle_message(&mut grin_gui, scan_message(LocalViewInteraction::StartScan)).unwrap();
+        run(
+            &mut grin_gui,
+            scan_message(LocalViewInteraction::CancelScan),
+        );
+        let scan_state = &grin_gui.wallet_state.operation_state.scan_state;
+        assert!(scan_state.is_scanning());
+        assert!(scan_state.is_cancelling());
+
+        // No second scan while the first is winding down
+        let command_again =
+            handle_message(&mut grin_gui, scan_message(LocalViewInteraction::StartScan)).unwrap();
+        assert!(command_again.actions().is_empty());
+
+        release_tx.send(()).unwrap();
+        block_on(busy).unwrap();
+        for action in command.actions() {
+            if let Action::Future(f) = action {
+                run(&mut grin_gui, block_on(f));
+            }
+        }
+        let scan_state = &grin_gui.wallet_state.operation_state.scan_state;
+        assert!(!scan_state.is_scanning());
+        assert!(!scan_state.is_cancelling());
+        assert!(scan_state.cancelled);
+        assert!(scan_state.summary.is_none());
+
+        // Once the scan itself has started, cancelling is refused and it runs to the end
+        let command =
+            handle_message(&mut grin_gui, scan_message(LocalViewInteraction::StartScan)).unwrap();
+        let mut results = vec![];
+        for action in command.actions() {
+            if let Action::Future(f) = action {
+                results.push(block_on(f));
+            }
+        }
+        run(
+            &mut grin_gui,
+            scan_message(LocalViewInteraction::CancelScan),
+        );
+        let scan_state = &grin_gui.wallet_state.operation_state.scan_state;
+        assert!(scan_state.is_past_cancelling());
+        assert!(!scan_state.is_cancelling());
+        for result in results {
+            run(&mut grin_gui, result);
+        }
+        let scan_state = &grin_gui.wallet_state.operation_state.scan_state;
+        assert!(!scan_state.cancelled);
+        assert!(scan_state.summary.is_some());
+    }
+}
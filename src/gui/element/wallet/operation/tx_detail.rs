@@ -0,0 +1,879 @@
+use crate::log_error;
+use grin_gui_core::config::{AddressBook, Config, SentSlatepacks};
+
+use {
+    super::super::super::{
+        BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+        SMALLER_FONT_SIZE,
+    },
+    super::tx_list::TxLogEntryWrap,
+    crate::gui::{GrinGui, Interaction, Message},
+    crate::localization::localized_string,
+    crate::Result,
+    anyhow::Context,
+    grin_gui_core::config::TxNote,
+    grin_gui_core::fs::PersistentData,
+    grin_gui_core::node::amount_to_hr_string,
+    grin_gui_core::theme::{
+        Button, Column, Container, ContainerStyle, Element, Row, Scrollable, Text, TextInput,
+    },
+    grin_gui_core::wallet::{
+        confirmations, tx_can_repost, tx_can_resend_slatepack, tx_confirmed_height,
+        tx_counterparty_address, tx_kernel_excess_hex, StoredTxSummary, TxLogEntry, TxLogEntryType,
+    },
+    iced::widget::{Checkbox, Space},
+    iced::{alignment, Alignment, Command, Length},
+    std::sync::{Arc, RwLock},
+};
+
+#[derive(Default)]
+pub struct StateContainer {
+    pub tx: Option<TxLogEntry>,
+    // note and comma separated tags being edited
+    pub note_value: String,
+    pub tags_value: String,
+    // filled in by the wallet after opening
+    pub stored_tx: Option<StoredTxSummary>,
+    pub confirmed_height: Option<u64>,
+    pub account: Option<String>,
+    pub is_loading: bool,
+    // post reposts straight to the network rather than stemming them
+    pub repost_fluff: bool,
+    pub reposted: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+    Back,
+    Note(String),
+    Tags(String),
+    SaveNote,
+    DetailsLoaded(u32, Option<StoredTxSummary>, Option<u64>, Option<String>),
+    DetailsLoadError(Arc<RwLock<Option<anyhow::Error>>>),
+    CancelTx,
+    TxCancelledOk(Option<TxLogEntry>),
+    TxCancelError(Arc<RwLock<Option<anyhow::Error>>>),
+    RepostFluff(bool),
+    RepostTx,
+    TxRepostedOk,
+    TxRepostError(Arc<RwLock<Option<anyhow::Error>>>),
+}
+
+/// Shows `tx_wrap`, loading its local note into the editor and its stored transaction and
+/// confirmation height from the wallet
+pub fn open(grin_gui: &mut GrinGui, tx_wrap: TxLogEntryWrap) -> Command<Message> {
+    let state = &mut grin_gui.wallet_state.operation_state.tx_detail_state;
+    let note = tx_wrap
+        .tx
+        .tx_slate_id
+        .and_then(|id| grin_gui.tx_notes.get(&id.to_string()).cloned())
+        .unwrap_or_default();
+    state.note_value = note.text.clone();
+    state.tags_value = note.tags_string();
+    state.stored_tx = None;
+    state.confirmed_height = None;
+    state.account = None;
+    state.is_loading = true;
+    state.reposted = false;
+    state.tx = Some(tx_wrap.tx.clone());
+    grin_gui.wallet_state.operation_state.mode =
+        crate::gui::element::wallet::operation::Mode::TxDetail;
+
+    let tx = tx_wrap.tx;
+    let id = tx.id;
+    let fut = grin_gui.wallet_interface.run(move |w| {
+        let stored_tx = w.get_stored_tx(tx.id)?;
+        let (_, outputs) = w.get_outputs(true)?;
+        let account = w
+            .accounts()?
+            .into_iter()
+            .find(|a| a.path == tx.parent_key_id)
+            .map(|a| a.label);
+        Ok((
+            stored_tx.as_ref().and_then(StoredTxSummary::from_slate),
+            tx_confirmed_height(w, &tx, &outputs),
+            account,
+        ))
+    });
+
+    Command::perform(fut, move |r| {
+        match r.context("Failed to load transaction details") {
+            Ok((stored_tx, height, account)) => {
+                Message::Interaction(Interaction::WalletOperationTxDetailViewInteraction(
+                    LocalViewInteraction::DetailsLoaded(id, stored_tx, height, account),
+                ))
+            }
+            Err(e) => Message::Interaction(Interaction::WalletOperationTxDetailViewInteraction(
+                LocalViewInteraction::DetailsLoadError(Arc::new(RwLock::new(Some(e)))),
+            )),
+        }
+    })
+}
+
+pub fn handle_message(
+    grin_gui: &mut GrinGui,
+    message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+    let state = &mut grin_gui.wallet_state.operation_state.tx_detail_state;
+
+    match message {
+        LocalViewInteraction::Back => {
+            log::debug!("Interaction::WalletOperationTxDetailViewInteraction(Back)");
+            grin_gui.wallet_state.operation_state.mode =
+                crate::gui::element::wallet::operation::Mode::Home;
+        }
+        LocalViewInteraction::Note(s) => state.note_value = s,
+        LocalViewInteraction::Tags(s) => state.tags_value = s,
+        LocalViewInteraction::SaveNote => {
+            log::debug!("Interaction::WalletOperationTxDetailViewInteraction(SaveNote)");
+            grin_gui.error.take();
+
+            let slate_id = match state.tx.as_ref().and_then(|tx| tx.tx_slate_id) {
+                Some(id) => id.to_string(),
+                None => return Ok(Command::none()),
+            };
+            let note = TxNote::new(&state.note_value, &state.tags_value);
+            state.note_value = note.text.clone();
+            state.tags_value = note.tags_string();
+
+            grin_gui.tx_notes.set(slate_id, note);
+            if let Err(e) = grin_gui
+                .tx_notes
+                .save()
+                .context("Failed to save transaction note")
+            {
+                log_error(&e);
+                grin_gui.error = Some(e);
+            }
+        }
+        LocalViewInteraction::DetailsLoaded(id, stored_tx, confirmed_height, account) => {
+            // Ignore details arriving after another transaction was opened
+            if state.tx.as_ref().map(|tx| tx.id) == Some(id) {
+                state.stored_tx = stored_tx;
+                state.confirmed_height = confirmed_height;
+                state.account = account;
+                state.is_loading = false;
+            }
+        }
+        LocalViewInteraction::DetailsLoadError(err) => {
+            state.is_loading = false;
+            grin_gui.error = err.write().unwrap().take();
+            if let Some(e) = grin_gui.error.as_ref() {
+                log_error(e);
+            }
+        }
+        LocalViewInteraction::CancelTx => {
+            log::debug!("Interaction::WalletOperationTxDetailViewInteraction(CancelTx)");
+            grin_gui.error.take();
+
+            let id = match state.tx.as_ref() {
+                Some(tx) => tx.id,
+                None => return Ok(Command::none()),
+            };
+            let fut = grin_gui.wallet_interface.run(move |w| {
+                w.cancel_tx(id)?;
+                let (_, txs) = w.get_txs(None)?;
+                Ok(txs.into_iter().find(|tx| tx.id == id))
+            });
+
+            return Ok(Command::perform(fut, |r| {
+                match r.context("Failed to Cancel Transaction") {
+                    Ok(tx) => {
+                        Message::Interaction(Interaction::WalletOperationTxDetailViewInteraction(
+                            LocalViewInteraction::TxCancelledOk(tx),
+                        ))
+                    }
+                    Err(e) => {
+                        Message::Interaction(Interaction::WalletOperationTxDetailViewInteraction(
+                            LocalViewInteraction::TxCancelError(Arc::new(RwLock::new(Some(e)))),
+                        ))
+                    }
+                }
+            }));
+        }
+        LocalViewInteraction::TxCancelledOk(tx) => {
+            let id = state.tx.as_ref().map(|tx| tx.id).unwrap_or_default();
+            let slate_id = state.tx.as_ref().and_then(|tx| tx.tx_slate_id);
+            if tx.is_some() {
+                state.tx = tx;
+            }
+            if let Some(slate_id) = slate_id {
+                super::forget_slatepacks(grin_gui, &[slate_id.to_string()]);
+            }
+            // Let home reload the transaction list
+            return Ok(Command::perform(async {}, move |_| {
+                Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+                    super::home::LocalViewInteraction::TxCancelledOk(id),
+                ))
+            }));
+        }
+        LocalViewInteraction::TxCancelError(err) => {
+            grin_gui.error = err.write().unwrap().take();
+            if let Some(e) = grin_gui.error.as_ref() {
+                log_error(e);
+            }
+        }
+        LocalViewInteraction::RepostFluff(b) => state.repost_fluff = b,
+        LocalViewInteraction::RepostTx => {
+            log::debug!("Interaction::WalletOperationTxDetailViewInteraction(RepostTx)");
+            grin_gui.error.take();
+            state.reposted = false;
+
+            let id = match state.tx.as_ref() {
+                Some(tx) => tx.id,
+                None => return Ok(Command::none()),
+            };
+            let fluff = state.repost_fluff;
+            let fut = grin_gui
+                .wallet_interface
+                .run(move |w| w.repost_tx(id, fluff));
+
+            return Ok(Command::perform(fut, |r| {
+                match r.context("Failed to repost transaction") {
+                    Ok(()) => {
+                        Message::Interaction(Interaction::WalletOperationTxDetailViewInteraction(
+                            LocalViewInteraction::TxRepostedOk,
+                        ))
+                    }
+                    Err(e) => {
+                        Message::Interaction(Interaction::WalletOperationTxDetailViewInteraction(
+                            LocalViewInteraction::TxRepostError(Arc::new(RwLock::new(Some(e)))),
+                        ))
+                    }
+                }
+            }));
+        }
+        LocalViewInteraction::TxRepostedOk => state.reposted = true,
+        LocalViewInteraction::TxRepostError(err) => {
+            grin_gui.error = err.write().unwrap().take();
+            if let Some(e) = grin_gui.error.as_ref() {
+                log_error(e);
+            }
+        }
+    }
+
+    Ok(Command::none())
+}
+
+fn amount_or_none(amount: Option<u64>) -> String {
+    match amount {
+        Some(a) => amount_to_hr_string(a, true),
+        None => localized_string("none"),
+    }
+}
+
+/// Every field of the transaction as (label, value) pairs, in display order
+fn tx_fields(
+    tx: &TxLogEntry,
+    state: &StateContainer,
+    address_book: &AddressBook,
+    tip_height: Option<u64>,
+) -> Vec<(String, String)> {
+    let none = || localized_string("none");
+    let yes_no = |b: bool| localized_string(if b { "yes" } else { "no" });
+
+    let shared_id = match tx.tx_slate_id {
+        Some(id) => id.to_string(),
+        None => none(),
+    };
+    let status = match tx.tx_type {
+        TxLogEntryType::TxSentCancelled | TxLogEntryType::TxReceivedCancelled => {
+            localized_string("tx-cancelled")
+        }
+        // The wallet says confirmed, but the node can't place it
+        _ if tx.confirmed && !state.is_loading && state.confirmed_height.is_none() => {
+            localized_string("tx-confirmation-unknown")
+        }
+        _ if tx.confirmed => localized_string("tx-confirmed"),
+        _ => localized_string("tx-unconfirmed"),
+    };
+    let confirmation_count = match (state.confirmed_height, tip_height) {
+        (Some(height), Some(tip)) => confirmations(height, tip).to_string(),
+        _ if !tx.confirmed => "0".to_owned(),
+        _ => localized_string("unknown"),
+    };
+    let net_difference = if tx.amount_credited >= tx.amount_debited {
+        amount_to_hr_string(tx.amount_credited - tx.amount_debited, true)
+    } else {
+        format!(
+            "-{}",
+            amount_to_hr_string(tx.amount_debited - tx.amount_credited, true)
+        )
+    };
+    let counterparty = match tx_counterparty_address(tx) {
+        Some(address) => match address_book.find_by_address(&address) {
+            Some(contact) => format!("{} ({})", contact.name, address),
+            None => address,
+        },
+        None => localized_string("unknown"),
+    };
+
+    vec![
+        (localized_string("tx-id"), tx.id.to_string()),
+        (localized_string("tx-shared-id"), shared_id),
+        (
+            localized_string("tx-type"),
+            tx.tx_type.to_string().replace('\n', ""),
+        ),
+        (localized_string("tx-status"), status),
+        (
+            localized_string("tx-account"),
+            state
+                .account
+                .clone()
+                .unwrap_or_else(|| localized_string("unknown")),
+        ),
+        (
+            localized_string("tx-creation-time"),
+            tx.creation_ts.to_string(),
+        ),
+        (
+            localized_string("tx-confirmation-time"),
+            tx.confirmation_ts
+                .map(|t| t.to_string())
+                .unwrap_or_else(none),
+        ),
+        (localized_string("tx-confirmations"), confirmation_count),
+        (
+            localized_string("tx-ttl-cutoff"),
+            tx.ttl_cutoff_height
+                .map(|h| h.to_string())
+                .unwrap_or_else(none),
+        ),
+        (
+            localized_string("tx-kernel-lookup-height"),
+            tx.kernel_lookup_min_height
+                .map(|h| h.to_string())
+                .unwrap_or_else(none),
+        ),
+        (localized_string("tx-num-inputs"), tx.num_inputs.to_string()),
+        (
+            localized_string("tx-num-outputs"),
+            tx.num_outputs.to_string(),
+        ),
+        (
+            localized_string("tx-amount-credited"),
+            amount_to_hr_string(tx.amount_credited, true),
+        ),
+        (
+            localized_string("tx-amount-debited"),
+            amount_to_hr_string(tx.amount_debited, true),
+        ),
+        (
+            localized_string("tx-fee"),
+            amount_or_none(tx.fee.map(|f| f.fee())),
+        ),
+        (localized_string("tx-net-difference"), net_difference),
+        (
+            localized_string("tx-kernel-excess"),
+            tx_kernel_excess_hex(tx).unwrap_or_else(none),
+        ),
+        (
+            localized_string("tx-stored-tx"),
+            tx.stored_tx.clone().unwrap_or_else(none),
+        ),
+        (
+            localized_string("tx-payment-proof"),
+            yes_no(tx.payment_proof.is_some()),
+        ),
+        (localized_string("tx-counterparty"), counterparty),
+        (
+            localized_string("tx-reverted-after"),
+            tx.reverted_after
+                .map(|d| format!("{:?}", d))
+                .unwrap_or_else(none),
+        ),
+    ]
+}
+
+/// Plain text version of the details, for the clipboard
+fn tx_fields_text(fields: &[(String, String)], stored_tx: Option<&StoredTxSummary>) -> String {
+    let mut lines: Vec<String> = fields
+        .iter()
+        .map(|(label, value)| format!("{}: {}", label, value))
+        .collect();
+    if let Some(stored_tx) = stored_tx {
+        for input in stored_tx.inputs.iter() {
+            lines.push(format!(
+                "{}: {}",
+                localized_string("tx-stored-inputs"),
+                input
+            ));
+        }
+        for (commit, features) in stored_tx.outputs.iter() {
+            lines.push(format!(
+                "{}: {} ({})",
+                localized_string("tx-stored-outputs"),
+                commit,
+                features
+            ));
+        }
+        for (excess, features) in stored_tx.kernels.iter() {
+            lines.push(format!(
+                "{}: {} ({})",
+                localized_string("tx-stored-kernels"),
+                excess,
+                features
+            ));
+        }
+    }
+    lines.join("\n")
+}
+
+fn detail_row<'a>(label: String, value: String) -> Row<'a, Message> {
+    let label = Container::new(Text::new(label).size(DEFAULT_FONT_SIZE))
+        .width(Length::Units(150))
+        .style(ContainerStyle::NormalBackground);
+    let value = Container::new(Text::new(value).size(DEFAULT_FONT_SIZE))
+        .style(ContainerStyle::NormalBackground);
+
+    Row::new().push(label).push(value)
+}
+
+/// Primary button wrapped the way the rest of the wallet views wrap their actions
+fn action_button<'a>(label: String, interaction: Interaction) -> Container<'a, Message> {
+    let label_container = Container::new(Text::new(label).size(DEFAULT_FONT_SIZE))
+        .width(Length::Units(BUTTON_WIDTH))
+        .height(Length::Units(BUTTON_HEIGHT))
+        .center_x()
+        .center_y()
+        .align_x(alignment::Horizontal::Center);
+
+    let button: Element<Interaction> = Button::new(label_container)
+        .style(grin_gui_core::theme::ButtonStyle::Primary)
+        .on_press(interaction)
+        .into();
+
+    let container = Container::new(button.map(Message::Interaction)).padding(1);
+    Container::new(container)
+        .style(grin_gui_core::theme::ContainerStyle::Segmented)
+        .padding(1)
+}
+
+pub fn data_container<'a>(
+    _config: &'a Config,
+    state: &'a StateContainer,
+    address_book: &'a AddressBook,
+    sent_slatepacks: &'a SentSlatepacks,
+    tip_height: Option<u64>,
+) -> Container<'a, Message> {
+    let unit_spacing = 15;
+
+    // Title row
+    let title = Text::new(localized_string("tx-details"))
+        .size(DEFAULT_HEADER_FONT_SIZE)
+        .horizontal_alignment(alignment::Horizontal::Center);
+
+    let title_container = Container::new(title)
+        .style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+        .padding(iced::Padding::from([
+            2, // top
+            0, // right
+            2, // bottom
+            5, // left
+        ]));
+
+    let header_row = Row::new().push(title_container);
+
+    let header_container = Container::new(header_row).padding(iced::Padding::from([
+        0,               // top
+        0,               // right
+        DEFAULT_PADDING, // bottom
+        0,               // left
+    ]));
+
+    let mut column = Column::new();
+    let mut action_row = Row::new();
+
+    if let Some(tx) = state.tx.as_ref() {
+        let fields = tx_fields(tx, state, address_book, tip_height);
+        for (label, value) in fields.iter() {
+            column = column
+                .push(detail_row(label.clone(), value.clone()))
+                .push(Space::new(Length::Units(0), Length::Units(5)));
+        }
+        column = column.push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+
+        // Stored transaction
+        let stored_title = Text::new(localized_string("tx-stored-title"))
+            .size(DEFAULT_FONT_SIZE)
+            .horizontal_alignment(alignment::Horizontal::Left);
+        column = column
+            .push(Container::new(stored_title).style(ContainerStyle::NormalBackground))
+            .push(Space::new(Length::Units(0), Length::Units(5)));
+
+        match state.stored_tx.as_ref() {
+            Some(stored_tx) => {
+                for input in stored_tx.inputs.iter() {
+                    column = column.push(detail_row(
+                        localized_string("tx-stored-inputs"),
+                        input.clone(),
+                    ));
+                }
+                for (commit, features) in stored_tx.outputs.iter() {
+                    column = column.push(detail_row(
+                        localized_string("tx-stored-outputs"),
+                        format!("{} ({})", commit, features),
+                    ));
+                }
+                for (excess, features) in stored_tx.kernels.iter() {
+                    column = column.push(detail_row(
+                        localized_string("tx-stored-kernels"),
+                        format!("{} ({})", excess, features),
+                    ));
+                }
+            }
+            None => {
+                let key = if state.is_loading {
+                    "tx-stored-loading"
+                } else {
+                    "tx-stored-none"
+                };
+                let text = Text::new(localized_string(key))
+                    .size(SMALLER_FONT_SIZE)
+                    .horizontal_alignment(alignment::Horizontal::Left);
+                column = column.push(Container::new(text).style(ContainerStyle::NormalBackground));
+            }
+        }
+        column = column.push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+
+        let copy_text = tx_fields_text(&fields, state.stored_tx.as_ref());
+        action_row = action_row
+            .push(action_button(
+                localized_string("copy-to-clipboard"),
+                Interaction::WriteToClipboard(copy_text),
+            ))
+            .push(Space::with_width(Length::Units(DEFAULT_PADDING)));
+
+        let cancelled = matches!(
+            tx.tx_type,
+            TxLogEntryType::TxSentCancelled | TxLogEntryType::TxReceivedCancelled
+        );
+        if !tx.confirmed && !cancelled {
+            action_row = action_row
+                .push(action_button(
+                    localized_string("cancel-tx"),
+                    Interaction::WalletOperationTxDetailViewInteraction(
+                        LocalViewInteraction::CancelTx,
+                    ),
+                ))
+                .push(Space::with_width(Length::Units(DEFAULT_PADDING)));
+        }
+
+        if tx_can_resend_slatepack(tx)
+            && super::tx_list_display::sent_slatepack(sent_slatepacks, tx).is_some()
+        {
+            action_row = action_row
+                .push(action_button(
+                    localized_string("tx-show-slatepack"),
+                    Interaction::WalletOperationHomeTxListDisplayInteraction(
+                        super::tx_list_display::LocalViewInteraction::ShowSlatepack(tx.clone()),
+                    ),
+                ))
+                .push(Space::with_width(Length::Units(DEFAULT_PADDING)));
+        }
+
+        // Finalized but stuck, offer to post it again
+        if tx_can_repost(tx) {
+            let repost_fluff: Element<Interaction> = Checkbox::new(
+                localized_string("tx-repost-fluff"),
+                state.repost_fluff,
+                |b| {
+                    Interaction::WalletOperationTxDetailViewInteraction(
+                        LocalViewInteraction::RepostFluff(b),
+                    )
+                },
+            )
+            .style(grin_gui_core::theme::CheckboxStyle::Normal)
+            .text_size(SMALLER_FONT_SIZE)
+            .spacing(5)
+            .into();
+
+            let mut repost_row = Row::new()
+                .spacing(DEFAULT_PADDING)
+                .align_items(Alignment::Center)
+                .push(action_button(
+                    localized_string("tx-repost"),
+                    Interaction::WalletOperationTxDetailViewInteraction(
+                        LocalViewInteraction::RepostTx,
+                    ),
+                ))
+                .push(repost_fluff.map(Message::Interaction));
+
+            if state.reposted {
+                let reposted = Text::new(localized_string("tx-reposted"))
+                    .size(SMALLER_FONT_SIZE)
+                    .horizontal_alignment(alignment::Horizontal::Left);
+                repost_row = repost_row
+                    .push(Container::new(reposted).style(ContainerStyle::NormalBackground));
+            }
+
+            column = column
+                .push(repost_row)
+                .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+        }
+
+        let notes_title = Text::new(localized_string("tx-notes"))
+            .size(DEFAULT_FONT_SIZE)
+            .horizontal_alignment(alignment::Horizontal::Left);
+        column = column
+            .push(Container::new(notes_title).style(ContainerStyle::NormalBackground))
+            .push(Space::new(Length::Units(0), Length::Units(5)));
+
+        // Notes are keyed by the shared id, so there's nowhere to keep them without one
+        if tx.tx_slate_id.is_some() {
+            let note_input: Element<Interaction> =
+                TextInput::new(&localized_string("tx-note"), &state.note_value, |s| {
+                    Interaction::WalletOperationTxDetailViewInteraction(LocalViewInteraction::Note(
+                        s,
+                    ))
+                })
+                .size(DEFAULT_FONT_SIZE)
+                .padding(6)
+                .width(Length::Units(400))
+                .style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+                .into();
+
+            let tags_input: Element<Interaction> =
+                TextInput::new(&localized_string("tx-tags"), &state.tags_value, |s| {
+                    Interaction::WalletOperationTxDetailViewInteraction(LocalViewInteraction::Tags(
+                        s,
+                    ))
+                })
+                .size(DEFAULT_FONT_SIZE)
+                .padding(6)
+                .width(Length::Units(400))
+                .style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+                .into();
+
+            let tags_instruction = Text::new(localized_string("tx-tags-instruction"))
+                .size(SMALLER_FONT_SIZE)
+                .horizontal_alignment(alignment::Horizontal::Left);
+
+            let save_button: Element<Interaction> =
+                Button::new(Text::new(localized_string("tx-save-note")).size(DEFAULT_FONT_SIZE))
+                    .style(grin_gui_core::theme::ButtonStyle::Bordered)
+                    .on_press(Interaction::WalletOperationTxDetailViewInteraction(
+                        LocalViewInteraction::SaveNote,
+                    ))
+                    .into();
+
+            column = column
+                .push(note_input.map(Message::Interaction))
+                .push(Space::new(Length::Units(0), Length::Units(5)))
+                .push(tags_input.map(Message::Interaction))
+                .push(Space::new(Length::Units(0), Length::Units(5)))
+                .push(Container::new(tags_instruction).style(ContainerStyle::NormalBackground))
+                .push(Space::new(Length::Units(0), Length::Units(5)))
+                .push(save_button.map(Message::Interaction));
+        } else {
+            let no_slate_id = Text::new(localized_string("tx-notes-no-shared-id"))
+                .size(SMALLER_FONT_SIZE)
+                .horizontal_alignment(alignment::Horizontal::Left);
+            column =
+                column.push(Container::new(no_slate_id).style(ContainerStyle::NormalBackground));
+        }
+
+        column = column.push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+    }
+
+    action_row = action_row.push(action_button(
+        localized_string("back"),
+        Interaction::WalletOperationTxDetailViewInteraction(LocalViewInteraction::Back),
+    ));
+
+    column = column.push(action_row).push(Space::new(
+        Length::Units(0),
+        Length::Units(unit_spacing + 10),
+    ));
+
+    let form_container = Container::new(column)
+        .width(Length::Fill)
+        .padding(iced::Padding::from([
+            0, // top
+            0, // right
+            0, // bottom
+            5, // left
+        ]));
+
+    // form container should be scrollable in tiny windows
+    let scrollable = Scrollable::new(form_container)
+        .height(Length::Fill)
+        .style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+    let content = Container::new(scrollable)
+        .width(Length::Fill)
+        .height(Length::Shrink)
+        .style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+    let wrapper_column = Column::new()
+        .height(Length::Fill)
+        .push(header_container)
+        .push(content);
+
+    // Returns the final container.
+    Container::new(wrapper_column).padding(iced::Padding::from([
+        DEFAULT_PADDING, // top
+        DEFAULT_PADDING, // right
+        DEFAULT_PADDING, // bottom
+        DEFAULT_PADDING, // left
+    ]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gui::element::wallet::operation::{self, home};
+    use crate::gui::test::{mock_gui, open_wallet, run, TestDir};
+    use futures::executor::block_on;
+    use grin_gui_core::config::TxNotes;
+    use grin_gui_core::wallet::{mock::MOCK_SLATEPACK_ADDRESS, InitTxArgs, MockWallet};
+
+    /// Opened on a wallet with a confirmed receive and an outstanding send, in that order
+    fn open_with_send(dir: &TestDir) -> (GrinGui, TxLogEntry, TxLogEntry) {
+        let mut grin_gui = mock_gui(
+            dir,
+            MockWallet::new("password").with_received(5_000_000_000),
+        );
+        open_wallet(&mut grin_gui, "password");
+        let (_, txs) = block_on(grin_gui.wallet_interface.run(|w| {
+            let args = InitTxArgs {
+                amount: 1_000_000_000,
+                ..Default::default()
+            };
+            w.create_tx(args, MOCK_SLATEPACK_ADDRESS.to_owned(), None)?;
+            w.get_txs(None)
+        }))
+        .unwrap();
+        (grin_gui, txs[0].clone(), txs[1].clone())
+    }
+
+    fn show(grin_gui: &mut GrinGui, tx: &TxLogEntry) {
+        run(
+            grin_gui,
+            Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+                home::LocalViewInteraction::TxDetails(TxLogEntryWrap::new(tx.clone())),
+            )),
+        );
+    }
+
+    fn detail(grin_gui: &mut GrinGui, interaction: LocalViewInteraction) {
+        run(
+            grin_gui,
+            Message::Interaction(Interaction::WalletOperationTxDetailViewInteraction(
+                interaction,
+            )),
+        );
+    }
+
+    #[test]
+    fn test_tx_detail_loads_stored_tx_and_cancels() {
+        let dir = TestDir::new();
+        let (mut grin_gui, received, sent) = open_with_send(&dir);
+
+        // Confirmed at the height of its kernel, nothing stored for it
+        show(&mut grin_gui, &received);
+        let state = &grin_gui.wallet_state.operation_state.tx_detail_state;
+        assert!(grin_gui.error.is_none());
+        assert_eq!(
+            grin_gui.wallet_state.operation_state.mode,
+            operation::Mode::TxDetail
+        );
+        assert!(!state.is_loading);
+        assert_eq!(state.confirmed_height, Some(1));
+        assert_eq!(state.account.as_deref(), Some("default"));
+        assert!(state.stored_tx.is_none());
+
+        // The send stored its slate when it locked its outputs
+        show(&mut grin_gui, &sent);
+        let state = &grin_gui.wallet_state.operation_state.tx_detail_state;
+        assert_eq!(state.confirmed_height, None);
+        assert!(state.stored_tx.is_some());
+
+        detail(&mut grin_gui, LocalViewInteraction::CancelTx);
+        assert!(grin_gui.error.is_none());
+        let tx = grin_gui
+            .wallet_state
+            .operation_state
+            .tx_detail_state
+            .tx
+            .clone()
+            .unwrap();
+        assert_eq!(tx.id, sent.id);
+        assert_eq!(tx.tx_type, TxLogEntryType::TxSentCancelled);
+    }
+
+    #[test]
+    fn test_tx_notes_and_tags() {
+        let dir = TestDir::new();
+        let (mut grin_gui, received, sent) = open_with_send(&dir);
+
+        // Without a shared id there's nothing to key a note by
+        show(&mut grin_gui, &received);
+        detail(
+            &mut grin_gui,
+            LocalViewInteraction::Note("mined".to_owned()),
+        );
+        detail(&mut grin_gui, LocalViewInteraction::SaveNote);
+        assert!(grin_gui.tx_notes.notes.is_empty());
+
+        show(&mut grin_gui, &sent);
+        for interaction in [
+            LocalViewInteraction::Note("March rent ".to_owned()),
+            LocalViewInteraction::Tags("rent,office, rent".to_owned()),
+            LocalViewInteraction::SaveNote,
+        ] {
+            detail(&mut grin_gui, interaction);
+        }
+        assert!(grin_gui.error.is_none());
+        let slate_id = sent.tx_slate_id.unwrap().to_string();
+        let note = grin_gui.tx_notes.get(&slate_id).unwrap();
+        assert_eq!(note.text, "March rent");
+        assert_eq!(note.tags, vec!["rent", "office"]);
+        assert_eq!(TxNotes::load().unwrap(), grin_gui.tx_notes);
+
+        // Reopening loads the saved note back into the editor
+        show(&mut grin_gui, &sent);
+        let state = &grin_gui.wallet_state.operation_state.tx_detail_state;
+        assert_eq!(state.note_value, "March rent");
+        assert_eq!(state.tags_value, "rent, office");
+    }
+
+    #[test]
+    fn test_repost_finalized_tx() {
+        let dir = TestDir::new();
+        let (mut grin_gui, received, sent) = open_with_send(&dir);
+        let id = sent.id;
+        let (_, txs) = block_on(grin_gui.wallet_interface.run(move |w| {
+            let slate = w.get_stored_tx(id)?.unwrap();
+            w.finalize_from_s2(slate, true)?;
+            w.get_txs(None)
+        }))
+        .unwrap();
+        let sent = txs.into_iter().find(|tx| tx.id == id).unwrap();
+
+        show(&mut grin_gui, &sent);
+        detail(&mut grin_gui, LocalViewInteraction::RepostFluff(true));
+        detail(&mut grin_gui, LocalViewInteraction::RepostTx);
+        assert!(grin_gui.error.is_none());
+        assert!(
+            grin_gui
+                .wallet_state
+                .operation_state
+                .tx_detail_state
+                .reposted
+        );
+
+        // Nothing stored to post for the received tx
+        show(&mut grin_gui, &received);
+        detail(&mut grin_gui, LocalViewInteraction::RepostTx);
+        assert!(grin_gui.error.is_some());
+        assert!(
+            !grin_gui
+                .wallet_state
+                .operation_state
+                .tx_detail_state
+                .reposted
+        );
+    }
+}
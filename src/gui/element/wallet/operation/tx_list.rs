@@ -7,15 +7,20 @@ use {
     crate::gui::{GrinGui, Interaction, Message},
     crate::localization::localized_string,
     crate::Result,
+    chrono::{Local, NaiveDate},
+    fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher},
     grin_gui_core::theme::{
         Button, Column, Container, Element, Header, PickList, Row, Scrollable, TableRow, Text,
         TextInput,
     },
     grin_gui_core::{
-        config::Config,
-        node::amount_to_hr_string,
+        config::{Config, TxNotes},
+        node::{amount_from_hr_string, amount_to_hr_string},
         theme::{ButtonStyle, ColorPalette, ContainerStyle},
-        wallet::TxLogEntry,
+        wallet::{
+            tx_can_resend_slatepack, tx_confirmations, tx_kernel_excess_hex, tx_maturity,
+            TxLogEntry, TxLogEntryType, TxMaturity,
+        },
     },
     grin_gui_widgets::widget::header,
     iced::widget::{button, pick_list, scrollable, text_input, Space},
@@ -64,6 +69,7 @@ pub enum ColumnKey {
     TTLCutoff,
     Height,
     IsConfirmed,
+    Confirmations,
     ConfirmationTime,
     NumInputs,
     NumOutputs,
@@ -91,6 +97,7 @@ impl ColumnKey {
             TTLCutoff => localized_string("tx_ttl_cutoff"),
             Height => localized_string("tx_height"),
             IsConfirmed => localized_string("tx_is_confirmed"),
+            Confirmations => localized_string("tx-confirmations"),
             ConfirmationTime => localized_string("tx-confirmation-time"),
             NumInputs => localized_string("tx_num_inputs"),
             NumOutputs => localized_string("tx_num_outputs"),
@@ -117,6 +124,7 @@ impl ColumnKey {
             TTLCutoff => "tx_ttl_cutoff",
             Height => "tx_height",
             IsConfirmed => "tx_is_confirmed",
+            Confirmations => "tx-confirmations",
             ConfirmationTime => "tx-confirmation-time",
             NumInputs => "tx_num_inputs",
             NumOutputs => "tx_num_outputs",
@@ -145,6 +153,7 @@ impl From<&str> for ColumnKey {
             "tx_ttl_cutoff" => ColumnKey::TTLCutoff,
             "tx_height" => ColumnKey::Height,
             "tx_is_confirmed" => ColumnKey::IsConfirmed,
+            "tx-confirmations" => ColumnKey::Confirmations,
             "tx-confirmation-time" => ColumnKey::ConfirmationTime,
             "tx_num_inputs" => ColumnKey::NumInputs,
             "tx_num_outputs" => ColumnKey::NumOutputs,
@@ -167,7 +176,7 @@ pub enum SortDirection {
 }
 
 impl SortDirection {
-    fn toggle(self) -> SortDirection {
+    pub fn toggle(self) -> SortDirection {
         match self {
             SortDirection::Asc => SortDirection::Desc,
             SortDirection::Desc => SortDirection::Asc,
@@ -178,11 +187,16 @@ impl SortDirection {
 #[derive(Debug, Clone)]
 pub struct TxLogEntryWrap {
     pub tx: TxLogEntry,
+    // Height the tx was confirmed at, when the node tells
+    pub confirmed_height: Option<u64>,
 }
 
 impl TxLogEntryWrap {
     pub fn new(tx: TxLogEntry) -> Self {
-        Self { tx }
+        Self {
+            tx,
+            confirmed_height: None,
+        }
     }
 }
 
@@ -288,107 +302,114 @@ impl Default for HeaderState {
                 ColumnState {
                     key: ColumnKey::Status,
                     // btn_state: Default::default(),
-                    width: Length::Units(300),
+                    width: Length::Units(190),
                     hidden: false,
                     order: 3,
                 },
+                ColumnState {
+                    key: ColumnKey::Confirmations,
+                    // btn_state: Default::default(),
+                    width: Length::Units(110),
+                    hidden: false,
+                    order: 4,
+                },
                 ColumnState {
                     key: ColumnKey::ConfirmationTime,
                     // btn_state: Default::default(),
                     width: Length::Units(110),
                     hidden: true,
-                    order: 4,
+                    order: 5,
                 },
                 ColumnState {
                     key: ColumnKey::Type,
                     // btn_state: Default::default(),
                     width: Length::Units(150),
                     hidden: true,
-                    order: 5,
+                    order: 6,
                 },
                 ColumnState {
                     key: ColumnKey::SharedTransactionId,
                     // btn_state: Default::default(),
                     width: Length::Units(150),
                     hidden: true,
-                    order: 6,
+                    order: 7,
                 },
                 ColumnState {
                     key: ColumnKey::TTLCutoff,
                     // btn_state: Default::default(),
                     width: Length::Units(85),
                     hidden: true,
-                    order: 7,
+                    order: 8,
                 },
                 ColumnState {
                     key: ColumnKey::Height,
                     // btn_state: Default::default(),
                     width: Length::Units(85),
                     hidden: true,
-                    order: 8,
+                    order: 9,
                 },
                 ColumnState {
                     key: ColumnKey::IsConfirmed,
                     // btn_state: Default::default(),
                     width: Length::Units(110),
                     hidden: true,
-                    order: 9,
+                    order: 10,
                 },
                 ColumnState {
                     key: ColumnKey::NumInputs,
                     // btn_state: Default::default(),
                     width: Length::Units(110),
                     hidden: true,
-                    order: 10,
+                    order: 11,
                 },
                 ColumnState {
                     key: ColumnKey::NumOutputs,
                     //  btn_state: Default::default(),
                     width: Length::Units(110),
                     hidden: true,
-                    order: 11,
+                    order: 12,
                 },
                 ColumnState {
                     key: ColumnKey::AmountCredited,
                     //  btn_state: Default::default(),
                     width: Length::Units(110),
                     hidden: true,
-                    order: 12,
+                    order: 13,
                 },
                 ColumnState {
                     key: ColumnKey::AmountDebited,
                     //  btn_state: Default::default(),
                     width: Length::Units(110),
                     hidden: true,
-                    order: 13,
+                    order: 14,
                 },
                 ColumnState {
                     key: ColumnKey::Fee,
                     //  btn_state: Default::default(),
                     width: Length::Units(110),
                     hidden: true,
-                    order: 14,
+                    order: 15,
                 },
                 ColumnState {
                     key: ColumnKey::PaymentProof,
                     //  btn_state: Default::default(),
                     width: Length::Units(110),
                     hidden: true,
-                    order: 15,
+                    order: 16,
                 },
                 ColumnState {
                     key: ColumnKey::Kernel,
                     //  btn_state: Default::default(),
                     width: Length::Units(110),
                     hidden: true,
-                    order: 16,
+                    order: 17,
                 },
                 ColumnState {
                     key: ColumnKey::TxData,
                     //  btn_state: Default::default(),
                     width: Length::Units(110),
                     hidden: true,
-                    order: 17,
+                    order: 18,
                 },
             ],
         }
@@ -430,61 +451,65 @@ impl Default for ColumnSettings {
                     order: 3,
                 },
                 ColumnSettingState {
-                    key: ColumnKey::ConfirmationTime,
+                    key: ColumnKey::Confirmations,
                     order: 4,
                 },
                 ColumnSettingState {
-                    key: ColumnKey::Type,
+                    key: ColumnKey::ConfirmationTime,
                     order: 5,
                 },
                 ColumnSettingState {
-                    key: ColumnKey::SharedTransactionId,
+                    key: ColumnKey::Type,
                     order: 6,
                 },
                 ColumnSettingState {
-                    key: ColumnKey::TTLCutoff,
+                    key: ColumnKey::SharedTransactionId,
                     order: 7,
                 },
                 ColumnSettingState {
-                    key: ColumnKey::Height,
+                    key: ColumnKey::TTLCutoff,
                     order: 8,
                 },
                 ColumnSettingState {
-                    key: ColumnKey::IsConfirmed,
+                    key: ColumnKey::Height,
                     order: 9,
                 },
                 ColumnSettingState {
-                    key: ColumnKey::NumInputs,
+                    key: ColumnKey::IsConfirmed,
                     order: 10,
                 },
                 ColumnSettingState {
-                    key: ColumnKey::NumOutputs,
+                    key: ColumnKey::NumInputs,
                     order: 11,
                 },
                 ColumnSettingState {
-                    key: ColumnKey::AmountCredited,
+                    key: ColumnKey::NumOutputs,
                     order: 12,
                 },
                 ColumnSettingState {
-                    key: ColumnKey::AmountDebited,
+                    key: ColumnKey::AmountCredited,
                     order: 13,
                 },
                 ColumnSettingState {
-                    key: ColumnKey::Fee,
+                    key: ColumnKey::AmountDebited,
                     order: 14,
                 },
                 ColumnSettingState {
-                    key: ColumnKey::PaymentProof,
+                    key: ColumnKey::Fee,
                     order: 15,
                 },
                 ColumnSettingState {
-                    key: ColumnKey::Kernel,
+                    key: ColumnKey::PaymentProof,
                     order: 16,
                 },
                 ColumnSettingState {
-                    key: ColumnKey::TxData,
+                    key: ColumnKey::Kernel,
                     order: 17,
                 },
+                ColumnSettingState {
+                    key: ColumnKey::TxData,
+                    order: 18,
+                },
             ],
         }
     }
@@ -845,33 +870,262 @@ pub struct TxListColumnSettingState {
     pub order: usize,
 }
 
-pub struct CatalogSearchState {
-    pub catalog_rows: Vec<CatalogRow>,
-    pub query: Option<String>,
-    // pub query_state: text_input::State,
+/// Which txs to list, by type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TxTypeFilter {
+    All,
+    Sent,
+    Received,
+    Coinbase,
+    Cancelled,
+}
+
+impl TxTypeFilter {
+    pub const ALL: [TxTypeFilter; 5] = [
+        TxTypeFilter::All,
+        TxTypeFilter::Sent,
+        TxTypeFilter::Received,
+        TxTypeFilter::Coinbase,
+        TxTypeFilter::Cancelled,
+    ];
+
+    fn matches(self, tx_type: &TxLogEntryType) -> bool {
+        match self {
+            TxTypeFilter::All => true,
+            TxTypeFilter::Sent => *tx_type == TxLogEntryType::TxSent,
+            TxTypeFilter::Received => *tx_type == TxLogEntryType::TxReceived,
+            TxTypeFilter::Coinbase => *tx_type == TxLogEntryType::ConfirmedCoinbase,
+            TxTypeFilter::Cancelled => matches!(
+                tx_type,
+                TxLogEntryType::TxSentCancelled | TxLogEntryType::TxReceivedCancelled
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for TxTypeFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            TxTypeFilter::All => localized_string("tx-filter-type-all"),
+            TxTypeFilter::Sent => localized_string("tx-filter-sent"),
+            TxTypeFilter::Received => localized_string("tx-filter-received"),
+            TxTypeFilter::Coinbase => localized_string("tx-filter-coinbase"),
+            TxTypeFilter::Cancelled => localized_string("tx-cancelled"),
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Which txs to list, by how far they are from counting as confirmed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfirmationFilter {
+    All,
+    Unconfirmed,
+    /// In a block, but short of the threshold or still maturing
+    Confirming,
+    Confirmed,
+}
+
+impl ConfirmationFilter {
+    pub const ALL: [ConfirmationFilter; 4] = [
+        ConfirmationFilter::All,
+        ConfirmationFilter::Unconfirmed,
+        ConfirmationFilter::Confirming,
+        ConfirmationFilter::Confirmed,
+    ];
+
+    fn matches(self, maturity: TxMaturity) -> bool {
+        match self {
+            ConfirmationFilter::All => true,
+            // Not known to be confirmed either
+            ConfirmationFilter::Unconfirmed => {
+                matches!(maturity, TxMaturity::Unconfirmed | TxMaturity::Unknown)
+            }
+            ConfirmationFilter::Confirming => matches!(
+                maturity,
+                TxMaturity::Confirming(_) | TxMaturity::Immature(_)
+            ),
+            ConfirmationFilter::Confirmed => maturity == TxMaturity::Confirmed,
+        }
+    }
+}
+
+impl std::fmt::Display for ConfirmationFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ConfirmationFilter::All => localized_string("tx-filter-status-all"),
+            ConfirmationFilter::Unconfirmed => localized_string("tx-unconfirmed"),
+            ConfirmationFilter::Confirming => localized_string("tx-confirming"),
+            ConfirmationFilter::Confirmed => localized_string("tx-confirmed"),
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Bound typed into a filter field, `Ok(None)` when left blank and `Err` when it doesn't parse
+fn parse_bound<T>(value: &str, parse: impl Fn(&str) -> Option<T>) -> Result<Option<T>, ()> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    parse(value).map(Some).ok_or(())
+}
+
+fn parse_date(value: &str) -> Result<Option<NaiveDate>, ()> {
+    parse_bound(value, |v| NaiveDate::parse_from_str(v, "%Y-%m-%d").ok())
+}
+
+fn parse_amount(value: &str) -> Result<Option<u64>, ()> {
+    parse_bound(value, |v| amount_from_hr_string(v).ok())
+}
+
+/// Search and filters over the tx list, and the page of matches shown
+pub struct TxListSearchState {
+    pub query: String,
+    pub type_filter: TxTypeFilter,
+    pub confirmation_filter: ConfirmationFilter,
+    // Creation date bounds as typed, YYYY-MM-DD
+    pub from_date: String,
+    pub to_date: String,
+    // Amount bounds as typed, in GRIN
+    pub min_amount: String,
+    pub max_amount: String,
     pub result_size: TxListResultSize,
     pub result_sizes: Vec<TxListResultSize>,
-    // pub result_sizes_state: pick_list::State<TxListResultSize>,
+    pub page: usize,
 }
 
-impl Default for CatalogSearchState {
+impl Default for TxListSearchState {
     fn default() -> Self {
-        CatalogSearchState {
-            catalog_rows: Default::default(),
-            query: None,
-            // query_state: Default::default(),
+        TxListSearchState {
+            query: Default::default(),
+            type_filter: TxTypeFilter::All,
+            confirmation_filter: ConfirmationFilter::All,
+            from_date: Default::default(),
+            to_date: Default::default(),
+            min_amount: Default::default(),
+            max_amount: Default::default(),
             result_size: Default::default(),
             result_sizes: TxListResultSize::all(),
-            // result_sizes_state: Default::default(),
+            page: 0,
         }
     }
 }
 
-pub struct CatalogRow {
-    // install_button_state: button::State,
+impl TxListSearchState {
+    /// Whether a date or amount bound was typed that can't be read, and so is ignored
+    pub fn has_invalid_bounds(&self) -> bool {
+        parse_date(&self.from_date).is_err()
+            || parse_date(&self.to_date).is_err()
+            || parse_amount(&self.min_amount).is_err()
+            || parse_amount(&self.max_amount).is_err()
+    }
+
+    /// Clears the query and every filter, keeping the page size
+    pub fn clear(&mut self) {
+        *self = TxListSearchState {
+            result_size: self.result_size,
+            ..Default::default()
+        };
+    }
+
+    /// How well the query fuzzy matches the tx's ids, kernel excess, amounts or the
+    /// note and tags kept for it. `None` when it doesn't match at all.
+    pub fn fuzzy_score(
+        &self,
+        matcher: &SkimMatcherV2,
+        tx: &TxLogEntry,
+        tx_notes: &TxNotes,
+    ) -> Option<i64> {
+        let query = self.query.trim();
+        if query.is_empty() {
+            return Some(0);
+        }
+
+        let slate_id = tx.tx_slate_id.map(|id| id.to_string());
+        let note = slate_id.as_ref().and_then(|id| tx_notes.get(id));
+        let mut candidates = vec![
+            tx.id.to_string(),
+            amount_to_hr_string(tx.amount_credited, false),
+            amount_to_hr_string(tx.amount_debited, false),
+        ];
+        candidates.extend(slate_id);
+        candidates.extend(tx_kernel_excess_hex(tx));
+        if let Some(note) = note {
+            candidates.push(note.text.clone());
+            candidates.extend(note.tags.iter().cloned());
+        }
+
+        candidates
+            .iter()
+            .filter_map(|c| matcher.fuzzy_match(c, query))
+            .max()
+    }
+
+    /// Txs passing every filter, best fuzzy matches first while there's a query. Bounds
+    /// that can't be read are left out rather than matching nothing.
+    pub fn matching<'a>(
+        &self,
+        txs: &'a [TxLogEntryWrap],
+        tx_notes: &TxNotes,
+        tip_height: Option<u64>,
+        confirmations_threshold: u64,
+    ) -> Vec<&'a TxLogEntryWrap> {
+        let from_date = parse_date(&self.from_date).unwrap_or_default();
+        let to_date = parse_date(&self.to_date).unwrap_or_default();
+        let min_amount = parse_amount(&self.min_amount).unwrap_or_default();
+        let max_amount = parse_amount(&self.max_amount).unwrap_or_default();
+        let matcher = SkimMatcherV2::default().ignore_case();
+
+        let mut scored: Vec<(i64, &TxLogEntryWrap)> = txs
+            .iter()
+            .filter(|t| self.type_filter.matches(&t.tx.tx_type))
+            .filter(|t| {
+                let confirmed_height = tip_height.and(t.confirmed_height);
+                let maturity = tx_maturity(
+                    &t.tx,
+                    confirmed_height,
+                    tip_height.unwrap_or_default(),
+                    confirmations_threshold,
+                );
+                self.confirmation_filter.matches(maturity)
+            })
+            .filter(|t| {
+                let date = t.tx.creation_ts.with_timezone(&Local).date_naive();
+                from_date.map_or(true, |from| date >= from) && to_date.map_or(true, |to| date <= to)
+            })
+            .filter(|t| {
+                let amount = t.tx.amount_credited.max(t.tx.amount_debited)
+                    - t.tx.amount_credited.min(t.tx.amount_debited);
+                min_amount.map_or(true, |min| amount >= min)
+                    && max_amount.map_or(true, |max| amount <= max)
+            })
+            .filter_map(|t| {
+                self.fuzzy_score(&matcher, &t.tx, tx_notes)
+                    .map(|score| (score, t))
+            })
+            .collect();
+
+        // Stable, so equally good matches keep the list's own order
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        scored.into_iter().map(|(_, t)| t).collect()
+    }
+
+    /// Number of pages the matches fill, at least one
+    pub fn page_count(&self, matches: usize) -> usize {
+        (matches.max(1) - 1) / self.result_size.as_usize() + 1
+    }
+
+    /// Matches on the current page, or the last page if the list shrank under it
+    pub fn page_of<'a, 'b>(&self, matches: &'b [&'a TxLogEntryWrap]) -> &'b [&'a TxLogEntryWrap] {
+        let size = self.result_size.as_usize();
+        let page = self.page.min(self.page_count(matches.len()) - 1);
+        let start = page * size;
+        &matches[start..(start + size).min(matches.len())]
+    }
 }
 
-fn row_title<T: PartialEq>(
+pub fn row_title<T: PartialEq>(
     column_key: T,
     previous_column_key: Option<T>,
     previous_sort_direction: Option<SortDirection>,
@@ -960,11 +1214,17 @@ pub fn titles_row_header<'a>(
 }
 
 //TODO: Move somewhere else
-pub fn create_tx_display_status(log_entry: &TxLogEntry) -> String {
-    if log_entry.confirmed {
-        localized_string("tx-confirmed")
-    } else {
-        localized_string("tx-unconfirmed")
+pub fn create_tx_display_status(maturity: TxMaturity) -> String {
+    match maturity {
+        TxMaturity::Unconfirmed => localized_string("tx-unconfirmed"),
+        TxMaturity::Confirming(_) => localized_string("tx-confirming"),
+        TxMaturity::Immature(blocks) => {
+            let mut vars = HashMap::new();
+            vars.insert("blocks".to_string(), blocks);
+            strfmt(&localized_string("tx-immature"), &vars).unwrap()
+        }
+        TxMaturity::Confirmed => localized_string("tx-confirmed"),
+        TxMaturity::Unknown => localized_string("tx-confirmation-unknown"),
     }
 }
 
@@ -977,6 +1237,9 @@ pub fn data_row_container<'a, 'b>(
     column_config: &'b [(ColumnKey, Length, bool)],
     is_odd: Option<bool>,
     pending_confirmation: &Option<Confirm>,
+    tip_height: Option<u64>,
+    confirmations_threshold: u64,
+    has_sent_slatepack: bool,
 ) -> Container<'a, Message> {
     let default_height = Length::Units(26);
     let mut default_row_height = 26;
@@ -1015,10 +1278,23 @@ pub fn data_row_container<'a, 'b>(
             )
         )
     };
+    // Heights mean nothing until the chain tip is known
+    let confirmed_height = tip_height.and(tx_log_entry_wrap.confirmed_height);
+    let tip_height = tip_height.unwrap_or_default();
+    let confirmation_count =
+        match tx_confirmations(&tx_log_entry_wrap.tx, confirmed_height, tip_height) {
+            Some(count) => count.to_string(),
+            None => localized_string("unknown"),
+        };
     //TODO this will show the latest status
     // Unconfirmed - Created time
     // Confirmed
-    let status = create_tx_display_status(&tx_log_entry_wrap.tx);
+    let status = create_tx_display_status(tx_maturity(
+        &tx_log_entry_wrap.tx,
+        confirmed_height,
+        tip_height,
+        confirmations_threshold,
+    ));
 
     /*let version = tx
         .version()
@@ -1187,6 +1463,30 @@ pub fn data_row_container<'a, 'b>(
         row_containers.push((idx, display_status_container));
     }
 
+    if let Some((idx, width)) = column_config
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, (key, width, hidden))| {
+            if *key == ColumnKey::Confirmations && !hidden {
+                Some((idx, width))
+            } else {
+                None
+            }
+        })
+        .next()
+    {
+        let display_confirmations = Text::new(confirmation_count).size(DEFAULT_FONT_SIZE);
+
+        let display_confirmations_container = Container::new(display_confirmations)
+            .padding(5)
+            .height(default_height)
+            .width(*width)
+            .center_y()
+            .style(grin_gui_core::theme::ContainerStyle::HoverableForeground);
+
+        row_containers.push((idx, display_confirmations_container));
+    }
+
     /*if let Some((idx, width)) = column_config
         .iter()
         .enumerate()
@@ -1626,6 +1926,70 @@ pub fn data_row_container<'a, 'b>(
                     action_button_row = action_button_row.push(tx_cancel_wrap)
                 }
 
+                // Still waiting on the other party, who may need the slatepack again
+                if has_sent_slatepack && tx_can_resend_slatepack(&tx_log_entry_wrap.tx) {
+                    let tx_slatepack_container = Container::new(
+                        Text::new(localized_string("tx-show-slatepack")).size(DEFAULT_FONT_SIZE),
+                    )
+                    .width(button_width)
+                    .align_y(alignment::Vertical::Center)
+                    .align_x(alignment::Horizontal::Center);
+
+                    let tx_slatepack_button: Element<Interaction> =
+                        Button::new(tx_slatepack_container)
+                            .width(Length::Units(BUTTON_WIDTH))
+                            .style(grin_gui_core::theme::ButtonStyle::Primary)
+                            .on_press(Interaction::WalletOperationHomeTxListDisplayInteraction(
+                                super::tx_list_display::LocalViewInteraction::ShowSlatepack(
+                                    tx_log_entry_wrap.tx.clone(),
+                                ),
+                            ))
+                            .into();
+
+                    let tx_slatepack_wrap =
+                        Container::new(tx_slatepack_button.map(Message::Interaction)).padding(1);
+                    let tx_slatepack_wrap = Container::new(tx_slatepack_wrap)
+                        .style(grin_gui_core::theme::ContainerStyle::Segmented)
+                        .padding(1);
+
+                    action_button_row = action_button_row
+                        .push(Space::with_width(Length::Units(DEFAULT_PADDING)))
+                        .push(tx_slatepack_wrap)
+                }
+
+                // Only a finished send has a proof worth exporting
+                if confirmed
+                    && tx_log_entry_wrap.tx.tx_type == TxLogEntryType::TxSent
+                    && tx_log_entry_wrap.tx.payment_proof.is_some()
+                {
+                    let tx_proof_container = Container::new(
+                        Text::new(localized_string("export-proof")).size(DEFAULT_FONT_SIZE),
+                    )
+                    .width(button_width)
+                    .align_y(alignment::Vertical::Center)
+                    .align_x(alignment::Horizontal::Center);
+
+                    let tx_proof_button: Element<Interaction> = Button::new(tx_proof_container)
+                        .width(Length::Units(BUTTON_WIDTH))
+                        .style(grin_gui_core::theme::ButtonStyle::Primary)
+                        .on_press(Interaction::WalletOperationHomeViewInteraction(
+                            super::home::LocalViewInteraction::ExportPaymentProof(
+                                tx_log_entry_wrap.tx.id,
+                            ),
+                        ))
+                        .into();
+
+                    let tx_proof_wrap =
+                        Container::new(tx_proof_button.map(Message::Interaction)).padding(1);
+                    let tx_proof_wrap = Container::new(tx_proof_wrap)
+                        .style(grin_gui_core::theme::ContainerStyle::Segmented)
+                        .padding(1);
+
+                    action_button_row = action_button_row
+                        .push(Space::with_width(Length::Units(DEFAULT_PADDING)))
+                        .push(tx_proof_wrap)
+                }
+
                 /*
                 let notes_title_text =
                     Text::new(localized_string("summary")).size(DEFAULT_FONT_SIZE);
@@ -1882,3 +2246,165 @@ pub fn handle_message<'a>(
     }
     Ok(Command::none())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::localization::LANG;
+    use chrono::{DateTime, TimeZone, Utc};
+    use grin_gui_core::config::TxNote;
+    use grin_gui_core::wallet::{MockWallet, Slate};
+    use std::sync::RwLock;
+
+    /// 30 confirmed receives of 1..=30 GRIN made on 2023-03-01, of which the second is
+    /// turned into a 2 GRIN send with 0.5 GRIN change made later that month and the
+    /// third is still unconfirmed
+    fn txs() -> Vec<TxLogEntryWrap> {
+        let made_on = |day| -> DateTime<Utc> {
+            Local
+                .with_ymd_and_hms(2023, 3, day, 12, 0, 0)
+                .unwrap()
+                .into()
+        };
+        let mut txs: Vec<TxLogEntryWrap> = (0..30)
+            .map(|i| {
+                let mut tx = MockWallet::tx(i, TxLogEntryType::TxReceived);
+                tx.amount_credited = (i as u64 + 1) * 1_000_000_000;
+                tx.creation_ts = made_on(1);
+                tx.confirmed = true;
+                TxLogEntryWrap {
+                    tx,
+                    confirmed_height: Some(1),
+                }
+            })
+            .collect();
+        txs[1].tx.tx_type = TxLogEntryType::TxSent;
+        txs[1].tx.amount_debited = 2_500_000_000;
+        txs[1].tx.amount_credited = 500_000_000;
+        txs[1].tx.creation_ts = made_on(20);
+        txs[2].tx.confirmed = false;
+        txs[2].confirmed_height = None;
+        txs
+    }
+
+    fn ids(
+        search: &TxListSearchState,
+        txs: &[TxLogEntryWrap],
+        tx_notes: &TxNotes,
+        threshold: u64,
+    ) -> Vec<u32> {
+        search
+            .matching(txs, tx_notes, Some(5), threshold)
+            .iter()
+            .map(|t| t.tx.id)
+            .collect()
+    }
+
+    #[test]
+    fn test_tx_list_filters() {
+        let mut txs = txs();
+        let notes = TxNotes::default();
+        let mut search = TxListSearchState::default();
+        assert_eq!(ids(&search, &txs, &notes, 10).len(), 30);
+        search.type_filter = TxTypeFilter::Sent;
+        assert_eq!(ids(&search, &txs, &notes, 10), vec![1]);
+        search.type_filter = TxTypeFilter::All;
+
+        // Confirmations are counted against the wallet's threshold
+        search.confirmation_filter = ConfirmationFilter::Unconfirmed;
+        assert_eq!(ids(&search, &txs, &notes, 10), vec![2]);
+        search.confirmation_filter = ConfirmationFilter::Confirming;
+        assert_eq!(ids(&search, &txs, &notes, 10).len(), 29);
+        assert!(ids(&search, &txs, &notes, 5).is_empty());
+        search.confirmation_filter = ConfirmationFilter::Confirmed;
+        assert_eq!(ids(&search, &txs, &notes, 5).len(), 29);
+
+        // Confirmed by the wallet at a height the node can't tell isn't counted as confirmed
+        txs[3].confirmed_height = None;
+        assert_eq!(ids(&search, &txs, &notes, 5).len(), 28);
+        search.confirmation_filter = ConfirmationFilter::Unconfirmed;
+        assert_eq!(ids(&search, &txs, &notes, 10), vec![2, 3]);
+        txs[3].confirmed_height = Some(1);
+        search.confirmation_filter = ConfirmationFilter::All;
+
+        // Sends are matched on what left the wallet, not counting change
+        search.min_amount = "2".to_owned();
+        search.max_amount = "3".to_owned();
+        assert_eq!(ids(&search, &txs, &notes, 10), vec![1, 2]);
+        search.max_amount = "three".to_owned();
+        assert!(search.has_invalid_bounds());
+        assert_eq!(ids(&search, &txs, &notes, 10).len(), 29);
+        search.clear();
+
+        search.from_date = "2023-03-02".to_owned();
+        assert_eq!(ids(&search, &txs, &notes, 10), vec![1]);
+        search.to_date = "2023-03-19".to_owned();
+        assert!(ids(&search, &txs, &notes, 10).is_empty());
+    }
+
+    #[test]
+    fn test_tx_list_fuzzy_search() {
+        let mut txs = txs();
+        let slate_id = Slate::blank(2, false).id;
+        txs[7].tx.tx_slate_id = Some(slate_id);
+        let mut notes = TxNotes::default();
+        notes.set(
+            slate_id.to_string(),
+            TxNote::new("Supplier invoice", "office"),
+        );
+
+        // Notes are fuzzy matched, ignoring case, as are ids and amounts
+        let mut search = TxListSearchState::default();
+        for (query, first) in [
+            ("splr invc", Some(7)),
+            ("OFFICE", Some(7)),
+            (&slate_id.to_string()[..8], Some(7)),
+            ("30.0", Some(29)),
+            ("payroll", None),
+        ] {
+            search.query = query.to_owned();
+            let found = ids(&search, &txs, &notes, 10);
+            assert_eq!(found.first().copied(), first, "{}", query);
+        }
+        search.query = "office".to_owned();
+        assert_eq!(ids(&search, &txs, &notes, 10), vec![7]);
+    }
+
+    #[test]
+    fn test_tx_list_paging() {
+        let txs = txs();
+        let notes = TxNotes::default();
+        let mut search = TxListSearchState::default();
+        let all = search.matching(&txs, &notes, Some(5), 10);
+        assert_eq!(search.page_count(all.len()), 2);
+        assert_eq!(search.page_count(0), 1);
+        search.page = 1;
+        assert_eq!(search.page_of(&all).len(), 5);
+
+        // A page past the end shows the last one
+        search.page = 7;
+        assert_eq!(search.page_of(&all)[0].tx.id, 25);
+
+        search.result_size = TxListResultSize::_50;
+        assert_eq!(search.page_count(all.len()), 1);
+        search.clear();
+        assert_eq!(search.result_size, TxListResultSize::_50);
+    }
+
+    #[test]
+    fn test_tx_display_status() {
+        let _ = LANG.set(RwLock::new("en_US"));
+        assert_eq!(
+            create_tx_display_status(TxMaturity::Confirming(5)),
+            localized_string("tx-confirming")
+        );
+        assert_eq!(
+            create_tx_display_status(TxMaturity::Immature(3)),
+            "Immature (3 blocks left)"
+        );
+        assert_eq!(
+            create_tx_display_status(TxMaturity::Unknown),
+            "Confirmation Unknown"
+        );
+    }
+}
@@ -3,15 +3,21 @@ use crate::log_error;
 use async_std::prelude::FutureExt;
 use chrono::DurationRound;
 use grin_gui_core::{
-    config::Config,
-    wallet::{TxLogEntry, TxLogEntryType},
+    config::{Config, SentSlatepack, SentSlatepacks, TxNotes, DEFAULT_CONFIRMATIONS_THRESHOLD},
+    error::GrinWalletInterfaceError,
+    wallet::{coinbase_height, tx_kernel_excess_hex, TxLogEntry, TxLogEntryType},
 };
-use grin_gui_widgets::widget::header;
+use grin_gui_widgets::{qr_code::QrData, widget::header};
 use iced_aw::Card;
 use iced_native::Widget;
-use std::{borrow::Borrow, path::PathBuf, str::FromStr};
+use native_dialog::FileDialog;
+use std::{borrow::Borrow, collections::HashMap, path::PathBuf, str::FromStr};
+use strfmt::strfmt;
 
-use super::tx_list::{HeaderState, TxList, TxLogEntryWrap};
+use super::tx_list::{
+    ConfirmationFilter, HeaderState, TxList, TxListResultSize, TxListSearchState, TxLogEntryWrap,
+    TxTypeFilter,
+};
 
 use {
     super::super::super::{
@@ -27,7 +33,8 @@ use {
         TextInput,
     },
     grin_gui_core::wallet::{
-        InitTxArgs, RetrieveTxQueryArgs, RetrieveTxQuerySortOrder, Slate, StatusMessage, WalletInfo, WalletInterface,
+        write_tx_history, InitTxArgs, RetrieveTxQueryArgs, RetrieveTxQuerySortOrder, SlateState,
+        StatusMessage, TxHistoryFormat, TxHistoryRecord, WalletInfo,
     },
     grin_gui_core::{
         node::amount_to_hr_string,
@@ -42,7 +49,7 @@ use {
 pub struct StateContainer {
     // maintains a list of all confirmed transactions sorted by date
     confirmed_txns: Vec<TxLogEntry>,
-    wallet_txs: TxList,
+    pub wallet_txs: TxList,
     tx_header_state: HeaderState,
     pub mode: Mode,
 
@@ -50,6 +57,16 @@ pub struct StateContainer {
 
     // balance history for wallet as (date, grin_balance)
     pub balance_data: Vec<(chrono::DateTime<chrono::Utc>, f64)>,
+
+    // only txs matching the search and filters are listed, a page at a time
+    pub search: TxListSearchState,
+
+    // whether exports hold only the listed txs rather than the whole history
+    pub export_listed_only: bool,
+
+    // heights of the kernels looked up so far by excess, `None` for those the node
+    // doesn't have. A kernel never moves, so each is only asked for once.
+    kernel_heights: HashMap<String, Option<u64>>,
 }
 
 impl Default for StateContainer {
@@ -61,6 +78,22 @@ impl Default for StateContainer {
             mode: Mode::NotInit,
             balance_data: vec![],
             confirmed_txns: vec![],
+            search: Default::default(),
+            export_listed_only: false,
+            kernel_heights: HashMap::new(),
+        }
+    }
+}
+
+impl StateContainer {
+    /// Fills in the confirmed heights of the listed txs whose kernels were looked up
+    fn apply_kernel_heights(&mut self) {
+        for t in self.wallet_txs.txs.iter_mut().filter(|t| t.tx.confirmed) {
+            if let Some(height) =
+                tx_kernel_excess_hex(&t.tx).and_then(|e| self.kernel_heights.get(&e))
+            {
+                t.confirmed_height = *height;
+            }
         }
     }
 }
@@ -78,8 +111,25 @@ pub enum Mode {
 #[derive(Debug, Clone)]
 pub enum LocalViewInteraction {
     SelectMode(Mode),
-    TxListUpdateSuccess(bool, Vec<TxLogEntry>),
+    TxListUpdateSuccess(bool, Vec<TxLogEntryWrap>),
     TxListUpdateFailure(Arc<RwLock<Option<anyhow::Error>>>),
+    KernelHeightsUpdateSuccess(Vec<(String, Option<u64>)>),
+    Query(String),
+    SelectTypeFilter(TxTypeFilter),
+    SelectConfirmationFilter(ConfirmationFilter),
+    FromDate(String),
+    ToDate(String),
+    MinAmount(String),
+    MaxAmount(String),
+    ClearFilters,
+    SelectResultSize(TxListResultSize),
+    SelectPage(usize),
+    ToggleExportListedOnly(bool),
+    ExportHistory(TxHistoryFormat),
+    ExportHistoryFile(TxHistoryFormat, PathBuf),
+    ExportHistorySuccess,
+    ExportHistoryFailure(Arc<RwLock<Option<anyhow::Error>>>),
+    ShowSlatepack(TxLogEntry),
 }
 
 pub fn handle_message<'a>(
@@ -92,6 +142,21 @@ pub fn handle_message<'a>(
         .home_state
         .tx_list_display_state;
 
+    // Other rows may be listed now, whose heights haven't been looked up yet
+    let relisted = matches!(
+        message,
+        LocalViewInteraction::Query(_)
+            | LocalViewInteraction::SelectTypeFilter(_)
+            | LocalViewInteraction::SelectConfirmationFilter(_)
+            | LocalViewInteraction::FromDate(_)
+            | LocalViewInteraction::ToDate(_)
+            | LocalViewInteraction::MinAmount(_)
+            | LocalViewInteraction::MaxAmount(_)
+            | LocalViewInteraction::ClearFilters
+            | LocalViewInteraction::SelectResultSize(_)
+            | LocalViewInteraction::SelectPage(_)
+    );
+
     match message {
         LocalViewInteraction::SelectMode(new_mode) => {
             let mut query_args = RetrieveTxQueryArgs::default();
@@ -113,8 +178,27 @@ pub fn handle_message<'a>(
 
             let w = grin_gui.wallet_interface.clone();
 
-            let fut = move || WalletInterface::get_txs(w, Some(query_args));
-            return Ok(Command::perform(fut(), |tx_list_res| {
+            // Heights txs confirmed at count their confirmations. Coinbase rewards go by
+            // their outputs, the kernels of the others are looked up once the list is in.
+            let fut = w.run(move |w| {
+                let (node_success, txs) = w.get_txs(Some(query_args))?;
+                let (_, outputs) = w.get_outputs(true)?;
+                let txs: Vec<TxLogEntryWrap> = txs
+                    .into_iter()
+                    .map(|tx| TxLogEntryWrap {
+                        confirmed_height: if tx.confirmed
+                            && tx.tx_type == TxLogEntryType::ConfirmedCoinbase
+                        {
+                            coinbase_height(&tx, &outputs)
+                        } else {
+                            None
+                        },
+                        tx,
+                    })
+                    .collect();
+                Ok((node_success, txs))
+            });
+            return Ok(Command::perform(fut, |tx_list_res| {
                 if tx_list_res.is_err() {
                     let e = tx_list_res
                         .context("Failed to retrieve transaction list")
@@ -137,13 +221,29 @@ pub fn handle_message<'a>(
         LocalViewInteraction::TxListUpdateSuccess(node_success, txs) => {
             debug!("Update Tx List Summary: {}", node_success);
             debug!("Update Wallet Txs Summary: {:?}", txs);
-            let tx_wrap_list = txs
+            // Confirmed or cancelled elsewhere, maybe by the other party finalizing
+            let settled: Vec<String> = txs
                 .iter()
-                .map(|tx| TxLogEntryWrap::new(tx.clone()))
+                .map(|t| &t.tx)
+                .filter(|tx| {
+                    tx.confirmed
+                        || matches!(
+                            tx.tx_type,
+                            TxLogEntryType::TxSentCancelled | TxLogEntryType::TxReceivedCancelled
+                        )
+                })
+                .filter_map(|tx| tx.tx_slate_id.map(|id| id.to_string()))
                 .collect();
-            state.wallet_txs = TxList { txs: tx_wrap_list };
+            state.wallet_txs = TxList { txs };
+            state.apply_kernel_heights();
 
-            let confirmed_txns: Vec<&TxLogEntry> = txs.iter().filter(|tx| tx.confirmed).collect();
+            let confirmed_txns: Vec<&TxLogEntry> = state
+                .wallet_txs
+                .txs
+                .iter()
+                .map(|t| &t.tx)
+                .filter(|tx| tx.confirmed)
+                .collect();
 
             if !confirmed_txns.is_empty() {
                 // added new confirmed transactions to state confirmed set?
@@ -208,6 +308,9 @@ pub fn handle_message<'a>(
                     state.balance_data = balance_history;
                 }
             }
+
+            super::forget_slatepacks(grin_gui, &settled);
+            return Ok(lookup_kernel_heights(grin_gui));
         }
         LocalViewInteraction::TxListUpdateFailure(err) => {
             grin_gui.error = err.write().unwrap().take();
@@ -215,12 +318,306 @@ pub fn handle_message<'a>(
                 log_error(e);
             }
         }
+        LocalViewInteraction::KernelHeightsUpdateSuccess(heights) => {
+            state.kernel_heights.extend(heights);
+            state.apply_kernel_heights();
+        }
+        LocalViewInteraction::Query(query) => {
+            state.search.query = query;
+            state.search.page = 0;
+        }
+        LocalViewInteraction::SelectTypeFilter(filter) => {
+            state.search.type_filter = filter;
+            state.search.page = 0;
+        }
+        LocalViewInteraction::SelectConfirmationFilter(filter) => {
+            state.search.confirmation_filter = filter;
+            state.search.page = 0;
+        }
+        LocalViewInteraction::FromDate(date) => {
+            state.search.from_date = date;
+            state.search.page = 0;
+        }
+        LocalViewInteraction::ToDate(date) => {
+            state.search.to_date = date;
+            state.search.page = 0;
+        }
+        LocalViewInteraction::MinAmount(amount) => {
+            state.search.min_amount = amount;
+            state.search.page = 0;
+        }
+        LocalViewInteraction::MaxAmount(amount) => {
+            state.search.max_amount = amount;
+            state.search.page = 0;
+        }
+        LocalViewInteraction::ClearFilters => state.search.clear(),
+        LocalViewInteraction::SelectResultSize(size) => {
+            state.search.result_size = size;
+            state.search.page = 0;
+        }
+        LocalViewInteraction::SelectPage(page) => state.search.page = page,
+        LocalViewInteraction::ToggleExportListedOnly(listed_only) => {
+            state.export_listed_only = listed_only;
+        }
+        LocalViewInteraction::ExportHistory(format) => {
+            let file_name = format!(
+                "tx_history_{}.{}",
+                chrono::Local::now().format("%Y-%m-%d"),
+                format.extension()
+            );
+            match FileDialog::new()
+                .set_filename(&file_name)
+                .add_filter(&format.extension().to_uppercase(), &[format.extension()])
+                .show_save_single_file()
+            {
+                Ok(Some(path)) => {
+                    return Ok(Command::perform(async {}, move |_| {
+                        Message::Interaction(
+                            Interaction::WalletOperationHomeTxListDisplayInteraction(
+                                LocalViewInteraction::ExportHistoryFile(format, path),
+                            ),
+                        )
+                    }));
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    log::debug!(
+                        "tx_list_display.rs::LocalViewInteraction::ExportHistory {}",
+                        e
+                    );
+                }
+            }
+        }
+        LocalViewInteraction::ExportHistoryFile(format, path) => {
+            log::debug!(
+                "Interaction::WalletOperationHomeTxListDisplayInteraction(ExportHistoryFile)"
+            );
+            grin_gui.error.take();
+
+            if state.export_listed_only {
+                let tip_height = grin_gui
+                    .wallet_state
+                    .operation_state
+                    .home_state
+                    .wallet_info
+                    .as_ref()
+                    .map(|i| i.last_confirmed_height);
+                let records: Vec<TxHistoryRecord> = state
+                    .search
+                    .matching(
+                        &state.wallet_txs.txs,
+                        &grin_gui.tx_notes,
+                        tip_height,
+                        confirmations_threshold(&grin_gui.config),
+                    )
+                    .into_iter()
+                    .map(|t| history_record(&t.tx, &grin_gui.tx_notes))
+                    .collect();
+                if let Err(e) = write_tx_history(&path, &records, format)
+                    .context("Failed to export transaction history")
+                {
+                    log_error(&e);
+                    grin_gui.error = Some(e);
+                }
+                return Ok(Command::none());
+            }
+
+            let tx_notes = grin_gui.tx_notes.clone();
+            let fut = grin_gui.wallet_interface.run(move |w| {
+                let (_, txs) = w.get_txs(None)?;
+                let records: Vec<TxHistoryRecord> =
+                    txs.iter().map(|tx| history_record(tx, &tx_notes)).collect();
+                write_tx_history(&path, &records, format)
+            });
+            return Ok(Command::perform(fut, |r| {
+                match r.context("Failed to export transaction history") {
+                    Ok(()) => Message::Interaction(
+                        Interaction::WalletOperationHomeTxListDisplayInteraction(
+                            LocalViewInteraction::ExportHistorySuccess,
+                        ),
+                    ),
+                    Err(e) => Message::Interaction(
+                        Interaction::WalletOperationHomeTxListDisplayInteraction(
+                            LocalViewInteraction::ExportHistoryFailure(Arc::new(RwLock::new(
+                                Some(e),
+                            ))),
+                        ),
+                    ),
+                }
+            }));
+        }
+        LocalViewInteraction::ExportHistorySuccess => {
+            log::debug!("Transaction history exported");
+        }
+        LocalViewInteraction::ExportHistoryFailure(err) => {
+            grin_gui.error = err.write().unwrap().take();
+            if let Some(e) = grin_gui.error.as_ref() {
+                log_error(e);
+            }
+        }
+        LocalViewInteraction::ShowSlatepack(tx) => {
+            log::debug!("Interaction::WalletOperationHomeTxListDisplayInteraction(ShowSlatepack)");
+            grin_gui.error.take();
+
+            // Shown again just as it was handed out, the wallet's own copy of the slate
+            // lacks the participant data the other party needs
+            let sent = match sent_slatepack(&grin_gui.sent_slatepacks, &tx) {
+                Some(sent) => sent.clone(),
+                None => {
+                    let e =
+                        anyhow::Error::from(GrinWalletInterfaceError::SentSlatepackNotFound(tx.id));
+                    log_error(&e);
+                    grin_gui.error = Some(e);
+                    return Ok(Command::none());
+                }
+            };
+
+            let operation_state = &mut grin_gui.wallet_state.operation_state;
+            if tx.tx_type == TxLogEntryType::TxReceived {
+                let success_state = &mut operation_state.apply_tx_success_state;
+                success_state.slatepack_qr = QrData::new(&sent.slatepack).ok();
+                success_state.encrypted_slate = Some(sent.slatepack);
+                success_state.slatepack_file_name = sent.file_name;
+                operation_state.mode = crate::gui::element::wallet::operation::Mode::ApplyTxSuccess;
+            } else {
+                let success_state = &mut operation_state.create_tx_success_state;
+                success_state.slatepack_qr = QrData::new(&sent.slatepack).ok();
+                success_state.encrypted_slate = sent.slatepack;
+                success_state.slatepack_file_name = sent.file_name;
+                operation_state.mode =
+                    crate::gui::element::wallet::operation::Mode::CreateTxSuccess;
+            }
+        }
     }
 
+    if relisted {
+        return Ok(lookup_kernel_heights(grin_gui));
+    }
     Ok(Command::none())
 }
 
-pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+/// Looks up on the node the kernel heights the list is still missing, for the rows on
+/// the current page only. Filtering by confirmations needs the heights of every row.
+fn lookup_kernel_heights(grin_gui: &GrinGui) -> Command<Message> {
+    let home_state = &grin_gui.wallet_state.operation_state.home_state;
+    let state = &home_state.tx_list_display_state;
+    let matches;
+    let rows: Vec<&TxLogEntryWrap> = if state.search.confirmation_filter == ConfirmationFilter::All
+    {
+        matches = state.search.matching(
+            &state.wallet_txs.txs,
+            &grin_gui.tx_notes,
+            home_state
+                .wallet_info
+                .as_ref()
+                .map(|i| i.last_confirmed_height),
+            confirmations_threshold(&grin_gui.config),
+        );
+        state.search.page_of(&matches).to_vec()
+    } else {
+        state.wallet_txs.txs.iter().collect()
+    };
+    let txs: Vec<TxLogEntry> = rows
+        .into_iter()
+        .map(|t| &t.tx)
+        .filter(|tx| tx.confirmed && tx.tx_type != TxLogEntryType::ConfirmedCoinbase)
+        .filter(|tx| {
+            tx_kernel_excess_hex(tx).map_or(false, |e| !state.kernel_heights.contains_key(&e))
+        })
+        .cloned()
+        .collect();
+    if txs.is_empty() {
+        return Command::none();
+    }
+
+    let fut = grin_gui.wallet_interface.run(move |w| {
+        let mut heights = vec![];
+        for tx in txs {
+            let (excess, excess_hex) = match (tx.kernel_excess, tx_kernel_excess_hex(&tx)) {
+                (Some(excess), Some(excess_hex)) => (excess, excess_hex),
+                _ => continue,
+            };
+            // Left out when the node can't be asked, so it's tried again next time
+            match w.kernel_height(excess, tx.kernel_lookup_min_height) {
+                Ok(height) => heights.push((excess_hex, height)),
+                Err(e) => log::warn!(
+                    "Unable to look up the kernel of transaction {}: {}",
+                    tx.id,
+                    e
+                ),
+            }
+        }
+        Ok(heights)
+    });
+    Command::perform(fut, |r| {
+        match r.context("Failed to look up transaction heights") {
+            Ok(heights) => {
+                Message::Interaction(Interaction::WalletOperationHomeTxListDisplayInteraction(
+                    LocalViewInteraction::KernelHeightsUpdateSuccess(heights),
+                ))
+            }
+            Err(e) => {
+                Message::Interaction(Interaction::WalletOperationHomeTxListDisplayInteraction(
+                    LocalViewInteraction::TxListUpdateFailure(Arc::new(RwLock::new(Some(e)))),
+                ))
+            }
+        }
+    })
+}
+
+/// The slatepack handed to the other party for a transaction still waiting on them, if
+/// one was kept when it was created
+pub fn sent_slatepack<'a>(
+    sent_slatepacks: &'a SentSlatepacks,
+    tx: &TxLogEntry,
+) -> Option<&'a SentSlatepack> {
+    let stages = match tx.tx_type {
+        TxLogEntryType::TxReceived => [SlateState::Standard2, SlateState::Invoice1],
+        _ => [SlateState::Standard1, SlateState::Invoice2],
+    };
+    let id = tx.tx_slate_id?.to_string();
+    stages
+        .iter()
+        .find_map(|stage| sent_slatepacks.get(&id, &stage.to_string()))
+}
+
+fn history_record(tx: &TxLogEntry, tx_notes: &TxNotes) -> TxHistoryRecord {
+    let note = tx.tx_slate_id.and_then(|id| tx_notes.get(&id.to_string()));
+    TxHistoryRecord::new(tx, note)
+}
+
+/// Confirmations the open wallet wants before a tx counts as confirmed
+fn confirmations_threshold(config: &Config) -> u64 {
+    config
+        .current_wallet_index
+        .map(|i| config.wallets[i].confirmations_threshold)
+        .unwrap_or(DEFAULT_CONFIRMATIONS_THRESHOLD)
+}
+
+/// Text input for one of the filter bounds
+fn filter_input<'a>(
+    placeholder: &str,
+    value: &'a str,
+    on_change: fn(String) -> LocalViewInteraction,
+) -> Element<'a, Message> {
+    let input: Element<Interaction> = TextInput::new(&localized_string(placeholder), value, |s| {
+        Interaction::WalletOperationHomeTxListDisplayInteraction(on_change(s))
+    })
+    .size(SMALLER_FONT_SIZE)
+    .padding(6)
+    .width(Length::Units(110))
+    .style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+    .into();
+    input.map(Message::Interaction)
+}
+
+pub fn data_container<'a>(
+    config: &'a Config,
+    state: &'a StateContainer,
+    tx_notes: &'a TxNotes,
+    sent_slatepacks: &'a SentSlatepacks,
+    tip_height: Option<u64>,
+) -> Container<'a, Message> {
     let button_height = Length::Units(BUTTON_HEIGHT);
     let button_width = Length::Units(BUTTON_WIDTH);
 
@@ -275,20 +672,67 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
         .style(grin_gui_core::theme::ContainerStyle::Segmented)
         .padding(1);
 
+    let query_input: Element<Interaction> =
+        TextInput::new(&localized_string("tx-search"), &state.search.query, |s| {
+            Interaction::WalletOperationHomeTxListDisplayInteraction(LocalViewInteraction::Query(s))
+        })
+        .size(DEFAULT_FONT_SIZE)
+        .padding(6)
+        .width(Length::Units(200))
+        .style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+        .into();
+
     // add additional buttons here
     let button_row = Row::new()
+        .push(query_input.map(Message::Interaction))
+        .push(Space::with_width(Length::Units(DEFAULT_PADDING)))
         .push(latest_container_wrap)
         .push(Space::with_width(Length::Units(DEFAULT_PADDING)))
-        .push(outstanding_container_wrap);
+        .push(outstanding_container_wrap)
+        .align_items(Alignment::Center);
 
     /*let segmented_mode_container = Container::new(button_row).padding(1);
     let segmented_mode_control_container = Container::new(segmented_mode_container)
         .style(grin_gui_core::theme::ContainerStyle::Segmented)
         .padding(1);*/
 
+    let export_listed_only: Element<Interaction> = Checkbox::new(
+        localized_string("export-listed-only"),
+        state.export_listed_only,
+        |b| {
+            Interaction::WalletOperationHomeTxListDisplayInteraction(
+                LocalViewInteraction::ToggleExportListedOnly(b),
+            )
+        },
+    )
+    .style(grin_gui_core::theme::CheckboxStyle::Normal)
+    .text_size(SMALLER_FONT_SIZE)
+    .spacing(5)
+    .into();
+
+    let mut export_row = Row::new()
+        .spacing(DEFAULT_PADDING)
+        .align_items(Alignment::Center)
+        .push(export_listed_only.map(Message::Interaction));
+    for (format, key) in [
+        (TxHistoryFormat::Csv, "export-history-csv"),
+        (TxHistoryFormat::Json, "export-history-json"),
+    ] {
+        let export_button: Element<Interaction> =
+            Button::new(Text::new(localized_string(key)).size(SMALLER_FONT_SIZE))
+                .style(grin_gui_core::theme::ButtonStyle::Bordered)
+                .on_press(Interaction::WalletOperationHomeTxListDisplayInteraction(
+                    LocalViewInteraction::ExportHistory(format),
+                ))
+                .into();
+        export_row = export_row.push(export_button.map(Message::Interaction));
+    }
+
     let header_row = Row::new()
         .push(title_container)
         .push(Space::with_width(Length::Fill))
+        .push(export_row)
+        .push(Space::with_width(Length::Units(DEFAULT_PADDING)))
         .push(button_row)
         .align_items(Alignment::Center);
 
@@ -299,6 +743,83 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
         0,               // left
     ]));
 
+    let type_pick_list = PickList::new(
+        &TxTypeFilter::ALL[..],
+        Some(state.search.type_filter),
+        |f| {
+            Message::Interaction(Interaction::WalletOperationHomeTxListDisplayInteraction(
+                LocalViewInteraction::SelectTypeFilter(f),
+            ))
+        },
+    )
+    .text_size(SMALLER_FONT_SIZE)
+    .width(Length::Units(120))
+    .style(grin_gui_core::theme::PickListStyle::Primary);
+
+    let confirmation_pick_list = PickList::new(
+        &ConfirmationFilter::ALL[..],
+        Some(state.search.confirmation_filter),
+        |f| {
+            Message::Interaction(Interaction::WalletOperationHomeTxListDisplayInteraction(
+                LocalViewInteraction::SelectConfirmationFilter(f),
+            ))
+        },
+    )
+    .text_size(SMALLER_FONT_SIZE)
+    .width(Length::Units(120))
+    .style(grin_gui_core::theme::PickListStyle::Primary);
+
+    let clear_button: Element<Interaction> =
+        Button::new(Text::new(localized_string("tx-filter-clear")).size(SMALLER_FONT_SIZE))
+            .style(grin_gui_core::theme::ButtonStyle::Bordered)
+            .on_press(Interaction::WalletOperationHomeTxListDisplayInteraction(
+                LocalViewInteraction::ClearFilters,
+            ))
+            .into();
+
+    let mut filter_row = Row::new()
+        .spacing(DEFAULT_PADDING)
+        .align_items(Alignment::Center)
+        .push(type_pick_list)
+        .push(confirmation_pick_list)
+        .push(filter_input(
+            "tx-filter-from-date",
+            &state.search.from_date,
+            LocalViewInteraction::FromDate,
+        ))
+        .push(filter_input(
+            "tx-filter-to-date",
+            &state.search.to_date,
+            LocalViewInteraction::ToDate,
+        ))
+        .push(filter_input(
+            "tx-filter-min-amount",
+            &state.search.min_amount,
+            LocalViewInteraction::MinAmount,
+        ))
+        .push(filter_input(
+            "tx-filter-max-amount",
+            &state.search.max_amount,
+            LocalViewInteraction::MaxAmount,
+        ))
+        .push(clear_button.map(Message::Interaction));
+
+    if state.search.has_invalid_bounds() {
+        let warning = Text::new(localized_string("tx-filter-invalid"))
+            .size(SMALLER_FONT_SIZE)
+            .style(grin_gui_core::theme::text::TextStyle::Warning);
+        filter_row = filter_row.push(
+            Container::new(warning).style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+        );
+    }
+
+    let filter_container = Container::new(filter_row).padding(iced::Padding::from([
+        0,               // top
+        0,               // right
+        DEFAULT_PADDING, // bottom
+        0,               // left
+    ]));
+
     // TRANSACTION HEADER
     let column_config = state.tx_header_state.column_config();
 
@@ -329,8 +850,16 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 
     let mut has_txs = false;
 
-    // Loops though the txs.
-    for (idx, tx_wrap) in state.wallet_txs.txs.iter().enumerate() {
+    let confirmations_threshold = confirmations_threshold(config);
+    let matches = state.search.matching(
+        &state.wallet_txs.txs,
+        tx_notes,
+        tip_height,
+        confirmations_threshold,
+    );
+
+    // Loops though the txs on the current page.
+    for (idx, tx_wrap) in state.search.page_of(&matches).iter().copied().enumerate() {
         has_txs = true;
         // If hiding ignored addons, we will skip it.
         /*if addon.state == AddonState::Ignored && self.config.hide_ignored_addons {
@@ -364,6 +893,9 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
             &column_config,
             is_odd,
             &None,
+            tip_height,
+            confirmations_threshold,
+            sent_slatepack(sent_slatepacks, &tx_wrap.tx).is_some(),
         );
 
         // Adds the addon data cell to the scrollable.
@@ -400,10 +932,68 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
         .height(Length::Fill)
         .width(Length::Fill);
 
+    // PAGING
+    let page_count = state.search.page_count(matches.len());
+    let page = state.search.page.min(page_count - 1);
+
+    let result_size_pick_list = PickList::new(
+        &state.search.result_sizes[..],
+        Some(state.search.result_size),
+        |size| {
+            Message::Interaction(Interaction::WalletOperationHomeTxListDisplayInteraction(
+                LocalViewInteraction::SelectResultSize(size),
+            ))
+        },
+    )
+    .text_size(SMALLER_FONT_SIZE)
+    .width(Length::Units(120))
+    .style(grin_gui_core::theme::PickListStyle::Primary);
+
+    let mut previous_button =
+        Button::new(Text::new(localized_string("tx-list-previous")).size(SMALLER_FONT_SIZE))
+            .style(grin_gui_core::theme::ButtonStyle::Bordered);
+    if page > 0 {
+        previous_button =
+            previous_button.on_press(Interaction::WalletOperationHomeTxListDisplayInteraction(
+                LocalViewInteraction::SelectPage(page - 1),
+            ));
+    }
+    let previous_button: Element<Interaction> = previous_button.into();
+
+    let mut next_button =
+        Button::new(Text::new(localized_string("tx-list-next")).size(SMALLER_FONT_SIZE))
+            .style(grin_gui_core::theme::ButtonStyle::Bordered);
+    if page + 1 < page_count {
+        next_button =
+            next_button.on_press(Interaction::WalletOperationHomeTxListDisplayInteraction(
+                LocalViewInteraction::SelectPage(page + 1),
+            ));
+    }
+    let next_button: Element<Interaction> = next_button.into();
+
+    let mut vars = HashMap::new();
+    vars.insert("page".to_string(), page + 1);
+    vars.insert("pages".to_string(), page_count);
+    vars.insert("matches".to_string(), matches.len());
+    let page_text = Text::new(strfmt(&localized_string("tx-list-page"), &vars).unwrap())
+        .size(SMALLER_FONT_SIZE);
+
+    let page_row = Row::new()
+        .spacing(DEFAULT_PADDING)
+        .align_items(Alignment::Center)
+        .push(Space::with_width(Length::Fill))
+        .push(result_size_pick_list)
+        .push(previous_button.map(Message::Interaction))
+        .push(
+            Container::new(page_text).style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+        )
+        .push(next_button.map(Message::Interaction));
+
     let row = Row::new().push(
         Column::new()
             .push(table_container)
-            .push(Space::with_height(Length::Units(DEFAULT_PADDING))), //.push(button_row),
+            .push(Space::with_height(Length::Units(DEFAULT_PADDING)))
+            .push(page_row),
     );
 
     let content = Container::new(row)
@@ -415,6 +1005,7 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
     let wrapper_column = Column::new()
         .height(Length::Fill)
         .push(header_container)
+        .push(filter_container)
         .push(content);
 
     // Returns the final container.
@@ -427,3 +1018,316 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
         ]))
         .style(grin_gui_core::theme::ContainerStyle::PanelBordered)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gui::element::wallet::operation::{
+        self, apply_tx, apply_tx_confirm, create_tx, home, tx_detail,
+    };
+    use crate::gui::test::{mock_gui, open_wallet, run, TestDir};
+    use futures::executor::block_on;
+    use grin_gui_core::config::{SentSlatepacks, TxNote};
+    use grin_gui_core::fs::PersistentData;
+    use grin_gui_core::wallet::{
+        mock::MOCK_SLATEPACK_ADDRESS, MockWallet, Slate, Slatepack, SlatepackAddress,
+    };
+
+    fn list(grin_gui: &mut GrinGui, interaction: LocalViewInteraction) {
+        run(
+            grin_gui,
+            Message::Interaction(Interaction::WalletOperationHomeTxListDisplayInteraction(
+                interaction,
+            )),
+        );
+    }
+
+    fn list_state(grin_gui: &mut GrinGui) -> &mut StateContainer {
+        &mut grin_gui
+            .wallet_state
+            .operation_state
+            .home_state
+            .tx_list_display_state
+    }
+
+    #[test]
+    fn test_tx_list_paging_resets() {
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, MockWallet::new("password"));
+        open_wallet(&mut grin_gui, "password");
+        list_state(&mut grin_gui).wallet_txs.txs = (0..30)
+            .map(|i| TxLogEntryWrap::new(MockWallet::tx(i, TxLogEntryType::TxReceived)))
+            .collect();
+
+        // Paging through the list, then changing what's listed goes back to the first page
+        for (interaction, page) in [
+            (LocalViewInteraction::SelectPage(1), 1),
+            (LocalViewInteraction::MinAmount("1".to_owned()), 0),
+            (LocalViewInteraction::SelectPage(1), 1),
+            (
+                LocalViewInteraction::SelectResultSize(TxListResultSize::_50),
+                0,
+            ),
+            (LocalViewInteraction::SelectPage(1), 1),
+            (LocalViewInteraction::ClearFilters, 0),
+        ] {
+            list(&mut grin_gui, interaction);
+            assert_eq!(list_state(&mut grin_gui).search.page, page);
+        }
+        let search = &list_state(&mut grin_gui).search;
+        assert!(search.min_amount.is_empty());
+        assert_eq!(search.result_size, TxListResultSize::_50);
+    }
+
+    #[test]
+    fn test_confirmed_heights() {
+        // A page and a bit, with the two oldest on the second page
+        let mut mock = MockWallet::new("password");
+        for _ in 0..27 {
+            mock = mock.with_received(1_000_000_000);
+        }
+        // Its kernel went into a later block than the output it created says, and the
+        // other one's kernel isn't known to the node
+        let excesses: Vec<String> = mock.txs[..2]
+            .iter()
+            .map(|tx| tx_kernel_excess_hex(tx).unwrap())
+            .collect();
+        mock.kernel_heights.insert(excesses[0].clone(), 3);
+        mock.kernel_heights.remove(&excesses[1]);
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, mock);
+        open_wallet(&mut grin_gui, "password");
+
+        let confirmed_height = |state: &StateContainer, id| {
+            let txs = &state.wallet_txs.txs;
+            txs.iter().find(|t| t.tx.id == id).unwrap().confirmed_height
+        };
+
+        // Only the kernels of the rows shown are looked up
+        list(
+            &mut grin_gui,
+            LocalViewInteraction::SelectMode(Mode::Recent),
+        );
+        let state = list_state(&mut grin_gui);
+        assert_eq!(state.kernel_heights.len(), 25);
+        assert!(!state.kernel_heights.contains_key(&excesses[0]));
+        assert_eq!(confirmed_height(state, 0), None);
+        assert!(confirmed_height(state, 26).is_some());
+
+        list(&mut grin_gui, LocalViewInteraction::SelectPage(1));
+        let state = list_state(&mut grin_gui);
+        assert_eq!(confirmed_height(state, 0), Some(3));
+        assert_eq!(confirmed_height(state, 1), None);
+        // Kept even though the node didn't have it, so it isn't asked for again
+        assert_eq!(state.kernel_heights.get(&excesses[1]), Some(&None));
+
+        // A reload keeps the heights already looked up
+        list(
+            &mut grin_gui,
+            LocalViewInteraction::SelectMode(Mode::Recent),
+        );
+        let state = list_state(&mut grin_gui);
+        assert_eq!(state.kernel_heights.len(), 27);
+        assert_eq!(confirmed_height(state, 0), Some(3));
+    }
+
+    #[test]
+    fn test_export_tx_history() {
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(
+            &dir,
+            MockWallet::new("password").with_received(5_000_000_000),
+        );
+        open_wallet(&mut grin_gui, "password");
+        let (_, txs) = block_on(grin_gui.wallet_interface.run(|w| {
+            let args = InitTxArgs {
+                amount: 1_000_000_000,
+                ..Default::default()
+            };
+            w.create_tx(args, MOCK_SLATEPACK_ADDRESS.to_owned(), None)?;
+            w.get_txs(None)
+        }))
+        .unwrap();
+        let sent = txs[1].clone();
+        grin_gui.tx_notes.set(
+            sent.tx_slate_id.unwrap().to_string(),
+            TxNote::new("Invoice 42", "supplier"),
+        );
+
+        let json_path = dir.join("tx_history.json");
+        list(
+            &mut grin_gui,
+            LocalViewInteraction::ExportHistoryFile(TxHistoryFormat::Json, json_path.clone()),
+        );
+        assert!(grin_gui.error.is_none());
+        let records: Vec<TxHistoryRecord> =
+            serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].amount_credited, "5.000000000");
+        let sent_record = records.iter().find(|r| r.id == sent.id).unwrap();
+        assert_eq!(sent_record.notes, "Invoice 42");
+        assert_eq!(sent_record.tags, vec!["supplier"]);
+        assert_eq!(
+            sent_record.shared_id,
+            sent.tx_slate_id.map(|id| id.to_string())
+        );
+
+        // Only the txs listed under the current search
+        for interaction in [
+            LocalViewInteraction::SelectMode(Mode::Recent),
+            LocalViewInteraction::Query("supplier".to_owned()),
+            LocalViewInteraction::ToggleExportListedOnly(true),
+        ] {
+            list(&mut grin_gui, interaction);
+        }
+        let csv_path = dir.join("tx_history.csv");
+        list(
+            &mut grin_gui,
+            LocalViewInteraction::ExportHistoryFile(TxHistoryFormat::Csv, csv_path.clone()),
+        );
+        assert!(grin_gui.error.is_none());
+        let csv = std::fs::read_to_string(&csv_path).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].starts_with(&format!("{},", sent.id)));
+        assert!(lines[1].ends_with(",Invoice 42,supplier"));
+    }
+
+    #[test]
+    fn test_show_slatepack_of_outstanding_tx() {
+        let mut mock = MockWallet::new("password").with_received(5_000_000_000);
+        let incoming = Slate::blank(2, false);
+        let incoming_id = incoming.id;
+        let sender = Slatepack {
+            sender: Some(SlatepackAddress::try_from(MOCK_SLATEPACK_ADDRESS).unwrap()),
+            ..Default::default()
+        };
+        mock.slatepacks.insert("S1".to_owned(), (sender, incoming));
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, mock);
+        open_wallet(&mut grin_gui, "password");
+
+        for interaction in [
+            create_tx::LocalViewInteraction::RecipientAddress(MOCK_SLATEPACK_ADDRESS.to_owned()),
+            create_tx::LocalViewInteraction::Amount("1".to_owned()),
+            create_tx::LocalViewInteraction::CreateTransaction(),
+            create_tx::LocalViewInteraction::ConfirmTransaction,
+        ] {
+            run(
+                &mut grin_gui,
+                Message::Interaction(Interaction::WalletOperationCreateTxViewInteraction(
+                    interaction,
+                )),
+            );
+        }
+        let sent_slatepack = grin_gui
+            .wallet_state
+            .operation_state
+            .create_tx_success_state
+            .encrypted_slate
+            .clone();
+        for message in [
+            Message::Interaction(Interaction::WalletOperationApplyTxViewInteraction(
+                apply_tx::LocalViewInteraction::ReadFromClipboardSuccess("S1".to_owned()),
+            )),
+            Message::Interaction(Interaction::WalletOperationApplyTxConfirmViewInteraction(
+                apply_tx_confirm::LocalViewInteraction::Accept,
+            )),
+        ] {
+            run(&mut grin_gui, message);
+        }
+        let response_slatepack = grin_gui
+            .wallet_state
+            .operation_state
+            .apply_tx_success_state
+            .encrypted_slate
+            .clone()
+            .unwrap();
+        let (_, txs) = block_on(grin_gui.wallet_interface.run(|w| w.get_txs(None))).unwrap();
+        let sent = txs
+            .iter()
+            .find(|tx| tx.tx_type == TxLogEntryType::TxSent)
+            .unwrap()
+            .clone();
+        let received = txs
+            .iter()
+            .find(|tx| tx.tx_slate_id == Some(incoming_id))
+            .unwrap()
+            .clone();
+
+        // The S1 goes back on the send success screen
+        grin_gui
+            .wallet_state
+            .operation_state
+            .create_tx_success_state
+            .encrypted_slate
+            .clear();
+        grin_gui.wallet_state.operation_state.mode = operation::Mode::Home;
+        list(
+            &mut grin_gui,
+            LocalViewInteraction::ShowSlatepack(sent.clone()),
+        );
+        assert!(grin_gui.error.is_none());
+        assert_eq!(
+            grin_gui.wallet_state.operation_state.mode,
+            operation::Mode::CreateTxSuccess
+        );
+        let success_state = &grin_gui
+            .wallet_state
+            .operation_state
+            .create_tx_success_state;
+        let sent_id = sent.tx_slate_id.unwrap();
+        assert_eq!(success_state.encrypted_slate, sent_slatepack);
+        assert_eq!(
+            success_state.slatepack_file_name,
+            format!("{}.S1.slatepack", sent_id)
+        );
+
+        // And the S2 response on the receive success screen
+        grin_gui
+            .wallet_state
+            .operation_state
+            .apply_tx_success_state
+            .encrypted_slate = None;
+        list(&mut grin_gui, LocalViewInteraction::ShowSlatepack(received));
+        assert!(grin_gui.error.is_none());
+        assert_eq!(
+            grin_gui.wallet_state.operation_state.mode,
+            operation::Mode::ApplyTxSuccess
+        );
+        let success_state = &grin_gui.wallet_state.operation_state.apply_tx_success_state;
+        assert_eq!(
+            success_state.slatepack_file_name,
+            format!("{}.S2.slatepack", incoming_id)
+        );
+        assert_eq!(success_state.encrypted_slate, Some(response_slatepack));
+        // Kept across restarts
+        assert_eq!(SentSlatepacks::load().unwrap(), grin_gui.sent_slatepacks);
+
+        // Nothing kept for a transaction never handed out from here, so none is offered
+        let mut unknown = sent.clone();
+        unknown.tx_slate_id = Some(Slate::blank(2, false).id);
+        assert!(sent_slatepack(&grin_gui.sent_slatepacks, &unknown).is_none());
+        list(&mut grin_gui, LocalViewInteraction::ShowSlatepack(unknown));
+        assert!(grin_gui.error.is_some());
+
+        // Dropped once the send is cancelled, and kept that way across restarts
+        for message in [
+            Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+                home::LocalViewInteraction::TxDetails(TxLogEntryWrap::new(sent.clone())),
+            )),
+            Message::Interaction(Interaction::WalletOperationTxDetailViewInteraction(
+                tx_detail::LocalViewInteraction::CancelTx,
+            )),
+        ] {
+            run(&mut grin_gui, message);
+        }
+        assert!(grin_gui.error.is_none());
+        assert!(sent_slatepack(&grin_gui.sent_slatepacks, &sent).is_none());
+        assert!(grin_gui
+            .sent_slatepacks
+            .get(&incoming_id.to_string(), "S2")
+            .is_some());
+        assert_eq!(SentSlatepacks::load().unwrap(), grin_gui.sent_slatepacks);
+    }
+}
@@ -0,0 +1,339 @@
+use crate::log_error;
+use grin_gui_core::config::Config;
+use native_dialog::FileDialog;
+
+use {
+    super::super::super::{
+        BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+        SMALLER_FONT_SIZE,
+    },
+    crate::gui::{GrinGui, Interaction, Message},
+    crate::localization::localized_string,
+    crate::Result,
+    anyhow::Context,
+    grin_gui_core::node::amount_to_hr_string,
+    grin_gui_core::theme::{
+        Button, Column, Container, ContainerStyle, Element, Row, Scrollable, Text,
+    },
+    grin_gui_core::wallet::{read_payment_proof, PaymentProof},
+    iced::widget::Space,
+    iced::{alignment, Command, Length},
+    std::path::PathBuf,
+    std::sync::{Arc, RwLock},
+};
+
+#[derive(Default)]
+pub struct StateContainer {
+    // Last proof that verified, with whether its sender and recipient are ours
+    pub verified: Option<(PaymentProof, bool, bool)>,
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+    Back,
+    OpenProofFile,
+    VerifyProofFile(PathBuf),
+    ProofVerifiedOk(PaymentProof, bool, bool),
+    ProofVerifyError(Arc<RwLock<Option<anyhow::Error>>>),
+}
+
+pub fn handle_message(
+    grin_gui: &mut GrinGui,
+    message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+    let state = &mut grin_gui.wallet_state.operation_state.verify_proof_state;
+
+    match message {
+        LocalViewInteraction::Back => {
+            log::debug!("Interaction::WalletOperationVerifyProofViewInteraction(Back)");
+            state.verified = None;
+            grin_gui.wallet_state.operation_state.mode =
+                crate::gui::element::wallet::operation::Mode::Home;
+        }
+        LocalViewInteraction::OpenProofFile => match FileDialog::new()
+            .add_filter("JSON", &["json"])
+            .show_open_single_file()
+        {
+            Ok(Some(path)) => {
+                return Ok(Command::perform(async {}, move |_| {
+                    Message::Interaction(Interaction::WalletOperationVerifyProofViewInteraction(
+                        LocalViewInteraction::VerifyProofFile(path),
+                    ))
+                }));
+            }
+            Ok(None) => {}
+            Err(e) => {
+                log::debug!("verify_proof.rs::LocalViewInteraction::OpenProofFile {}", e);
+            }
+        },
+        LocalViewInteraction::VerifyProofFile(path) => {
+            log::debug!("Interaction::WalletOperationVerifyProofViewInteraction(VerifyProofFile)");
+            grin_gui.error.take();
+            state.verified = None;
+
+            let fut = grin_gui.wallet_interface.run(move |w| {
+                let proof = read_payment_proof(&path)?;
+                let (sender_is_ours, recipient_is_ours) = w.verify_payment_proof(proof.clone())?;
+                Ok((proof, sender_is_ours, recipient_is_ours))
+            });
+
+            return Ok(Command::perform(fut, |r| {
+                match r.context("Payment proof is not valid") {
+                    Ok((proof, sender_is_ours, recipient_is_ours)) => Message::Interaction(
+                        Interaction::WalletOperationVerifyProofViewInteraction(
+                            LocalViewInteraction::ProofVerifiedOk(
+                                proof,
+                                sender_is_ours,
+                                recipient_is_ours,
+                            ),
+                        ),
+                    ),
+                    Err(e) => Message::Interaction(
+                        Interaction::WalletOperationVerifyProofViewInteraction(
+                            LocalViewInteraction::ProofVerifyError(Arc::new(RwLock::new(Some(e)))),
+                        ),
+                    ),
+                }
+            }));
+        }
+        LocalViewInteraction::ProofVerifiedOk(proof, sender_is_ours, recipient_is_ours) => {
+            state.verified = Some((proof, sender_is_ours, recipient_is_ours));
+        }
+        LocalViewInteraction::ProofVerifyError(err) => {
+            grin_gui.error = err.write().unwrap().take();
+            if let Some(e) = grin_gui.error.as_ref() {
+                log_error(e);
+            }
+        }
+    }
+
+    Ok(Command::none())
+}
+
+fn result_row<'a>(label: String, value: String, is_ours: Option<bool>) -> Row<'a, Message> {
+    let label = Container::new(Text::new(label).size(DEFAULT_FONT_SIZE))
+        .width(Length::Units(120))
+        .style(ContainerStyle::NormalBackground);
+    let value = Container::new(Text::new(value).size(DEFAULT_FONT_SIZE))
+        .style(ContainerStyle::NormalBackground);
+
+    let mut row = Row::new().push(label).push(value);
+
+    if let Some(is_ours) = is_ours {
+        let ours_key = if is_ours {
+            "proof-address-ours"
+        } else {
+            "proof-address-not-ours"
+        };
+        let ours = Text::new(localized_string(ours_key))
+            .size(SMALLER_FONT_SIZE)
+            .style(grin_gui_core::theme::text::TextStyle::Warning);
+        row = row
+            .push(Space::new(Length::Units(10), Length::Units(0)))
+            .push(Container::new(ours).style(ContainerStyle::NormalBackground));
+    }
+
+    row
+}
+
+pub fn data_container<'a>(
+    _config: &'a Config,
+    state: &'a StateContainer,
+) -> Container<'a, Message> {
+    let unit_spacing = 15;
+
+    // Title row
+    let title = Text::new(localized_string("verify-proof"))
+        .size(DEFAULT_HEADER_FONT_SIZE)
+        .horizontal_alignment(alignment::Horizontal::Center);
+
+    let title_container = Container::new(title)
+        .style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+        .padding(iced::Padding::from([
+            2, // top
+            0, // right
+            2, // bottom
+            5, // left
+        ]));
+
+    let header_row = Row::new().push(title_container);
+
+    let header_container = Container::new(header_row).padding(iced::Padding::from([
+        0,               // top
+        0,               // right
+        DEFAULT_PADDING, // bottom
+        0,               // left
+    ]));
+
+    let instruction = Text::new(localized_string("verify-proof-instruction"))
+        .size(DEFAULT_FONT_SIZE)
+        .horizontal_alignment(alignment::Horizontal::Left);
+
+    let instruction_container = Container::new(instruction).style(ContainerStyle::NormalBackground);
+
+    let mut column = Column::new()
+        .push(instruction_container)
+        .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+
+    if let Some((proof, sender_is_ours, recipient_is_ours)) = state.verified.as_ref() {
+        let valid = Text::new(localized_string("verify-proof-valid"))
+            .size(DEFAULT_FONT_SIZE)
+            .horizontal_alignment(alignment::Horizontal::Left);
+
+        column = column
+            .push(Container::new(valid).style(ContainerStyle::NormalBackground))
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)))
+            .push(result_row(
+                localized_string("proof-amount"),
+                amount_to_hr_string(proof.amount, false),
+                None,
+            ))
+            .push(Space::new(Length::Units(0), Length::Units(5)))
+            .push(result_row(
+                localized_string("proof-sender"),
+                proof.sender_address.to_string(),
+                Some(*sender_is_ours),
+            ))
+            .push(Space::new(Length::Units(0), Length::Units(5)))
+            .push(result_row(
+                localized_string("proof-recipient"),
+                proof.recipient_address.to_string(),
+                Some(*recipient_is_ours),
+            ))
+            .push(Space::new(Length::Units(0), Length::Units(unit_spacing)));
+    }
+
+    let button_height = Length::Units(BUTTON_HEIGHT);
+    let button_width = Length::Units(BUTTON_WIDTH);
+
+    let open_button_label_container =
+        Container::new(Text::new(localized_string("verify-proof-open")).size(DEFAULT_FONT_SIZE))
+            .width(button_width)
+            .height(button_height)
+            .center_x()
+            .center_y()
+            .align_x(alignment::Horizontal::Center);
+
+    let open_button: Element<Interaction> = Button::new(open_button_label_container)
+        .style(grin_gui_core::theme::ButtonStyle::Primary)
+        .on_press(Interaction::WalletOperationVerifyProofViewInteraction(
+            LocalViewInteraction::OpenProofFile,
+        ))
+        .into();
+
+    let cancel_button_label_container =
+        Container::new(Text::new(localized_string("cancel")).size(DEFAULT_FONT_SIZE))
+            .width(button_width)
+            .height(button_height)
+            .center_x()
+            .center_y()
+            .align_x(alignment::Horizontal::Center);
+
+    let cancel_button: Element<Interaction> = Button::new(cancel_button_label_container)
+        .style(grin_gui_core::theme::ButtonStyle::Primary)
+        .on_press(Interaction::WalletOperationVerifyProofViewInteraction(
+            LocalViewInteraction::Back,
+        ))
+        .into();
+
+    let open_container = Container::new(open_button.map(Message::Interaction)).padding(1);
+    let open_container = Container::new(open_container)
+        .style(grin_gui_core::theme::ContainerStyle::Segmented)
+        .padding(1);
+
+    let cancel_container = Container::new(cancel_button.map(Message::Interaction)).padding(1);
+    let cancel_container = Container::new(cancel_container)
+        .style(grin_gui_core::theme::ContainerStyle::Segmented)
+        .padding(1);
+
+    let button_row = Row::new()
+        .push(open_container)
+        .push(Space::new(Length::Units(unit_spacing), Length::Units(0)))
+        .push(cancel_container);
+
+    column = column.push(button_row).push(Space::new(
+        Length::Units(0),
+        Length::Units(unit_spacing + 10),
+    ));
+
+    let form_container = Container::new(column)
+        .width(Length::Fill)
+        .padding(iced::Padding::from([
+            0, // top
+            0, // right
+            0, // bottom
+            5, // left
+        ]));
+
+    // form container should be scrollable in tiny windows
+    let scrollable = Scrollable::new(form_container)
+        .height(Length::Fill)
+        .style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+    let content = Container::new(scrollable)
+        .width(Length::Fill)
+        .height(Length::Shrink)
+        .style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+    let wrapper_column = Column::new()
+        .height(Length::Fill)
+        .push(header_container)
+        .push(content);
+
+    // Returns the final container.
+    Container::new(wrapper_column).padding(iced::Padding::from([
+        DEFAULT_PADDING, // top
+        DEFAULT_PADDING, // right
+        DEFAULT_PADDING, // bottom
+        DEFAULT_PADDING, // left
+    ]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gui::element::wallet::operation::home;
+    use crate::gui::test::{mock_gui, open_wallet, run, TestDir};
+    use grin_gui_core::wallet::{mock::MOCK_SLATEPACK_ADDRESS, MockWallet, SlatepackAddress};
+
+    #[test]
+    fn test_export_and_verify_payment_proof() {
+        let mut mock = MockWallet::new("password");
+        let recipient = SlatepackAddress::random().to_string();
+        mock.payment_proofs.insert(
+            3,
+            MockWallet::payment_proof(1_000_000_000, MOCK_SLATEPACK_ADDRESS, &recipient),
+        );
+        let dir = TestDir::new();
+        let mut grin_gui = mock_gui(&dir, mock);
+        open_wallet(&mut grin_gui, "password");
+
+        let path = dir.join("payment_proof_3.json");
+        run(
+            &mut grin_gui,
+            Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+                home::LocalViewInteraction::SavePaymentProof(3, path.clone()),
+            )),
+        );
+        assert!(grin_gui.error.is_none());
+
+        run(
+            &mut grin_gui,
+            Message::Interaction(Interaction::WalletOperationVerifyProofViewInteraction(
+                LocalViewInteraction::VerifyProofFile(path),
+            )),
+        );
+        assert!(grin_gui.error.is_none());
+        let (proof, sender_is_ours, recipient_is_ours) = grin_gui
+            .wallet_state
+            .operation_state
+            .verify_proof_state
+            .verified
+            .clone()
+            .unwrap();
+        assert_eq!(proof.amount, 1_000_000_000);
+        assert_eq!(proof.recipient_address.to_string(), recipient);
+        assert!(sender_is_ours);
+        assert!(!recipient_is_ours);
+    }
+}
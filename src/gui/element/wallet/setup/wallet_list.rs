@@ -3,7 +3,7 @@ use {
     crate::gui::{GrinGui, Interaction, Message},
     crate::localization::localized_string,
     crate::Result,
-    grin_gui_core::config::Config,
+    grin_gui_core::config::{Config, WalletBackendType},
     grin_gui_core::{
         theme::ColorPalette,
         wallet::{create_grin_wallet_path, ChainTypes},
@@ -234,9 +234,10 @@ pub fn data_container<'a>(
         let mut wallet_chain_container =
             Container::new(chain_name).style(grin_gui_core::theme::ContainerStyle::HoverableForeground);
 
-        let tld_string = match &w.tld {
-            Some(path_buf) => path_buf.display().to_string(),
-            None => String::from("Unknown"),
+        let tld_string = match (&w.backend, &w.tld) {
+            (WalletBackendType::RemoteOwnerApi { owner_api_url, .. }, _) => owner_api_url.clone(),
+            (_, Some(path_buf)) => path_buf.display().to_string(),
+            (_, None) => String::from("Unknown"),
         };
         let wallet_directory = Text::new(tld_string).size(DEFAULT_FONT_SIZE);
 
@@ -23,7 +23,6 @@ use {
         fs::PersistentData,
         node::ChainTypes::{self, Mainnet, Testnet},
         wallet::create_grin_wallet_path,
-        wallet::WalletInterface,
     },
     iced::widget::{button, pick_list, scrollable, text_input, Checkbox, Space},
     iced::{alignment, Alignment, Command, Length},
@@ -199,18 +198,17 @@ pub fn handle_message<'a>(
                 None
             };
 
-            let fut = move || {
-                WalletInterface::init(
-                    w,
+            let fut = w.run(move |w| {
+                w.init(
                     password.clone(),
                     top_level_directory,
                     display_name,
                     chain_type,
                     recovery_phrase,
                 )
-            };
+            });
 
-            return Ok(Command::perform(fut(), |r| {
+            return Ok(Command::perform(fut, |r| {
                 match r.context("Failed to Create Wallet") {
                     Ok(ret) => Message::Interaction(Interaction::WalletSetupWalletViewInteraction(
                         LocalViewInteraction::WalletCreatedOk(ret),
@@ -2,6 +2,8 @@ mod element;
 //mod style;
 mod update;
 mod time;
+#[cfg(test)]
+mod test;
 
 use crate::cli::Opts;
 use crate::error_cause_string;
@@ -9,10 +11,10 @@ use crate::localization::{localized_string, LANG};
 use crate::gui::element::{DEFAULT_FONT_SIZE, SMALLER_FONT_SIZE};
 use grin_gui_core::theme::Element;
 use grin_gui_core::{
-    config::Config,
+    config::{AddressBook, Config, SentSlatepacks, TxNotes},
     fs::PersistentData,
     theme::{Theme, Container, Column, ColorPalette, Button, PickList, Row, Scrollable, Text},
-    wallet::{WalletInterfaceHttpNodeClient, HTTPNodeClient, global, get_grin_wallet_default_path},
+    wallet::{self, WalletBackend, WalletWorker, WalletInterfaceHttpNodeClient, HTTPNodeClient, global, get_grin_wallet_default_path},
     node::{NodeInterface, subscriber::{self, UIMessage}, ChainTypes},
 };
 
@@ -39,7 +41,7 @@ static WINDOW_ICON: &[u8] = include_bytes!("../../resources/windows/ajour.ico");
 
 pub struct GrinGui {
     /// Wallet Interface
-    wallet_interface: Arc<RwLock<WalletInterfaceHttpNodeClient>>,
+    wallet_interface: WalletWorker,
 
     /// Node Interface
     node_interface: Arc<RwLock<NodeInterface>>,
@@ -48,6 +50,15 @@ pub struct GrinGui {
     mode: Mode,
     config: Config,
 
+    /// Saved slatepack contacts
+    address_book: AddressBook,
+
+    /// Local notes and tags on transactions
+    tx_notes: TxNotes,
+
+    /// Slatepacks handed out for transactions, to show again while outstanding
+    sent_slatepacks: SentSlatepacks,
+
     /// Top-level error modal overlay
     modal_state: modal::State<element::modal::StateContainer>,
 
@@ -96,16 +107,27 @@ impl GrinGui{
         let node_url = "http://localhost:8080";
     	let node_client = HTTPNodeClient::new(node_url, None).unwrap();
 
+        GrinGui::with_wallet_backend(
+            config,
+            Box::new(WalletInterfaceHttpNodeClient::new(node_client)),
+        )
+    }
+
+    /// Builds the GUI state around any wallet backend, e.g. a mock one in tests
+    fn with_wallet_backend(config: &Config, backend: Box<dyn WalletBackend>) -> Self {
         // restore theme from config
         let name = config.theme.clone().unwrap_or("Alliance".to_string());
         let theme = Theme::all().iter().find(|t| t.0 == name).unwrap().1.clone();
 
         Self {
-            wallet_interface: Arc::new(RwLock::new(WalletInterfaceHttpNodeClient::new(node_client))),
+            wallet_interface: WalletWorker::spawn(backend),
             node_interface: Arc::new(RwLock::new(NodeInterface::new())),
             error: None,
             mode: Mode::Catalog,
             config: Config::default(),
+            address_book: Default::default(),
+            tx_notes: Default::default(),
+            sent_slatepacks: Default::default(),
             modal_state: Default::default(),
             menu_state: Default::default(),
             wallet_state: Default::default(),
@@ -128,6 +150,13 @@ impl GrinGui{
 pub enum Message {
     Error(Arc<RwLock<Option<anyhow::Error>>>),
     SendNodeMessage((usize, UIMessage, Option<mpsc::Sender<UIMessage>>)),
+    SendWalletMessage(
+        (
+            usize,
+            wallet::subscriber::UIMessage,
+            Option<mpsc::Sender<wallet::subscriber::UIMessage>>,
+        ),
+    ),
     Interaction(Interaction),
     Tick(chrono::DateTime<chrono::Local>),
     RuntimeEvent(iced_native::Event),
@@ -172,6 +201,21 @@ impl Application for GrinGui {
 
         apply_config(&mut grin_gui, config);
 
+        match AddressBook::load_or_default() {
+            Ok(address_book) => grin_gui.address_book = address_book,
+            Err(e) => log::error!("Failed to load address book: {}", e),
+        }
+
+        match TxNotes::load_or_default() {
+            Ok(tx_notes) => grin_gui.tx_notes = tx_notes,
+            Err(e) => log::error!("Failed to load transaction notes: {}", e),
+        }
+
+        match SentSlatepacks::load_or_default() {
+            Ok(sent_slatepacks) => grin_gui.sent_slatepacks = sent_slatepacks,
+            Err(e) => log::error!("Failed to load sent slatepacks: {}", e),
+        }
+
         (grin_gui, Command::batch(vec![]))
     }
 
@@ -202,8 +246,15 @@ impl Application for GrinGui {
         let node_subscription = subscriber::subscriber(0).map(|e| 
             Message::SendNodeMessage(e)
         );
- 
-        iced::Subscription::batch(vec![runtime_subscription, tick_subscription, node_subscription])
+        let wallet_subscription =
+            wallet::subscriber::subscriber(0).map(Message::SendWalletMessage);
+
+        iced::Subscription::batch(vec![
+            runtime_subscription,
+            tick_subscription,
+            node_subscription,
+            wallet_subscription,
+        ])
     }
 
     fn update(&mut self, message: Message) -> Command<Message> {
@@ -227,7 +278,10 @@ impl Application for GrinGui {
             element::menu::Mode::Wallet => {
                 let setup_container = element::wallet::data_container(
                    &self.wallet_state,
-                   &self.config
+                   &self.config,
+                   &self.address_book,
+                   &self.tx_notes,
+                   &self.sent_slatepacks,
                 );
                 content = content.push(setup_container)
             }
@@ -398,6 +452,8 @@ pub enum Interaction {
     /// Clipboard copy
     WriteToClipboard(String),
     ReadSlatepackFromClipboard,
+    /// QR code export
+    QrCodeViewInteraction(element::qr_code::LocalViewInteraction),
     /// View interactions
     MenuViewInteraction(element::menu::LocalViewInteraction),
     SettingsViewInteraction(element::settings::LocalViewInteraction),
@@ -420,6 +476,12 @@ pub enum Interaction {
     WalletOperationApplyTxViewInteraction(element::wallet::operation::apply_tx::LocalViewInteraction),
     WalletOperationApplyTxConfirmViewInteraction(element::wallet::operation::apply_tx_confirm::LocalViewInteraction),
     WalletOperationApplyTxSuccessViewInteraction(element::wallet::operation::apply_tx_success::LocalViewInteraction),
+    WalletOperationOutputsViewInteraction(element::wallet::operation::outputs::LocalViewInteraction),
+    WalletOperationCreateInvoiceViewInteraction(element::wallet::operation::create_invoice::LocalViewInteraction),
+    WalletOperationInvoiceSuccessViewInteraction(element::wallet::operation::invoice_success::LocalViewInteraction),
+    WalletOperationVerifyProofViewInteraction(element::wallet::operation::verify_proof::LocalViewInteraction),
+    WalletOperationScanViewInteraction(element::wallet::operation::scan::LocalViewInteraction),
+    WalletOperationTxDetailViewInteraction(element::wallet::operation::tx_detail::LocalViewInteraction),
     ViewInteraction(String, String),
     ModeSelected(Mode),
     ModeSelectedSettings(element::settings::Mode),
@@ -0,0 +1,103 @@
+//! Drives `GrinGui` through its update loop against a mock wallet, for tests of the views
+use super::{update::handle_message, GrinGui, Interaction, Message};
+use crate::gui::element::wallet::operation::open;
+use crate::localization::LANG;
+use futures::executor::block_on;
+use grin_gui_core::config::{Config, Wallet};
+use grin_gui_core::fs::CONFIG_DIR;
+use grin_gui_core::node::ChainTypes::Testnet;
+use grin_gui_core::wallet::{MockWallet, WALLET_CONFIG_FILE_NAME};
+use iced_native::command::Action;
+use once_cell::sync::Lazy;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, RwLock};
+
+// Tests point the process wide config dir at their own directory, and cargo runs
+// them in parallel, so only one may hold it at a time
+static CONFIG_DIR_LOCK: Lazy<Mutex<()>> = Lazy::new(Default::default);
+static TEST_DIR_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Temporary directory of a single test, holding the mock wallet's directory and the
+/// config dir. The config dir is restored and everything removed once it's dropped.
+pub struct TestDir {
+    path: PathBuf,
+    config_dir: PathBuf,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl TestDir {
+    pub fn new() -> Self {
+        // A test failing while holding the lock leaves nothing the next one relies on
+        let lock = CONFIG_DIR_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let path = std::env::temp_dir().join(format!(
+            "grin-gui-test-{}-{}",
+            std::process::id(),
+            TEST_DIR_COUNT.fetch_add(1, Ordering::SeqCst)
+        ));
+        std::fs::create_dir_all(&path).unwrap();
+        let config_dir = std::mem::replace(
+            &mut *CONFIG_DIR.lock().unwrap_or_else(|e| e.into_inner()),
+            path.join("gui"),
+        );
+        TestDir {
+            path,
+            config_dir,
+            _lock: lock,
+        }
+    }
+
+    pub fn join(&self, file_name: &str) -> PathBuf {
+        self.path.join(file_name)
+    }
+}
+
+impl Drop for TestDir {
+    fn drop(&mut self) {
+        *CONFIG_DIR.lock().unwrap_or_else(|e| e.into_inner()) = self.config_dir.clone();
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+/// GUI with a single wallet, backed by `mock`, selected but not opened yet
+pub fn mock_gui(dir: &TestDir, mock: MockWallet) -> GrinGui {
+    let _ = LANG.set(RwLock::new("en_US"));
+    let mut grin_gui = GrinGui::with_wallet_backend(&Config::default(), Box::new(mock));
+
+    // The config file check in handle_message only looks at the filesystem
+    let tld = dir.join("wallet");
+    std::fs::create_dir_all(&tld).unwrap();
+    std::fs::write(tld.join(WALLET_CONFIG_FILE_NAME), "").unwrap();
+
+    let mut wallet = Wallet::new(Some(tld), "Mock".to_owned(), Testnet);
+    wallet.use_embedded_node = false;
+    let index = grin_gui.config.add_wallet(wallet);
+    grin_gui.config.current_wallet_index = Some(index);
+    grin_gui
+}
+
+/// Feeds a message through the update loop, then keeps resolving any returned
+/// futures and feeding their messages back in until nothing is left
+pub fn run(grin_gui: &mut GrinGui, message: Message) {
+    let mut pending = vec![message];
+    while let Some(message) = pending.pop() {
+        let command = handle_message(grin_gui, message).unwrap();
+        for action in command.actions() {
+            if let Action::Future(f) = action {
+                pending.push(block_on(f));
+            }
+        }
+    }
+}
+
+pub fn open_wallet(grin_gui: &mut GrinGui, password: &str) {
+    for interaction in [
+        open::LocalViewInteraction::PasswordInput(password.to_owned()),
+        open::LocalViewInteraction::OpenWallet,
+    ] {
+        run(
+            grin_gui,
+            Message::Interaction(Interaction::WalletOperationOpenViewInteraction(interaction)),
+        );
+    }
+}
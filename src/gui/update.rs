@@ -3,7 +3,7 @@ use {
     crate::{gui::element, log_error, Result},
     grin_gui_core::{
         fs::PersistentData, node::subscriber::UIMessage, node::ChainTypes::Mainnet,
-        node::ChainTypes::Testnet,
+        node::ChainTypes::Testnet, wallet,
     },
     iced::{clipboard, Command},
     //grin_gui_widgets::header::ResizeEvent,
@@ -19,13 +19,13 @@ use std::sync::atomic::Ordering;
 
 pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Command<Message>> {
     if let Some(index) = grin_gui.config.current_wallet_index {
+        let is_remote = grin_gui.config.wallets[index].backend.is_remote();
         // Take opportunity to check if we don't have a wallet config file for some reason
-        if !grin_gui.wallet_state.config_missing() {
+        // (remote wallets keep their config on the remote host)
+        if !is_remote && !grin_gui.wallet_state.config_missing() {
             match &grin_gui.config.wallets[index].tld {
                 Some(t) => {
-                    let wallet_interface = grin_gui.wallet_interface.clone();
-                    let w = wallet_interface.read().unwrap();
-                    if !w.config_exists(t.to_str().unwrap()) {
+                    if !wallet::config_exists(t.to_str().unwrap()) {
                         grin_gui.wallet_state.set_config_missing();
                     }
                 }
@@ -39,13 +39,12 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
         if !grin_gui.wallet_state.config_missing()
             && !grin_gui.wallet_state.operation_state.wallet_not_open()
         {
-            let w = grin_gui.wallet_interface.read().unwrap();
-            if !w.wallet_is_open() {
+            if !grin_gui.wallet_interface.wallet_is_open() {
                 grin_gui.wallet_state.operation_state.set_wallet_not_open()
             }
         }
         // Check if embedded node needs starting
-        if grin_gui.config.wallets[index].use_embedded_node {
+        if !is_remote && grin_gui.config.wallets[index].use_embedded_node {
             let (node_started, has_ui_sender) = {
                 let n = grin_gui.node_interface.read().unwrap();
                 (n.node_started, n.ui_sender.is_some())
@@ -88,6 +87,7 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
                 _,
                 _,
             ) => {}
+            element::wallet::operation::home::LocalViewInteraction::AccountsUpdateSuccess(_) => {}
             _ => {
                 grin_gui.error.take();
                 ()
@@ -122,6 +122,24 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
                 return Ok(Command::none());
             }
         },
+        Message::SendWalletMessage((_id, msg, sender)) => match sender {
+            Some(sender) => {
+                let fut = grin_gui.wallet_interface.run(move |w| {
+                    w.set_status_sender(sender);
+                    Ok(())
+                });
+                return Ok(Command::perform(fut, |_| Message::None(())));
+            }
+            None => {
+                match msg {
+                    wallet::subscriber::UIMessage::None => {}
+                    wallet::subscriber::UIMessage::UpdaterStatus(m) => {
+                        element::wallet::operation::home::handle_status_message(grin_gui, m);
+                    }
+                }
+                return Ok(Command::none());
+            }
+        },
         // Error modal state
         Message::Interaction(Interaction::OpenErrorModal) => grin_gui.modal_state.show(true),
         Message::Interaction(Interaction::CloseErrorModal) => grin_gui.modal_state.show(false),
@@ -129,6 +147,10 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
         Message::Interaction(Interaction::WriteToClipboard(contents)) => {
             return Ok(clipboard::write::<Message>(contents));
         }
+        // QR code export
+        Message::Interaction(Interaction::QrCodeViewInteraction(l)) => {
+            return element::qr_code::handle_message(grin_gui, l);
+        }
         Message::Interaction(Interaction::ReadSlatepackFromClipboard) => {
             return Ok(clipboard::read::<Message>(|value| {
                 match value {
@@ -146,7 +168,7 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
         }
         // Settings -> Wallet Settings
         Message::Interaction(Interaction::WalletSettingsViewInteraction(l)) => {
-            element::settings::wallet::handle_message(grin_gui, l);
+            return element::settings::wallet::handle_message(grin_gui, l);
         }
         // Settings -> Node Settings
         Message::Interaction(Interaction::NodeSettingsViewInteraction(l)) => {
@@ -213,6 +235,29 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
         Message::Interaction(Interaction::WalletOperationApplyTxSuccessViewInteraction(l)) => {
             return element::wallet::operation::apply_tx_success::handle_message(grin_gui, l);
         }
+        Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(l)) => {
+            return element::wallet::operation::outputs::handle_message(grin_gui, l);
+        }
+        // Wallet -> Operation -> CreateInvoice
+        Message::Interaction(Interaction::WalletOperationCreateInvoiceViewInteraction(l)) => {
+            return element::wallet::operation::create_invoice::handle_message(grin_gui, l);
+        }
+        // Wallet -> Operation -> InvoiceSuccess
+        Message::Interaction(Interaction::WalletOperationInvoiceSuccessViewInteraction(l)) => {
+            return element::wallet::operation::invoice_success::handle_message(grin_gui, l);
+        }
+        // Wallet -> Operation -> VerifyProof
+        Message::Interaction(Interaction::WalletOperationVerifyProofViewInteraction(l)) => {
+            return element::wallet::operation::verify_proof::handle_message(grin_gui, l);
+        }
+        // Wallet -> Operation -> Scan
+        Message::Interaction(Interaction::WalletOperationScanViewInteraction(l)) => {
+            return element::wallet::operation::scan::handle_message(grin_gui, l);
+        }
+        // Wallet -> Operation -> TxDetail
+        Message::Interaction(Interaction::WalletOperationTxDetailViewInteraction(l)) => {
+            return element::wallet::operation::tx_detail::handle_message(grin_gui, l);
+        }
         // Wallet -> Operation -> Home -> Action
         Message::Interaction(Interaction::WalletOperationHomeActionMenuViewInteraction(l)) => {
             return element::wallet::operation::action_menu::handle_message(grin_gui, l);